@@ -73,7 +73,7 @@ where T: Clone
 	pub fn handle_input(&mut self, input: Input) -> Option<&T> {
 		if handle_view_data_scroll(input, &mut self.view_data).is_none() {
 			match input {
-				Input::Resize => {},
+				Input::Resize | Input::Mouse(_) => {},
 				Input::Character(c) => {
 					if let Some(v) = self.map.get(&c) {
 						self.invalid_selection = false;
@@ -8,20 +8,28 @@ use crate::{
 };
 
 pub struct Confirm {
+	prompt: String,
 	view_data: ViewData,
 }
 
 impl Confirm {
 	pub fn new(prompt: &str, confirm_yes: &[String], confirm_no: &[String]) -> Self {
+		let prompt = format!("{} ({}/{})? ", prompt, confirm_yes.join(","), confirm_no.join(","));
 		let mut view_data = ViewData::new();
 		view_data.set_show_title(true);
-		view_data.push_line(ViewLine::from(format!(
-			"{} ({}/{})? ",
-			prompt,
-			confirm_yes.join(","),
-			confirm_no.join(",")
-		)));
-		Self { view_data }
+		view_data.push_line(ViewLine::from(prompt.as_str()));
+		Self { prompt, view_data }
+	}
+
+	pub fn set_details(&mut self, details: Vec<ViewLine>) {
+		self.view_data.clear_body();
+		self.view_data.push_line(ViewLine::from(self.prompt.as_str()));
+		if !details.is_empty() {
+			self.view_data.push_line(ViewLine::new_empty_line());
+			for line in details {
+				self.view_data.push_line(line);
+			}
+		}
 	}
 
 	pub fn get_view_data(&mut self, view_width: usize, view_height: usize) -> &ViewData {
@@ -17,6 +17,34 @@ fn render() {
 	);
 }
 
+#[test]
+fn render_with_details() {
+	let mut module = Confirm::new("Prompt message", &[String::from("y")], &[String::from("n")]);
+	module.set_details(vec![ViewLine::from("Detail 1"), ViewLine::from("Detail 2")]);
+	assert_rendered_output!(
+		module.get_view_data(100, 100),
+		"{TITLE}",
+		"{BODY}",
+		"{Normal}Prompt message (y/n)? ",
+		"",
+		"{Normal}Detail 1",
+		"{Normal}Detail 2"
+	);
+}
+
+#[test]
+fn render_with_details_cleared() {
+	let mut module = Confirm::new("Prompt message", &[String::from("y")], &[String::from("n")]);
+	module.set_details(vec![ViewLine::from("Detail 1")]);
+	module.set_details(vec![]);
+	assert_rendered_output!(
+		module.get_view_data(100, 100),
+		"{TITLE}",
+		"{BODY}",
+		"{Normal}Prompt message (y/n)? "
+	);
+}
+
 #[test]
 fn handle_input_yes() {
 	let mut module = Confirm::new("Prompt message", &[], &[]);
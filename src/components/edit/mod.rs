@@ -27,15 +27,6 @@ impl Edit {
 	}
 
 	pub fn update_view_data(&mut self, view_data: &mut ViewData) {
-		let line = self.content.as_str();
-		let pointer = self.cursor_position;
-
-		let graphemes = UnicodeSegmentation::graphemes(line, true);
-
-		let start = graphemes.clone().take(pointer).collect::<String>();
-		let indicator = graphemes.clone().skip(pointer).take(1).collect::<String>();
-		let end = graphemes.skip(pointer + 1).collect::<String>();
-
 		if let Some(description) = self.description.as_ref() {
 			view_data.push_leading_line(ViewLine::from(vec![LineSegment::new_with_color(
 				description.as_str(),
@@ -43,6 +34,17 @@ impl Edit {
 			)]));
 			view_data.push_leading_line(ViewLine::new_empty_line());
 		}
+		view_data.push_line(ViewLine::from(self.get_line_segments(true)));
+		view_data.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+			"Enter to finish",
+			DisplayColor::IndicatorColor,
+		)]));
+		view_data.rebuild();
+		view_data.ensure_column_visible(self.cursor_position);
+		view_data.ensure_line_visible(0);
+	}
+
+	pub(crate) fn get_line_segments(&self, show_cursor: bool) -> Vec<LineSegment> {
 		let mut segments = vec![];
 		if let Some(label) = self.label.as_ref() {
 			segments.push(LineSegment::new_with_color_and_style(
@@ -53,6 +55,18 @@ impl Edit {
 				false,
 			));
 		}
+		if !show_cursor {
+			segments.push(LineSegment::new(self.content.as_str()));
+			return segments;
+		}
+
+		let pointer = self.cursor_position;
+		let graphemes = UnicodeSegmentation::graphemes(self.content.as_str(), true);
+
+		let start = graphemes.clone().take(pointer).collect::<String>();
+		let indicator = graphemes.clone().skip(pointer).take(1).collect::<String>();
+		let end = graphemes.skip(pointer + 1).collect::<String>();
+
 		segments.push(LineSegment::new(start.as_str()));
 		segments.push(LineSegment::new_with_color_and_style(
 			indicator.as_str(),
@@ -71,14 +85,7 @@ impl Edit {
 				false,
 			));
 		}
-		view_data.push_line(ViewLine::from(segments));
-		view_data.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
-			"Enter to finish",
-			DisplayColor::IndicatorColor,
-		)]));
-		view_data.rebuild();
-		view_data.ensure_column_visible(pointer);
-		view_data.ensure_line_visible(0);
+		segments
 	}
 
 	pub fn handle_input(&mut self, input: Input) -> bool {
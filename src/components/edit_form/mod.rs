@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{
+	components::Edit,
+	display::display_color::DisplayColor,
+	input::Input,
+	view::{line_segment::LineSegment, view_data::ViewData, view_line::ViewLine},
+};
+
+pub struct EditForm {
+	description: Option<String>,
+	fields: Vec<Edit>,
+	selected_field: usize,
+}
+
+impl EditForm {
+	pub(crate) const fn new() -> Self {
+		Self {
+			description: None,
+			fields: vec![],
+			selected_field: 0,
+		}
+	}
+
+	pub fn update_view_data(&mut self, view_data: &mut ViewData) {
+		if let Some(description) = self.description.as_ref() {
+			view_data.push_leading_line(ViewLine::from(vec![LineSegment::new_with_color(
+				description.as_str(),
+				DisplayColor::IndicatorColor,
+			)]));
+			view_data.push_leading_line(ViewLine::new_empty_line());
+		}
+		for (index, field) in self.fields.iter().enumerate() {
+			view_data.push_line(ViewLine::from(field.get_line_segments(index == self.selected_field)));
+		}
+		view_data.push_trailing_line(ViewLine::new_pinned(vec![LineSegment::new_with_color(
+			"Tab to change field, Enter to finish",
+			DisplayColor::IndicatorColor,
+		)]));
+		view_data.rebuild();
+		view_data.ensure_line_visible(self.selected_field);
+	}
+
+	pub fn handle_input(&mut self, input: Input) -> bool {
+		if self.fields.is_empty() {
+			return false;
+		}
+		match input {
+			Input::Tab | Input::Down => self.selected_field = (self.selected_field + 1) % self.fields.len(),
+			Input::BackTab | Input::Up => {
+				self.selected_field = if self.selected_field == 0 {
+					self.fields.len() - 1
+				}
+				else {
+					self.selected_field - 1
+				};
+			},
+			_ => return self.fields[self.selected_field].handle_input(input),
+		}
+		true
+	}
+
+	pub fn set_description(&mut self, description: &str) {
+		self.description = Some(String::from(description));
+	}
+
+	pub fn add_field(&mut self, label: &str, content: &str) {
+		let mut field = Edit::new();
+		field.set_label(label);
+		field.set_content(content);
+		self.fields.push(field);
+	}
+
+	pub fn clear(&mut self) {
+		self.description = None;
+		self.fields.clear();
+		self.selected_field = 0;
+	}
+
+	pub fn get_values(&self) -> Vec<String> {
+		self.fields.iter().map(Edit::get_content).collect()
+	}
+}
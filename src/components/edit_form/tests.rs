@@ -0,0 +1,111 @@
+use super::*;
+use crate::assert_rendered_output;
+
+fn create_form() -> EditForm {
+	let mut module = EditForm::new();
+	module.add_field("One: ", "foo");
+	module.add_field("Two: ", "bar");
+	module
+}
+
+#[test]
+fn render_first_field_selected() {
+	let mut module = create_form();
+	let view_data = &mut ViewData::new();
+	view_data.set_view_size(500, 30);
+	module.update_view_data(view_data);
+	assert_rendered_output!(
+		view_data,
+		"{BODY}",
+		"{Normal,Dimmed}One: {Normal}foo{Normal,Underline} ",
+		"{Normal,Dimmed}Two: {Normal}bar",
+		"{TRAILING}",
+		"{IndicatorColor}Tab to change field, Enter to finish"
+	);
+}
+
+#[test]
+fn with_description() {
+	let mut module = create_form();
+	module.set_description("Description");
+	let view_data = &mut ViewData::new();
+	view_data.set_view_size(500, 30);
+	module.update_view_data(view_data);
+	assert_rendered_output!(
+		view_data,
+		"{LEADING}",
+		"{IndicatorColor}Description",
+		"",
+		"{BODY}",
+		"{Normal,Dimmed}One: {Normal}foo{Normal,Underline} ",
+		"{Normal,Dimmed}Two: {Normal}bar",
+		"{TRAILING}",
+		"{IndicatorColor}Tab to change field, Enter to finish"
+	);
+}
+
+#[test]
+fn tab_to_next_field() {
+	let mut module = create_form();
+	assert!(module.handle_input(Input::Tab));
+	let view_data = &mut ViewData::new();
+	view_data.set_view_size(500, 30);
+	module.update_view_data(view_data);
+	assert_rendered_output!(
+		view_data,
+		"{BODY}",
+		"{Normal,Dimmed}One: {Normal}foo",
+		"{Normal,Dimmed}Two: {Normal}bar{Normal,Underline} ",
+		"{TRAILING}",
+		"{IndicatorColor}Tab to change field, Enter to finish"
+	);
+}
+
+#[test]
+fn tab_wraps_to_first_field() {
+	let mut module = create_form();
+	module.handle_input(Input::Tab);
+	module.handle_input(Input::Down);
+	module.handle_input(Input::Character('x'));
+	assert_eq!(module.get_values(), vec![String::from("foox"), String::from("bar")]);
+}
+
+#[test]
+fn back_tab_wraps_to_last_field() {
+	let mut module = create_form();
+	module.handle_input(Input::BackTab);
+	module.handle_input(Input::Character('x'));
+	assert_eq!(module.get_values(), vec![String::from("foo"), String::from("barx")]);
+}
+
+#[test]
+fn up_to_previous_field() {
+	let mut module = create_form();
+	module.handle_input(Input::Tab);
+	module.handle_input(Input::Up);
+	module.handle_input(Input::Backspace);
+	assert_eq!(module.get_values(), vec![String::from("fo"), String::from("bar")]);
+}
+
+#[test]
+fn ignore_other_input() {
+	let mut module = create_form();
+	assert!(!module.handle_input(Input::Enter));
+}
+
+#[test]
+fn empty_form_ignores_input() {
+	let mut module = EditForm::new();
+	assert!(!module.handle_input(Input::Tab));
+}
+
+#[test]
+fn clear_fields() {
+	let mut module = create_form();
+	module.set_description("Description");
+	module.handle_input(Input::Tab);
+	module.clear();
+	assert!(module.get_values().is_empty());
+	assert!(module.description.is_none());
+	assert_eq!(module.selected_field, 0);
+}
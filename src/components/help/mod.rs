@@ -42,7 +42,8 @@ impl Help {
 			.set_padding_color_and_style(DisplayColor::Normal, false, true, false),
 		);
 
-		for line in keybindings {
+		// unbound keys, like the backwards search by default, are not listed
+		for line in keybindings.iter().filter(|line| !line.0.is_empty()) {
 			view_data.push_line(ViewLine::new_with_pinned_segments(
 				vec![
 					LineSegment::new_with_color(
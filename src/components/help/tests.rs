@@ -37,6 +37,25 @@ fn from_key_bindings() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn from_key_bindings_unbound_key() {
+	let mut module = Help::new_from_keybindings(&[
+		(vec![String::from("a")], String::from("Description A")),
+		(vec![], String::from("Description B")),
+	]);
+	assert_rendered_output!(
+		module.get_view_data(100, 100),
+		"{TITLE}",
+		"{LEADING}",
+		"{Normal,Underline} Key Action{Normal,Underline}{Pad  ,89}",
+		"{BODY}",
+		"{IndicatorColor} a{Normal,Dimmed}|{Normal}Description A",
+		"{TRAILING}",
+		"{IndicatorColor}Press any key to close"
+	);
+}
+
 #[rstest(
 	input,
 	case::resize(Input::Resize),
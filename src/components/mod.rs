@@ -1,9 +1,11 @@
 mod choice;
 mod confirm;
 mod edit;
+mod edit_form;
 mod help;
 
 pub use choice::Choice;
 pub use confirm::Confirm;
 pub use edit::Edit;
+pub use edit_form::EditForm;
 pub use help::Help;
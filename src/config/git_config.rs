@@ -1,10 +1,15 @@
 use anyhow::{anyhow, Result};
 use git2::Config;
 
-use crate::config::utils::{editor_from_env, get_string, get_unsigned_integer};
+use crate::config::{
+	missing_commits_check_setting::MissingCommitsCheckSetting,
+	utils::{editor_from_env, get_bool, get_missing_commits_check, get_string, get_unsigned_integer},
+};
 
 #[derive(Clone, Debug)]
 pub struct GitConfig {
+	pub(crate) abbrev: Option<u32>,
+	pub(crate) abbreviate_commands: bool,
 	pub(crate) comment_char: String,
 	pub(crate) diff_context: u32,
 	pub(crate) diff_interhunk_lines: u32,
@@ -12,6 +17,7 @@ pub struct GitConfig {
 	pub(crate) diff_renames: bool,
 	pub(crate) diff_copies: bool,
 	pub(crate) editor: String,
+	pub(crate) missing_commits_check: MissingCommitsCheckSetting,
 }
 
 impl GitConfig {
@@ -38,7 +44,27 @@ impl GitConfig {
 			},
 		};
 
+		// auto leaves the length up to git, no disables abbreviation
+		let abbrev = match get_string(git_config, "core.abbrev", "auto")?.to_lowercase().as_str() {
+			"auto" => None,
+			"no" | "false" | "off" => Some(40),
+			v => {
+				match v.parse::<u32>() {
+					Ok(length) if (4..=40).contains(&length) => Some(length),
+					_ => {
+						return Err(anyhow!(
+							"\"{}\" must be \"auto\", \"no\" or a number between 4 and 40",
+							v
+						)
+						.context("\"core.abbrev\" is not valid"));
+					},
+				}
+			},
+		};
+
 		Ok(Self {
+			abbrev,
+			abbreviate_commands: get_bool(git_config, "rebase.abbreviateCommands", false)?,
 			comment_char,
 			diff_context: get_unsigned_integer(git_config, "diff.context", 3)?,
 			diff_interhunk_lines: get_unsigned_integer(git_config, "diff.interHunkContext", 0)?,
@@ -46,6 +72,7 @@ impl GitConfig {
 			diff_renames,
 			diff_copies,
 			editor: get_string(git_config, "core.editor", editor_from_env().as_str())?,
+			missing_commits_check: get_missing_commits_check(git_config)?,
 		})
 	}
 }
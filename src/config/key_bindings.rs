@@ -6,6 +6,7 @@ use crate::config::utils::get_input;
 #[derive(Clone, Debug)]
 pub struct KeyBindings {
 	pub(crate) abort: Vec<String>,
+	pub(crate) absorb: Vec<String>,
 	pub(crate) action_break: Vec<String>,
 	pub(crate) action_drop: Vec<String>,
 	pub(crate) action_edit: Vec<String>,
@@ -13,12 +14,18 @@ pub struct KeyBindings {
 	pub(crate) action_pick: Vec<String>,
 	pub(crate) action_reword: Vec<String>,
 	pub(crate) action_squash: Vec<String>,
+	pub(crate) autosquash: Vec<String>,
 	pub(crate) confirm_no: Vec<String>,
 	pub(crate) confirm_yes: Vec<String>,
+	pub(crate) cut: Vec<String>,
+	pub(crate) cycle_fixup_message: Vec<String>,
+	pub(crate) drop_redundant: Vec<String>,
 	pub(crate) edit: Vec<String>,
+	pub(crate) filter: Vec<String>,
 	pub(crate) force_abort: Vec<String>,
 	pub(crate) force_rebase: Vec<String>,
 	pub(crate) help: Vec<String>,
+	pub(crate) insert_exec_template: Vec<String>,
 	pub(crate) insert_line: Vec<String>,
 	pub(crate) move_down: Vec<String>,
 	pub(crate) move_down_step: Vec<String>,
@@ -31,13 +38,25 @@ pub struct KeyBindings {
 	pub(crate) move_up: Vec<String>,
 	pub(crate) move_up_step: Vec<String>,
 	pub(crate) open_in_external_editor: Vec<String>,
+	pub(crate) paste_after: Vec<String>,
+	pub(crate) paste_before: Vec<String>,
 	pub(crate) rebase: Vec<String>,
 	pub(crate) redo: Vec<String>,
+	pub(crate) remove_exec_template: Vec<String>,
 	pub(crate) remove_line: Vec<String>,
+	pub(crate) search: Vec<String>,
+	pub(crate) search_backward: Vec<String>,
+	pub(crate) search_next: Vec<String>,
+	pub(crate) search_previous: Vec<String>,
+	pub(crate) select_register: Vec<String>,
 	pub(crate) show_commit: Vec<String>,
 	pub(crate) show_diff: Vec<String>,
+	pub(crate) show_history: Vec<String>,
+	pub(crate) sort_selection: Vec<String>,
+	pub(crate) toggle_graph: Vec<String>,
 	pub(crate) toggle_visual_mode: Vec<String>,
 	pub(crate) undo: Vec<String>,
+	pub(crate) yank: Vec<String>,
 }
 
 impl KeyBindings {
@@ -52,6 +71,7 @@ impl KeyBindings {
 			.collect();
 		Ok(Self {
 			abort: get_input(git_config, "interactive-rebase-tool.inputAbort", "q")?,
+			absorb: get_input(git_config, "interactive-rebase-tool.inputAbsorb", "F")?,
 			action_break: get_input(git_config, "interactive-rebase-tool.inputActionBreak", "b")?,
 			action_drop: get_input(git_config, "interactive-rebase-tool.inputActionDrop", "d")?,
 			action_edit: get_input(git_config, "interactive-rebase-tool.inputActionEdit", "e")?,
@@ -61,10 +81,16 @@ impl KeyBindings {
 			action_squash: get_input(git_config, "interactive-rebase-tool.inputActionSquash", "s")?,
 			confirm_no,
 			confirm_yes,
+			autosquash: get_input(git_config, "interactive-rebase-tool.inputAutosquash", "A")?,
+			cut: get_input(git_config, "interactive-rebase-tool.inputCut", "x")?,
+			cycle_fixup_message: get_input(git_config, "interactive-rebase-tool.inputCycleFixupMessage", "u")?,
+			drop_redundant: get_input(git_config, "interactive-rebase-tool.inputDropRedundant", "D")?,
 			edit: get_input(git_config, "interactive-rebase-tool.inputEdit", "E")?,
+			filter: get_input(git_config, "interactive-rebase-tool.inputFilter", "|")?,
 			force_abort: get_input(git_config, "interactive-rebase-tool.inputForceAbort", "Q")?,
 			force_rebase: get_input(git_config, "interactive-rebase-tool.inputForceRebase", "W")?,
 			help: get_input(git_config, "interactive-rebase-tool.inputHelp", "?")?,
+			insert_exec_template: get_input(git_config, "interactive-rebase-tool.inputInsertExecTemplate", "X")?,
 			insert_line: get_input(git_config, "interactive-rebase-tool.insertLine", "I")?,
 			move_down: get_input(git_config, "interactive-rebase-tool.inputMoveDown", "Down")?,
 			move_down_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepDown", "PageDown")?,
@@ -77,13 +103,26 @@ impl KeyBindings {
 			move_up_step: get_input(git_config, "interactive-rebase-tool.inputMoveStepUp", "PageUp")?,
 			move_up: get_input(git_config, "interactive-rebase-tool.inputMoveUp", "Up")?,
 			open_in_external_editor: get_input(git_config, "interactive-rebase-tool.inputOpenInExternalEditor", "!")?,
+			paste_after: get_input(git_config, "interactive-rebase-tool.inputPasteAfter", "]")?,
+			paste_before: get_input(git_config, "interactive-rebase-tool.inputPasteBefore", "[")?,
 			rebase: get_input(git_config, "interactive-rebase-tool.inputRebase", "w")?,
 			redo: get_input(git_config, "interactive-rebase-tool.inputRedo", "control+y")?,
+			remove_exec_template: get_input(git_config, "interactive-rebase-tool.inputRemoveExecTemplate", "R")?,
 			remove_line: get_input(git_config, "interactive-rebase-tool.removeLine", "delete")?,
+			search: get_input(git_config, "interactive-rebase-tool.inputSearch", "/")?,
+			// unbound by default, since "?" opens the help
+			search_backward: get_input(git_config, "interactive-rebase-tool.inputSearchBackward", "")?,
+			search_next: get_input(git_config, "interactive-rebase-tool.inputSearchNext", "n")?,
+			search_previous: get_input(git_config, "interactive-rebase-tool.inputSearchPrevious", "N")?,
+			select_register: get_input(git_config, "interactive-rebase-tool.inputSelectRegister", "\"")?,
 			show_commit: get_input(git_config, "interactive-rebase-tool.inputShowCommit", "c")?,
 			show_diff: get_input(git_config, "interactive-rebase-tool.inputShowDiff", "d")?,
+			show_history: get_input(git_config, "interactive-rebase-tool.inputShowHistory", "H")?,
+			sort_selection: get_input(git_config, "interactive-rebase-tool.inputSortSelection", "S")?,
+			toggle_graph: get_input(git_config, "interactive-rebase-tool.inputToggleGraph", "G")?,
 			toggle_visual_mode: get_input(git_config, "interactive-rebase-tool.inputToggleVisualMode", "v")?,
 			undo: get_input(git_config, "interactive-rebase-tool.inputUndo", "control+z")?,
+			yank: get_input(git_config, "interactive-rebase-tool.inputYank", "y")?,
 		})
 	}
 }
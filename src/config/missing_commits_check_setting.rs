@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MissingCommitsCheckSetting {
+	Ignore,
+	Warn,
+	Error,
+}
@@ -2,6 +2,7 @@ pub mod diff_ignore_whitespace_setting;
 pub mod diff_show_whitespace_setting;
 pub mod git_config;
 pub mod key_bindings;
+pub mod missing_commits_check_setting;
 pub mod theme;
 mod utils;
 use anyhow::Result;
@@ -19,6 +20,7 @@ use crate::config::{
 		get_bool,
 		get_diff_ignore_whitespace,
 		get_diff_show_whitespace,
+		get_exec_templates,
 		get_string,
 		get_unsigned_integer,
 		open_git_config,
@@ -33,6 +35,7 @@ pub struct Config {
 	pub(crate) diff_space_symbol: String,
 	pub(crate) diff_tab_symbol: String,
 	pub(crate) diff_tab_width: u32,
+	pub(crate) exec_templates: Vec<(String, String)>,
 	pub(crate) undo_limit: u32,
 	pub(crate) git: GitConfig,
 	pub(crate) key_bindings: KeyBindings,
@@ -53,6 +56,7 @@ impl Config {
 			diff_space_symbol: get_string(git_config, "interactive-rebase-tool.diffSpaceSymbol", "·")?,
 			diff_tab_symbol: get_string(git_config, "interactive-rebase-tool.diffTabSymbol", "→")?,
 			diff_tab_width: get_unsigned_integer(git_config, "interactive-rebase-tool.diffTabWidth", 4)?,
+			exec_templates: get_exec_templates(git_config)?,
 			undo_limit: get_unsigned_integer(git_config, "interactive-rebase-tool.undoLimit", 5000)?,
 			git: GitConfig::new(git_config)?,
 			key_bindings: KeyBindings::new(git_config)?,
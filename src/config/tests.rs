@@ -8,7 +8,7 @@ use serial_test::serial;
 use tempfile::NamedTempFile;
 
 use super::*;
-use crate::display::color::Color;
+use crate::{config::missing_commits_check_setting::MissingCommitsCheckSetting, display::color::Color};
 
 fn load_with_git_config_callback<F>(callback: F) -> Result<Config>
 where F: FnOnce(&mut git2::Config) {
@@ -356,6 +356,40 @@ fn config_diff_tab_width_invalid_range() {
 	);
 }
 
+#[test]
+fn config_exec_templates_default() {
+	let config = load(|_| {});
+	assert!(config.exec_templates.is_empty());
+}
+
+#[test]
+fn config_exec_templates() {
+	let config = load(|git_config| {
+		git_config
+			.set_str("interactive-rebase-tool.execTemplate.test", " cargo test --all ")
+			.unwrap();
+		git_config
+			.set_str("interactive-rebase-tool.execTemplate.build", "cargo build")
+			.unwrap();
+	});
+	assert_eq!(config.exec_templates, vec![
+		(String::from("build"), String::from("cargo build")),
+		(String::from("test"), String::from("cargo test --all"))
+	]);
+}
+
+#[test]
+fn config_exec_templates_empty() {
+	assert_eq!(
+		load_error(|git_config| {
+			git_config
+				.set_str("interactive-rebase-tool.execTemplate.test", " ")
+				.unwrap();
+		}),
+		"Error reading git config: \"interactive-rebase-tool.execTemplate.test\" must not be empty"
+	);
+}
+
 #[test]
 fn config_diff_tab_symbol_default() {
 	let config = load(|_| {});
@@ -457,6 +491,80 @@ fn config_diff_space_symbol_invalid_utf8() {
 	);
 }
 
+#[test]
+fn config_git_abbrev_default() {
+	let config = load(|_| {});
+	assert_eq!(config.git.abbrev, None);
+}
+
+#[test]
+fn config_git_abbrev_auto() {
+	let config = load(|git_config| {
+		git_config.set_str("core.abbrev", "auto").unwrap();
+	});
+	assert_eq!(config.git.abbrev, None);
+}
+
+#[test]
+fn config_git_abbrev_no() {
+	let config = load(|git_config| {
+		git_config.set_str("core.abbrev", "no").unwrap();
+	});
+	assert_eq!(config.git.abbrev, Some(40));
+}
+
+#[test]
+fn config_git_abbrev() {
+	let config = load(|git_config| {
+		git_config.set_str("core.abbrev", "12").unwrap();
+	});
+	assert_eq!(config.git.abbrev, Some(12));
+}
+
+#[test]
+fn config_git_abbrev_invalid() {
+	assert_eq!(
+		load_error(|git_config| {
+			git_config.set_str("core.abbrev", "invalid").unwrap();
+		}),
+		"\"core.abbrev\" is not valid: \"invalid\" must be \"auto\", \"no\" or a number between 4 and 40"
+	);
+}
+
+#[test]
+fn config_git_abbrev_invalid_range() {
+	assert_eq!(
+		load_error(|git_config| {
+			git_config.set_str("core.abbrev", "3").unwrap();
+		}),
+		"\"core.abbrev\" is not valid: \"3\" must be \"auto\", \"no\" or a number between 4 and 40"
+	);
+}
+
+#[test]
+fn config_git_abbreviate_commands_default() {
+	let config = load(|_| {});
+	assert!(!config.git.abbreviate_commands);
+}
+
+#[test]
+fn config_git_abbreviate_commands() {
+	let config = load(|git_config| {
+		git_config.set_bool("rebase.abbreviateCommands", true).unwrap();
+	});
+	assert!(config.git.abbreviate_commands);
+}
+
+#[test]
+fn config_git_abbreviate_commands_invalid() {
+	assert_eq!(
+		load_error(|git_config| {
+			git_config.set_str("rebase.abbreviateCommands", "invalid").unwrap();
+		}),
+		"\"rebase.abbreviateCommands\" is not valid: failed to parse \'invalid\' as a boolean value"
+	);
+}
+
 #[test]
 fn config_git_comment_char_default() {
 	let config = load(|_| {});
@@ -592,6 +700,37 @@ fn config_git_diff_renames_invalid() {
 	);
 }
 
+#[test]
+fn config_git_missing_commits_check_default() {
+	let config = load(|_| {});
+	assert_eq!(config.git.missing_commits_check, MissingCommitsCheckSetting::Ignore);
+}
+
+#[rstest(
+	value,
+	expected,
+	case::ignore("ignore", MissingCommitsCheckSetting::Ignore),
+	case::warn("warn", MissingCommitsCheckSetting::Warn),
+	case::error("error", MissingCommitsCheckSetting::Error),
+	case::mixed_case("WaRn", MissingCommitsCheckSetting::Warn)
+)]
+fn config_git_missing_commits_check(value: &str, expected: MissingCommitsCheckSetting) {
+	let config = load(|git_config| {
+		git_config.set_str("rebase.missingCommitsCheck", value).unwrap();
+	});
+	assert_eq!(config.git.missing_commits_check, expected);
+}
+
+#[test]
+fn config_git_missing_commits_check_invalid() {
+	assert_eq!(
+		load_error(|git_config| {
+			git_config.set_str("rebase.missingCommitsCheck", "invalid").unwrap();
+		}),
+		"\"rebase.missingCommitsCheck\" is not valid: \"invalid\" does not match one of \"ignore\", \"warn\" or \"error\""
+	);
+}
+
 #[test]
 #[serial]
 fn config_git_editor_default_no_env() {
@@ -772,6 +911,20 @@ fn config_key_bindings_abort() {
 	assert_eq!(config.key_bindings.abort, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_absorb_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.absorb, vec![String::from("F")]);
+}
+
+#[test]
+fn config_key_bindings_absorb() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputAbsorb", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.absorb, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_action_break_default() {
 	let config = load(|_| {});
@@ -884,6 +1037,20 @@ fn config_key_bindings_action_squash() {
 	assert_eq!(config.key_bindings.action_squash, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_autosquash_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.autosquash, vec![String::from("A")]);
+}
+
+#[test]
+fn config_key_bindings_autosquash() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputAutosquash", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.autosquash, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_confirm_no_default() {
 	let config = load(|_| {});
@@ -916,6 +1083,48 @@ fn config_key_bindings_confirm_yes() {
 	assert_eq!(config.key_bindings.confirm_yes, vec![String::from("x")]);
 }
 
+#[test]
+fn config_key_bindings_cut_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.cut, vec![String::from("x")]);
+}
+
+#[test]
+fn config_key_bindings_cut() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputCut", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.cut, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_cycle_fixup_message_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.cycle_fixup_message, vec![String::from("u")]);
+}
+
+#[test]
+fn config_key_bindings_cycle_fixup_message() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputCycleFixupMessage", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.cycle_fixup_message, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_drop_redundant_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.drop_redundant, vec![String::from("D")]);
+}
+
+#[test]
+fn config_key_bindings_drop_redundant() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputDropRedundant", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.drop_redundant, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_edit_default() {
 	let config = load(|_| {});
@@ -930,6 +1139,20 @@ fn config_key_bindings_confirm_edit() {
 	assert_eq!(config.key_bindings.edit, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_filter_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.filter, vec![String::from("|")]);
+}
+
+#[test]
+fn config_key_bindings_filter() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputFilter", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.filter, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_force_abort_default() {
 	let config = load(|_| {});
@@ -976,6 +1199,20 @@ fn config_key_bindings_help() {
 	assert_eq!(config.key_bindings.help, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_insert_exec_template_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.insert_exec_template, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_insert_exec_template() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputInsertExecTemplate", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.insert_exec_template, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_insert_line_default() {
 	let config = load(|_| {});
@@ -1162,6 +1399,34 @@ fn config_key_bindings_open_in_external_editor() {
 	assert_eq!(config.key_bindings.open_in_external_editor, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_paste_after_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.paste_after, vec![String::from("]")]);
+}
+
+#[test]
+fn config_key_bindings_paste_after() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputPasteAfter", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.paste_after, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_paste_before_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.paste_before, vec![String::from("[")]);
+}
+
+#[test]
+fn config_key_bindings_paste_before() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputPasteBefore", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.paste_before, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_rebase_default() {
 	let config = load(|_| {});
@@ -1190,6 +1455,20 @@ fn config_key_bindings_redo() {
 	assert_eq!(config.key_bindings.redo, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_remove_exec_template_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.remove_exec_template, vec![String::from("R")]);
+}
+
+#[test]
+fn config_key_bindings_remove_exec_template() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputRemoveExecTemplate", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.remove_exec_template, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_remove_line_default() {
 	let config = load(|_| {});
@@ -1204,6 +1483,76 @@ fn config_key_bindings_remove_line() {
 	assert_eq!(config.key_bindings.remove_line, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_search_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.search, vec![String::from("/")]);
+}
+
+#[test]
+fn config_key_bindings_search() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSearch", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.search, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_search_backward_default() {
+	let config = load(|_| {});
+	assert!(config.key_bindings.search_backward.is_empty());
+}
+
+#[test]
+fn config_key_bindings_search_backward() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSearchBackward", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.search_backward, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_search_next_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.search_next, vec![String::from("n")]);
+}
+
+#[test]
+fn config_key_bindings_search_next() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSearchNext", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.search_next, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_search_previous_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.search_previous, vec![String::from("N")]);
+}
+
+#[test]
+fn config_key_bindings_search_previous() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSearchPrevious", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.search_previous, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_select_register_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.select_register, vec![String::from("\"")]);
+}
+
+#[test]
+fn config_key_bindings_select_register() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSelectRegister", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.select_register, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_show_commit_default() {
 	let config = load(|_| {});
@@ -1236,6 +1585,48 @@ fn config_key_bindings_show_diff() {
 	assert_eq!(config.key_bindings.show_diff, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_show_history_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.show_history, vec![String::from("H")]);
+}
+
+#[test]
+fn config_key_bindings_show_history() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputShowHistory", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.show_history, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_sort_selection_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.sort_selection, vec![String::from("S")]);
+}
+
+#[test]
+fn config_key_bindings_sort_selection() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputSortSelection", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.sort_selection, vec![String::from("X")]);
+}
+
+#[test]
+fn config_key_bindings_toggle_graph_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.toggle_graph, vec![String::from("G")]);
+}
+
+#[test]
+fn config_key_bindings_toggle_graph() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputToggleGraph", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.toggle_graph, vec![String::from("X")]);
+}
+
 #[test]
 fn config_key_bindings_toggle_visual_mode_default() {
 	let config = load(|_| {});
@@ -1266,6 +1657,20 @@ fn config_key_bindings_undo() {
 	assert_eq!(config.key_bindings.undo, vec![String::from("X")]);
 }
 
+#[test]
+fn config_key_bindings_yank_default() {
+	let config = load(|_| {});
+	assert_eq!(config.key_bindings.yank, vec![String::from("y")]);
+}
+
+#[test]
+fn config_key_bindings_yank() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.inputYank", "X").unwrap();
+	});
+	assert_eq!(config.key_bindings.yank, vec![String::from("X")]);
+}
+
 #[test]
 fn config_theme_character_vertical_spacing_default() {
 	let config = load(|_| {});
@@ -1436,6 +1841,20 @@ fn config_theme_color_action_merge() {
 	assert_eq!(config.theme.color_action_merge, Color::Index(10));
 }
 
+#[test]
+fn config_theme_color_action_update_ref_default() {
+	let config = load(|_| {});
+	assert_eq!(config.theme.color_action_update_ref, Color::DarkMagenta);
+}
+
+#[test]
+fn config_theme_color_action_update_ref() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.updateRefColor", "10").unwrap();
+	});
+	assert_eq!(config.theme.color_action_update_ref, Color::Index(10));
+}
+
 #[test]
 fn config_theme_color_background_default() {
 	let config = load(|_| {});
@@ -1564,6 +1983,20 @@ fn config_theme_color_indicator() {
 	assert_eq!(config.theme.color_indicator, Color::Index(10));
 }
 
+#[test]
+fn config_theme_color_warning_default() {
+	let config = load(|_| {});
+	assert_eq!(config.theme.color_warning, Color::LightYellow);
+}
+
+#[test]
+fn config_theme_color_warning() {
+	let config = load(|git_config| {
+		git_config.set_str("interactive-rebase-tool.warningColor", "10").unwrap();
+	});
+	assert_eq!(config.theme.color_warning, Color::Index(10));
+}
+
 #[test]
 fn config_theme_color_selected_background_default() {
 	let config = load(|_| {});
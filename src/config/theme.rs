@@ -20,6 +20,7 @@ pub struct Theme {
 	pub(crate) color_action_label: Color,
 	pub(crate) color_action_reset: Color,
 	pub(crate) color_action_merge: Color,
+	pub(crate) color_action_update_ref: Color,
 	pub(crate) color_background: Color,
 	pub(crate) color_diff_add: Color,
 	pub(crate) color_diff_change: Color,
@@ -28,6 +29,7 @@ pub struct Theme {
 	pub(crate) color_diff_whitespace: Color,
 	pub(crate) color_foreground: Color,
 	pub(crate) color_indicator: Color,
+	pub(crate) color_warning: Color,
 	pub(crate) color_selected_background: Color,
 }
 
@@ -50,6 +52,11 @@ impl Theme {
 			color_action_label: get_color(git_config, "interactive-rebase-tool.labelColor", Color::DarkYellow)?,
 			color_action_reset: get_color(git_config, "interactive-rebase-tool.resetColor", Color::DarkYellow)?,
 			color_action_merge: get_color(git_config, "interactive-rebase-tool.mergeColor", Color::DarkYellow)?,
+			color_action_update_ref: get_color(
+				git_config,
+				"interactive-rebase-tool.updateRefColor",
+				Color::DarkMagenta,
+			)?,
 			color_background: get_color(git_config, "interactive-rebase-tool.backgroundColor", Color::Default)?,
 			color_diff_add: get_color(git_config, "interactive-rebase-tool.diffAddColor", Color::LightGreen)?,
 			color_diff_change: get_color(
@@ -66,6 +73,7 @@ impl Theme {
 			color_diff_whitespace: get_color(git_config, "interactive-rebase-tool.diffWhitespace", Color::LightBlack)?,
 			color_foreground: get_color(git_config, "interactive-rebase-tool.foregroundColor", Color::Default)?,
 			color_indicator: get_color(git_config, "interactive-rebase-tool.indicatorColor", Color::LightCyan)?,
+			color_warning: get_color(git_config, "interactive-rebase-tool.warningColor", Color::LightYellow)?,
 			color_selected_background: get_color(
 				git_config,
 				"interactive-rebase-tool.selectedBackgroundColor",
@@ -10,6 +10,7 @@ use crate::{
 	config::{
 		diff_ignore_whitespace_setting::DiffIgnoreWhitespaceSetting,
 		diff_show_whitespace_setting::DiffShowWhitespaceSetting,
+		missing_commits_check_setting::MissingCommitsCheckSetting,
 	},
 	display::color::Color,
 };
@@ -112,6 +113,53 @@ pub(super) fn get_color(config: &Config, name: &str, default_color: Color) -> Re
 	.map_err(|e| e.context(anyhow!("\"{}\" is not valid", name)))
 }
 
+// Templates are named by the last part of their key, such as `test` in `interactive-rebase-tool.execTemplate.test`,
+// and are sorted by name.
+pub(super) fn get_exec_templates(config: &Config) -> Result<Vec<(String, String)>> {
+	let prefix = "interactive-rebase-tool.exectemplate.";
+	let mut names = vec![];
+	let entries = config
+		.entries(Some("interactive-rebase-tool"))
+		.map_err(|e| anyhow!(String::from(e.message())))?;
+	for entry in &entries {
+		let entry = entry.map_err(|e| anyhow!(String::from(e.message())))?;
+		if let Some(name) = entry.name() {
+			if name.len() > prefix.len() && name.to_lowercase().starts_with(prefix) {
+				names.push(String::from(name));
+			}
+		}
+	}
+	names.sort();
+	names.dedup();
+
+	let mut templates = vec![];
+	for name in names {
+		let command = get_string(config, name.as_str(), "")?;
+		if command.trim().is_empty() {
+			return Err(anyhow!("\"{}\" must not be empty", name).context("Error reading git config"));
+		}
+		templates.push((String::from(&name[prefix.len()..]), String::from(command.trim())));
+	}
+	Ok(templates)
+}
+
+pub(super) fn get_missing_commits_check(git_config: &Config) -> Result<MissingCommitsCheckSetting> {
+	let missing_commits_check = get_string(git_config, "rebase.missingCommitsCheck", "ignore")?;
+
+	match missing_commits_check.to_lowercase().as_str() {
+		"ignore" => Ok(MissingCommitsCheckSetting::Ignore),
+		"warn" => Ok(MissingCommitsCheckSetting::Warn),
+		"error" => Ok(MissingCommitsCheckSetting::Error),
+		_ => {
+			Err(anyhow!(
+				"\"{}\" does not match one of \"ignore\", \"warn\" or \"error\"",
+				missing_commits_check
+			)
+			.context("\"rebase.missingCommitsCheck\" is not valid"))
+		},
+	}
+}
+
 pub(super) fn editor_from_env() -> String {
 	env::var("VISUAL")
 		.or_else(|_| env::var("EDITOR"))
@@ -0,0 +1,5 @@
+#[derive(Debug, PartialEq)]
+pub enum ConfirmRebaseState {
+	Confirm,
+	MissingCommits,
+}
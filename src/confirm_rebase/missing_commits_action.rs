@@ -0,0 +1,6 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum MissingCommitsAction {
+	DropCommits,
+	Continue,
+	Return,
+}
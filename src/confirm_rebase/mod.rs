@@ -1,22 +1,94 @@
+mod confirm_rebase_state;
+mod missing_commits_action;
+
 use crate::{
-	components::Confirm,
+	components::{Choice, Confirm},
+	config::missing_commits_check_setting::MissingCommitsCheckSetting,
+	confirm_rebase::{confirm_rebase_state::ConfirmRebaseState, missing_commits_action::MissingCommitsAction},
+	display::display_color::DisplayColor,
 	input::input_handler::InputMode,
 	process::{exit_status::ExitStatus, process_module::ProcessModule, process_result::ProcessResult, state::State},
 	todo_file::TodoFile,
-	view::{view_data::ViewData, View},
+	view::{line_segment::LineSegment, view_data::ViewData, view_line::ViewLine, View},
 };
 
 pub struct ConfirmRebase {
 	dialog: Confirm,
+	missing_commits_check: MissingCommitsCheckSetting,
+	missing_commits_choice: Choice<MissingCommitsAction>,
+	state: ConfirmRebaseState,
 }
 
 impl ProcessModule for ConfirmRebase {
-	fn build_view_data(&mut self, view: &View<'_>, _: &TodoFile) -> &ViewData {
+	fn activate(&mut self, todo_file: &TodoFile, _: State) -> ProcessResult {
+		self.state = ConfirmRebaseState::Confirm;
+		if self.missing_commits_check != MissingCommitsCheckSetting::Ignore {
+			let missing_commits = todo_file.get_missing_commits();
+			if !missing_commits.is_empty() {
+				let mut prompt = vec![ViewLine::from(LineSegment::new_with_color(
+					if self.missing_commits_check == MissingCommitsCheckSetting::Error {
+						"Error: some commits were removed without being dropped."
+					}
+					else {
+						"Warning: some commits may have been dropped accidentally."
+					},
+					DisplayColor::WarningColor,
+				))];
+				prompt.push(ViewLine::from("Removed commits:"));
+				for line in missing_commits {
+					prompt.push(ViewLine::from(format!(" - {} {}", line.get_hash(), line.get_content())));
+				}
+				prompt.push(ViewLine::from("Use \"drop\" to explicitly remove a commit."));
+				self.missing_commits_choice.set_prompt(prompt);
+				self.state = ConfirmRebaseState::MissingCommits;
+			}
+		}
+		ProcessResult::new()
+	}
+
+	fn build_view_data(&mut self, view: &View<'_>, todo_file: &TodoFile) -> &ViewData {
 		let view_size = view.get_view_size();
+		if self.state == ConfirmRebaseState::MissingCommits {
+			return self
+				.missing_commits_choice
+				.get_view_data(view_size.width(), view_size.height());
+		}
+		let problems = todo_file.get_problems();
+		let mut details = vec![];
+		if !problems.is_empty() {
+			details.push(ViewLine::from(LineSegment::new_with_color(
+				"The following problems were found in the todo list:",
+				DisplayColor::WarningColor,
+			)));
+			for problem in problems {
+				details.push(ViewLine::from(format!(
+					" Line {}: {}",
+					problem.get_index() + 1,
+					problem.get_message()
+				)));
+			}
+		}
+		self.dialog.set_details(details);
 		self.dialog.get_view_data(view_size.width(), view_size.height())
 	}
 
-	fn handle_input(&mut self, view: &mut View<'_>, _: &mut TodoFile) -> ProcessResult {
+	fn handle_input(&mut self, view: &mut View<'_>, todo_file: &mut TodoFile) -> ProcessResult {
+		if self.state == ConfirmRebaseState::MissingCommits {
+			let input = view.get_input(InputMode::Default);
+			let mut result = ProcessResult::new().input(input);
+			if let Some(action) = self.missing_commits_choice.handle_input(input) {
+				match *action {
+					MissingCommitsAction::DropCommits => {
+						todo_file.drop_missing_commits();
+						self.state = ConfirmRebaseState::Confirm;
+					},
+					MissingCommitsAction::Continue => self.state = ConfirmRebaseState::Confirm,
+					MissingCommitsAction::Return => result = result.state(State::List),
+				}
+			}
+			return result;
+		}
+
 		let input = view.get_input(InputMode::Confirm);
 		let mut result = ProcessResult::new().input(input);
 		if let Some(confirmed) = self.dialog.handle_input(input) {
@@ -32,9 +104,42 @@ impl ProcessModule for ConfirmRebase {
 }
 
 impl ConfirmRebase {
-	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String]) -> Self {
+	pub(crate) fn new(
+		confirm_yes: &[String],
+		confirm_no: &[String],
+		missing_commits_check: MissingCommitsCheckSetting,
+	) -> Self {
+		// git refuses to continue on error, so only offer to continue on warn
+		let missing_commits_choice = if missing_commits_check == MissingCommitsCheckSetting::Error {
+			Choice::new(vec![
+				(
+					MissingCommitsAction::DropCommits,
+					'1',
+					String::from("Add drop lines for the removed commits"),
+				),
+				(MissingCommitsAction::Return, '2', String::from("Return to the list")),
+			])
+		}
+		else {
+			Choice::new(vec![
+				(
+					MissingCommitsAction::DropCommits,
+					'1',
+					String::from("Add drop lines for the removed commits"),
+				),
+				(
+					MissingCommitsAction::Continue,
+					'2',
+					String::from("Continue without the removed commits"),
+				),
+				(MissingCommitsAction::Return, '3', String::from("Return to the list")),
+			])
+		};
 		Self {
 			dialog: Confirm::new("Are you sure you want to rebase", confirm_yes, confirm_no),
+			missing_commits_check,
+			missing_commits_choice,
+			state: ConfirmRebaseState::Confirm,
 		}
 	}
 }
@@ -43,12 +148,22 @@ impl ConfirmRebase {
 mod tests {
 	use super::*;
 	use crate::{
-		assert_process_result,
-		assert_rendered_output,
+		assert_process_result, assert_rendered_output,
 		input::Input,
 		process::testutil::{process_module_test, TestContext, ViewState},
 	};
 
+	fn create_module(
+		test_context: &TestContext<'_>,
+		missing_commits_check: MissingCommitsCheckSetting,
+	) -> ConfirmRebase {
+		ConfirmRebase::new(
+			&test_context.config.key_bindings.confirm_yes,
+			&test_context.config.key_bindings.confirm_no,
+			missing_commits_check,
+		)
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn build_view_data() {
@@ -60,6 +175,7 @@ mod tests {
 				let mut module = ConfirmRebase::new(
 					&test_context.config.key_bindings.confirm_yes,
 					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
 				);
 				let view_data = test_context.build_view_data(&mut module);
 				assert_rendered_output!(
@@ -72,6 +188,57 @@ mod tests {
 		);
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_with_problems() {
+		process_module_test(
+			&["fixup aaaa comment", "pick bbbb comment", "reset feature", "label feature"],
+			ViewState::default(),
+			&[],
+			|test_context: TestContext<'_>| {
+				let mut module = ConfirmRebase::new(
+					&test_context.config.key_bindings.confirm_yes,
+					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
+				);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{BODY}",
+					"{Normal}Are you sure you want to rebase (y/n)? ",
+					"",
+					"{WarningColor}The following problems were found in the todo list:",
+					"{Normal} Line 1: Cannot fixup without a previous commit",
+					"{Normal} Line 3: Label 'feature' is not defined"
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn handle_input_yes_with_problems() {
+		process_module_test(
+			&["fixup aaaa comment", "pick bbbb comment"],
+			ViewState::default(),
+			&[Input::Yes],
+			|mut test_context: TestContext<'_>| {
+				let mut module = ConfirmRebase::new(
+					&test_context.config.key_bindings.confirm_yes,
+					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
+				);
+				test_context.build_view_data(&mut module);
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Yes,
+					exit_status = ExitStatus::Good
+				);
+			},
+		);
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn handle_input_yes() {
@@ -83,6 +250,7 @@ mod tests {
 				let mut module = ConfirmRebase::new(
 					&test_context.config.key_bindings.confirm_yes,
 					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
 				);
 				assert_process_result!(
 					test_context.handle_input(&mut module),
@@ -105,6 +273,7 @@ mod tests {
 				let mut module = ConfirmRebase::new(
 					&test_context.config.key_bindings.confirm_yes,
 					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
 				);
 				assert_process_result!(
 					test_context.handle_input(&mut module),
@@ -126,9 +295,166 @@ mod tests {
 				let mut module = ConfirmRebase::new(
 					&test_context.config.key_bindings.confirm_yes,
 					&test_context.config.key_bindings.confirm_no,
+					MissingCommitsCheckSetting::Ignore,
 				);
 				assert_process_result!(test_context.handle_input(&mut module), input = Input::Resize);
 			},
 		);
 	}
+
+	#[test]
+	#[serial_test::serial]
+	fn activate_missing_commits_ignore() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Ignore);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_eq!(module.state, ConfirmRebaseState::Confirm);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn activate_no_missing_commits() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[],
+			|test_context: TestContext<'_>| {
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Error);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_eq!(module.state, ConfirmRebaseState::Confirm);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_missing_commits_warn() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Warn);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{WarningColor}Warning: some commits may have been dropped accidentally.",
+					"{Normal}Removed commits:",
+					"{Normal} - aaa comment",
+					"{Normal}Use \"drop\" to explicitly remove a commit.",
+					"",
+					"{BODY}",
+					"{Normal}1) Add drop lines for the removed commits",
+					"{Normal}2) Continue without the removed commits",
+					"{Normal}3) Return to the list",
+					"",
+					"{IndicatorColor}Please choose an option."
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_missing_commits_error() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 1);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Error);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{WarningColor}Error: some commits were removed without being dropped.",
+					"{Normal}Removed commits:",
+					"{Normal} - aaa comment",
+					"{Normal} - bbb comment2",
+					"{Normal}Use \"drop\" to explicitly remove a commit.",
+					"",
+					"{BODY}",
+					"{Normal}1) Add drop lines for the removed commits",
+					"{Normal}2) Return to the list",
+					"",
+					"{IndicatorColor}Please choose an option."
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn handle_input_missing_commits_drop() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[Input::Character('1')],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Warn);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(test_context.handle_input(&mut module), input = Input::Character('1'));
+				assert_eq!(module.state, ConfirmRebaseState::Confirm);
+				assert_eq!(
+					test_context.rebase_todo_file.get_line(1).unwrap().to_text(),
+					"drop aaa comment"
+				);
+				assert!(test_context.rebase_todo_file.get_missing_commits().is_empty());
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn handle_input_missing_commits_continue() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[Input::Character('2')],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Warn);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(test_context.handle_input(&mut module), input = Input::Character('2'));
+				assert_eq!(module.state, ConfirmRebaseState::Confirm);
+				assert_eq!(test_context.rebase_todo_file.get_lines_owned().len(), 1);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn handle_input_missing_commits_return() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment2"],
+			ViewState::default(),
+			&[Input::Character('2')],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				let mut module = create_module(&test_context, MissingCommitsCheckSetting::Error);
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Character('2'),
+					state = State::List
+				);
+				assert_eq!(module.state, ConfirmRebaseState::MissingCommits);
+			},
+		);
+	}
 }
@@ -1,9 +1,12 @@
-use std::io::{stdout, BufWriter, Stdout, Write};
+use std::{
+	io::{stdout, BufWriter, Stdout, Write},
+	time::Duration,
+};
 
 use anyhow::{anyhow, Error, Result};
 use crossterm::{
 	cursor::{Hide, MoveTo, MoveToColumn, MoveToNextLine, Show},
-	event::{read, DisableMouseCapture, EnableMouseCapture},
+	event::{poll, read, DisableMouseCapture, EnableMouseCapture},
 	style::{available_color_count, Attribute, Print, ResetColor, SetAttribute, SetColors},
 	terminal::{
 		disable_raw_mode,
@@ -21,7 +24,7 @@ use crossterm::{
 	QueueableCommand,
 };
 pub use crossterm::{
-	event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind},
+	event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
 	style::{Color, Colors},
 };
 
@@ -118,6 +121,10 @@ impl CrossTerm {
 		read().map_err(Self::map_err)
 	}
 
+	pub(crate) fn poll_event(timeout: Duration) -> Result<bool> {
+		poll(timeout).map_err(Self::map_err)
+	}
+
 	#[allow(clippy::unused_self)]
 	pub(super) fn get_size(&self) -> Size {
 		size().map_or_else(
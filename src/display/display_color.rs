@@ -11,11 +11,13 @@ pub enum DisplayColor {
 	ActionLabel,
 	ActionReset,
 	ActionMerge,
+	ActionUpdateRef,
 	DiffAddColor,
 	DiffChangeColor,
 	DiffRemoveColor,
 	DiffContextColor,
 	DiffWhitespaceColor,
 	IndicatorColor,
+	WarningColor,
 	Normal,
 }
@@ -1,9 +1,9 @@
-use std::sync::Mutex;
+use std::{sync::Mutex, time::Duration};
 
 use anyhow::{anyhow, Result};
 use crossterm::style::{Attribute, Attributes};
 pub use crossterm::{
-	event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind},
+	event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
 	style::{Color, Colors},
 };
 use lazy_static::lazy_static;
@@ -166,6 +166,11 @@ impl CrossTerm {
 		Ok(())
 	}
 
+	#[allow(clippy::unnecessary_wraps)]
+	pub(crate) const fn poll_event(_timeout: Duration) -> Result<bool> {
+		Ok(true)
+	}
+
 	pub(crate) fn read_event() -> Result<Event> {
 		if let Some(input) = INPUT
 			.lock()
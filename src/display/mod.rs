@@ -10,9 +10,11 @@ mod crossterm;
 mod mockcrossterm;
 #[cfg(test)]
 pub mod testutil;
+use std::time::Duration;
+
 use anyhow::Result;
 use ct::Color as CrosstermColor;
-pub use ct::{Colors, CrossTerm, Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind};
+pub use ct::{Colors, CrossTerm, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 #[cfg(test)]
 use mockcrossterm as ct;
 
@@ -41,12 +43,14 @@ pub struct Display<'d> {
 	action_label: (Colors, Colors),
 	action_reset: (Colors, Colors),
 	action_merge: (Colors, Colors),
+	action_update_ref: (Colors, Colors),
 	diff_add: (Colors, Colors),
 	diff_change: (Colors, Colors),
 	diff_remove: (Colors, Colors),
 	diff_context: (Colors, Colors),
 	diff_whitespace: (Colors, Colors),
 	indicator: (Colors, Colors),
+	warning: (Colors, Colors),
 	normal: (Colors, Colors),
 }
 
@@ -65,6 +69,12 @@ impl<'d> Display<'d> {
 			theme.color_background,
 			theme.color_selected_background,
 		);
+		let warning = register_selectable_color_pairs(
+			color_mode,
+			theme.color_warning,
+			theme.color_background,
+			theme.color_selected_background,
+		);
 		let action_break = register_selectable_color_pairs(
 			color_mode,
 			theme.color_action_break,
@@ -131,6 +141,12 @@ impl<'d> Display<'d> {
 			theme.color_background,
 			theme.color_selected_background,
 		);
+		let action_update_ref = register_selectable_color_pairs(
+			color_mode,
+			theme.color_action_update_ref,
+			theme.color_background,
+			theme.color_selected_background,
+		);
 		let diff_add = register_selectable_color_pairs(
 			color_mode,
 			theme.color_diff_add,
@@ -167,6 +183,7 @@ impl<'d> Display<'d> {
 			input_handler,
 			normal,
 			indicator,
+			warning,
 			action_break,
 			action_drop,
 			action_edit,
@@ -178,6 +195,7 @@ impl<'d> Display<'d> {
 			action_label,
 			action_reset,
 			action_merge,
+			action_update_ref,
 			diff_add,
 			diff_change,
 			diff_remove,
@@ -215,8 +233,10 @@ impl<'d> Display<'d> {
 					DisplayColor::ActionLabel => self.action_label.1,
 					DisplayColor::ActionReset => self.action_reset.1,
 					DisplayColor::ActionMerge => self.action_merge.1,
+					DisplayColor::ActionUpdateRef => self.action_update_ref.1,
 					DisplayColor::Normal => self.normal.1,
 					DisplayColor::IndicatorColor => self.indicator.1,
+					DisplayColor::WarningColor => self.warning.1,
 					DisplayColor::DiffAddColor => self.diff_add.1,
 					DisplayColor::DiffRemoveColor => self.diff_remove.1,
 					DisplayColor::DiffChangeColor => self.diff_change.1,
@@ -237,8 +257,10 @@ impl<'d> Display<'d> {
 					DisplayColor::ActionLabel => self.action_label.0,
 					DisplayColor::ActionReset => self.action_reset.0,
 					DisplayColor::ActionMerge => self.action_merge.0,
+					DisplayColor::ActionUpdateRef => self.action_update_ref.0,
 					DisplayColor::Normal => self.normal.0,
 					DisplayColor::IndicatorColor => self.indicator.0,
+					DisplayColor::WarningColor => self.warning.0,
 					DisplayColor::DiffAddColor => self.diff_add.0,
 					DisplayColor::DiffRemoveColor => self.diff_remove.0,
 					DisplayColor::DiffChangeColor => self.diff_change.0,
@@ -281,6 +303,12 @@ impl<'d> Display<'d> {
 		}
 	}
 
+	// an error is treated as ready, so that reading the input reports it
+	#[allow(clippy::unused_self)]
+	pub(crate) fn is_input_ready(&self, timeout: Duration) -> bool {
+		CrossTerm::poll_event(timeout).unwrap_or(true)
+	}
+
 	pub(crate) fn ensure_at_line_start(&mut self) -> Result<()> {
 		self.crossterm.move_to_column(1)
 	}
@@ -452,6 +480,18 @@ mod tests {
 			CrosstermColor::DarkYellow,
 			CrosstermColor::AnsiValue(237)
 		),
+		case::action_update_ref(
+			DisplayColor::ActionUpdateRef,
+			false,
+			CrosstermColor::DarkMagenta,
+			CrosstermColor::Reset
+		),
+		case::action_update_ref_selected(
+			DisplayColor::ActionUpdateRef,
+			true,
+			CrosstermColor::DarkMagenta,
+			CrosstermColor::AnsiValue(237)
+		),
 		case::normal(DisplayColor::Normal, false, CrosstermColor::Reset, CrosstermColor::Reset),
 		case::normal_selected(DisplayColor::Normal, true, CrosstermColor::Reset, CrosstermColor::AnsiValue(237)),
 		case::indicator(DisplayColor::IndicatorColor, false, CrosstermColor::Cyan, CrosstermColor::Reset),
@@ -461,6 +501,8 @@ mod tests {
 			CrosstermColor::Cyan,
 			CrosstermColor::AnsiValue(237)
 		),
+		case::warning(DisplayColor::WarningColor, false, CrosstermColor::Yellow, CrosstermColor::Reset),
+		case::warning_selected(DisplayColor::WarningColor, true, CrosstermColor::Yellow, CrosstermColor::AnsiValue(237)),
 		case::diff_add(DisplayColor::DiffAddColor, false, CrosstermColor::Green, CrosstermColor::Reset),
 		case::diff_add_selected(
 			DisplayColor::DiffAddColor,
@@ -0,0 +1,19 @@
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Conflict {
+	index: usize,
+	paths: Vec<String>,
+}
+
+impl Conflict {
+	pub(super) const fn new(index: usize, paths: Vec<String>) -> Self {
+		Self { index, paths }
+	}
+
+	pub(crate) const fn get_index(&self) -> usize {
+		self.index
+	}
+
+	pub(crate) fn get_paths(&self) -> &[String] {
+		self.paths.as_slice()
+	}
+}
@@ -0,0 +1,274 @@
+mod conflict;
+mod simulate;
+
+use std::{
+	fs::read_to_string,
+	path::Path,
+	sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+	thread,
+};
+
+use git2::Repository;
+
+pub(crate) use self::conflict::Conflict;
+use crate::{
+	dry_run::simulate::simulate,
+	todo_file::{line::Line, TodoFile},
+};
+
+// higher than the priorities of the loose and pack backends, so that new objects are written to memory
+const MEMPACK_PRIORITY: i32 = 1000;
+
+// the generation of the simulated lines, the conflicts and the index of the line that could not be applied
+type DryRunResult = (usize, Vec<Conflict>, Option<usize>);
+
+// Simulates the rebase in a background thread, so that the lines can be edited while it runs. Only the most recent
+// lines are simulated, and a result for older lines is ignored.
+pub(crate) struct DryRun {
+	conflicts: Vec<Conflict>,
+	failed_index: Option<usize>,
+	generation: usize,
+	is_pending: bool,
+	is_started: bool,
+	receiver: Option<Receiver<DryRunResult>>,
+	sender: Option<Sender<(usize, Vec<Line>)>>,
+	simulated_lines: Vec<String>,
+}
+
+impl DryRun {
+	pub(crate) const fn new() -> Self {
+		Self {
+			conflicts: vec![],
+			failed_index: None,
+			generation: 0,
+			is_pending: false,
+			is_started: false,
+			receiver: None,
+			sender: None,
+			simulated_lines: vec![],
+		}
+	}
+
+	// the onto commit is only known during a rebase, so without it there is nothing to simulate, and once lines have
+	// been applied the rebase continues from the current commit
+	fn start(&mut self, todo_file: &TodoFile) {
+		self.is_started = true;
+		let base = match read_to_string(Path::new(todo_file.get_filepath()).with_file_name("onto")) {
+			Ok(_) if !todo_file.get_done_lines().is_empty() => String::from("HEAD"),
+			Ok(onto) => String::from(onto.trim()),
+			Err(_) => return,
+		};
+		let (request_sender, request_receiver) = channel::<(usize, Vec<Line>)>();
+		let (result_sender, result_receiver) = channel();
+		let _handle = thread::spawn(move || {
+			let repo = match Repository::open_from_env() {
+				Ok(repo) => repo,
+				Err(_) => return,
+			};
+			// the written trees are kept in memory, so nothing is added to the object database of the repository
+			let odb = match repo.odb() {
+				Ok(odb) => odb,
+				Err(_) => return,
+			};
+			let mempack = match odb.add_new_mempack_backend(MEMPACK_PRIORITY) {
+				Ok(mempack) => mempack,
+				Err(_) => return,
+			};
+			while let Ok(mut request) = request_receiver.recv() {
+				while let Ok(newer_request) = request_receiver.try_recv() {
+					request = newer_request;
+				}
+				let (generation, lines) = request;
+				// a base that can not be loaded leaves nothing to check
+				let (conflicts, failed_index) = simulate(&repo, base.as_str(), &lines).unwrap_or_default();
+				if mempack.reset().is_err() || result_sender.send((generation, conflicts, failed_index)).is_err() {
+					break;
+				}
+			}
+		});
+		self.receiver = Some(result_receiver);
+		self.sender = Some(request_sender);
+	}
+
+	fn receive(&mut self) {
+		if let Some(ref receiver) = self.receiver {
+			loop {
+				match receiver.try_recv() {
+					Ok((generation, conflicts, failed_index)) => {
+						if generation == self.generation {
+							self.conflicts = conflicts;
+							self.failed_index = failed_index;
+							self.is_pending = false;
+						}
+					},
+					Err(TryRecvError::Empty) => break,
+					Err(TryRecvError::Disconnected) => {
+						self.is_pending = false;
+						self.receiver = None;
+						self.sender = None;
+						break;
+					},
+				}
+			}
+		}
+	}
+
+	pub(crate) fn update(&mut self, todo_file: &TodoFile) {
+		if !self.is_started {
+			self.start(todo_file);
+		}
+		self.receive();
+
+		if let Some(ref sender) = self.sender {
+			if todo_file.iter().map(Line::to_text).eq(self.simulated_lines.iter().cloned()) {
+				return;
+			}
+			self.generation += 1;
+			self.simulated_lines = todo_file.iter().map(Line::to_text).collect();
+			// the line indexes of a previous result no longer match the lines
+			self.conflicts.clear();
+			self.failed_index = None;
+			self.is_pending = sender.send((self.generation, todo_file.get_lines_owned())).is_ok();
+		}
+	}
+
+	pub(crate) const fn is_pending(&self) -> bool {
+		self.is_pending
+	}
+
+	pub(crate) fn get_conflicts(&self) -> &[Conflict] {
+		self.conflicts.as_slice()
+	}
+
+	// the index of the line that could not be applied, the lines after it were not checked
+	pub(crate) const fn get_failed_index(&self) -> Option<usize> {
+		self.failed_index
+	}
+
+	#[cfg(test)]
+	pub(crate) fn wait(&mut self) {
+		while self.is_pending {
+			thread::sleep(std::time::Duration::from_millis(10));
+			self.receive();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, fs::write, path::Path};
+
+	use serial_test::serial;
+	use tempfile::{tempdir, TempDir};
+
+	use super::*;
+
+	fn create_todo_file(onto: Option<&str>, lines: &[&str]) -> (TodoFile, TempDir) {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		let rebase_dir = tempdir().unwrap();
+		if let Some(onto) = onto {
+			write(rebase_dir.path().join("onto"), format!("{}\n", onto)).unwrap();
+		}
+		let mut todo_file = TodoFile::new(rebase_dir.path().join("git-rebase-todo").to_str().unwrap(), 1, "#");
+		todo_file.set_lines(lines.iter().map(|l| Line::new(l).unwrap()).collect());
+		(todo_file, rebase_dir)
+	}
+
+	#[test]
+	#[serial]
+	fn update_without_onto() {
+		let (todo_file, _rebase_dir) = create_todo_file(None, &["pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base"]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		assert!(!dry_run.is_pending());
+		assert!(dry_run.get_conflicts().is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn update_with_conflict() {
+		let (todo_file, _rebase_dir) = create_todo_file(Some("1cc0456637cb220155e957c641f483e60724c581"), &[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		assert!(dry_run.is_pending());
+		dry_run.wait();
+		let conflict = &dry_run.get_conflicts()[0];
+		assert_eq!(conflict.get_index(), 1);
+		assert_eq!(conflict.get_paths(), &[String::from("a")]);
+	}
+
+	#[test]
+	#[serial]
+	fn update_with_invalid_commit() {
+		let (todo_file, _rebase_dir) = create_todo_file(Some("1cc0456637cb220155e957c641f483e60724c581"), &[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 0123456789 c1",
+		]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		dry_run.wait();
+		assert_eq!(dry_run.get_conflicts()[0].get_index(), 1);
+		assert_eq!(dry_run.get_failed_index(), Some(2));
+	}
+
+	#[test]
+	#[serial]
+	fn update_with_done_lines_starts_from_head() {
+		let (mut todo_file, _rebase_dir) = create_todo_file(Some("2836dcdcbd040f9157652dd3db0d584a44d4793d"), &[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		dry_run.wait();
+		assert!(dry_run.get_conflicts().is_empty());
+
+		todo_file.set_done_lines(vec![Line::new("pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved").unwrap()]);
+		let mut dry_run_with_done_lines = DryRun::new();
+		dry_run_with_done_lines.update(&todo_file);
+		dry_run_with_done_lines.wait();
+		assert_eq!(dry_run_with_done_lines.get_conflicts()[0].get_index(), 0);
+	}
+
+	#[test]
+	#[serial]
+	fn update_reruns_after_edit() {
+		let (mut todo_file, _rebase_dir) = create_todo_file(Some("1cc0456637cb220155e957c641f483e60724c581"), &[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		dry_run.wait();
+		todo_file.remove_lines(1, 1);
+		dry_run.update(&todo_file);
+		assert!(dry_run.get_conflicts().is_empty());
+		assert!(dry_run.is_pending());
+		dry_run.wait();
+		assert!(dry_run.get_conflicts().is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn update_without_changes() {
+		let (todo_file, _rebase_dir) = create_todo_file(Some("1cc0456637cb220155e957c641f483e60724c581"), &[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+		]);
+		let mut dry_run = DryRun::new();
+		dry_run.update(&todo_file);
+		dry_run.wait();
+		dry_run.update(&todo_file);
+		assert!(!dry_run.is_pending());
+	}
+}
@@ -0,0 +1,252 @@
+use std::{collections::HashMap, path::Path};
+
+use git2::{Error, Index, Oid, Repository};
+
+use crate::{
+	dry_run::conflict::Conflict,
+	todo_file::{action::Action, line::Line},
+};
+
+fn get_reference(line: &Line) -> &str {
+	line.get_content().split_whitespace().next().unwrap_or("")
+}
+
+// the stage bits of an index entry flags, a conflict entry is at a stage above zero
+const INDEX_ENTRY_STAGE_MASK: u16 = 0x3000;
+
+// the commit being applied is the "their" side, so the conflict is resolved with the version from the commit, and
+// the paths of the conflicts are returned
+fn resolve_conflicts(merged: &mut Index) -> Result<Vec<String>, Error> {
+	let mut conflicts = vec![];
+	for conflict in merged.conflicts()? {
+		conflicts.push(conflict?);
+	}
+	let mut paths = vec![];
+	for conflict in conflicts {
+		if let Some(entry) = conflict.our.as_ref().or(conflict.their.as_ref()).or(conflict.ancestor.as_ref()) {
+			let path = String::from_utf8_lossy(entry.path.as_slice()).to_string();
+			merged.remove_path(Path::new(path.as_str()))?;
+			paths.push(path);
+		}
+		if let Some(mut their) = conflict.their {
+			their.flags &= !INDEX_ENTRY_STAGE_MASK;
+			merged.add(&their)?;
+		}
+	}
+	paths.sort();
+	paths.dedup();
+	Ok(paths)
+}
+
+// the tree after a line and the paths of its conflicts
+type AppliedLine = (Oid, Option<Vec<String>>);
+
+// Applies a line to the tree of the previous line, returning the tree after the line and the paths of its conflicts,
+// or `None` when the lines after it can not be checked.
+fn apply_line<'l>(
+	repo: &Repository,
+	line: &'l Line,
+	tree_id: Oid,
+	empty_tree_id: Oid,
+	labels: &mut HashMap<&'l str, Oid>,
+) -> Result<Option<AppliedLine>, Error> {
+	let next_tree_id = match *line.get_action() {
+		Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+			let commit = repo.revparse_single(line.get_hash())?.peel_to_commit()?;
+			let ancestor_tree = if commit.parent_count() == 0 {
+				repo.find_tree(empty_tree_id)?
+			}
+			else {
+				commit.parent(0)?.tree()?
+			};
+			let mut merged = repo.merge_trees(&ancestor_tree, &repo.find_tree(tree_id)?, &commit.tree()?, None)?;
+			let conflicts = if merged.has_conflicts() {
+				Some(resolve_conflicts(&mut merged)?)
+			}
+			else {
+				None
+			};
+			return Ok(Some((merged.write_tree_to(repo)?, conflicts)));
+		},
+		Action::Label => {
+			let _previous = labels.insert(get_reference(line), tree_id);
+			tree_id
+		},
+		Action::Reset => {
+			let reference = get_reference(line);
+			if reference == "[new" {
+				empty_tree_id
+			}
+			else if let Some(label_tree_id) = labels.get(reference) {
+				*label_tree_id
+			}
+			else {
+				repo.revparse_single(reference)?.peel_to_tree()?.id()
+			}
+		},
+		// the result of a merge depends on the history of both sides, which is not simulated
+		Action::Merge => return Ok(None),
+		Action::Break | Action::Drop | Action::Exec | Action::Noop | Action::UpdateRef => tree_id,
+	};
+	Ok(Some((next_tree_id, None)))
+}
+
+// Applies each commit to the tree of the previous line in the same way as a cherry-pick, using the parent of the
+// commit as the merge base. A conflict is resolved with the version from the commit, so the lines after it can still
+// be checked. A line that can not be applied, such as a commit that can not be loaded, stops the simulation, and its
+// index is returned with the conflicts found before it. Trees are written to the object database of the repository,
+// so it should have an in memory backend.
+pub(super) fn simulate(repo: &Repository, base: &str, lines: &[Line]) -> Result<(Vec<Conflict>, Option<usize>), Error> {
+	let empty_tree_id = repo.treebuilder(None)?.write()?;
+	let mut tree_id = repo.revparse_single(base)?.peel_to_tree()?.id();
+	let mut labels: HashMap<&str, Oid> = HashMap::new();
+	let mut conflicts = vec![];
+
+	for (index, line) in lines.iter().enumerate() {
+		match apply_line(repo, line, tree_id, empty_tree_id, &mut labels) {
+			Ok(Some((next_tree_id, paths))) => {
+				if let Some(conflict_paths) = paths {
+					conflicts.push(Conflict::new(index, conflict_paths));
+				}
+				tree_id = next_tree_id;
+			},
+			Ok(None) => break,
+			Err(_) => return Ok((conflicts, Some(index))),
+		}
+	}
+	Ok((conflicts, None))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use serial_test::serial;
+
+	use super::*;
+
+	fn simulate_lines(base: &str, lines: &[&str]) -> Result<(Vec<Conflict>, Option<usize>), Error> {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		let repo = Repository::open_from_env().unwrap();
+		let odb = repo.odb().unwrap();
+		let _mempack = odb.add_new_mempack_backend(1000).unwrap();
+		let lines: Vec<Line> = lines.iter().map(|l| Line::new(l).unwrap()).collect();
+		simulate(&repo, base, &lines)
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_without_conflict() {
+		assert_eq!(
+			simulate_lines("7f5eac44012ea33e5bdec0df72125c1bc2b2691d", &[
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+				"exec make",
+				"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			])
+			.unwrap(),
+			(vec![], None)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_with_conflict() {
+		assert_eq!(
+			simulate_lines("1cc0456637cb220155e957c641f483e60724c581", &[
+				"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+				"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			])
+			.unwrap(),
+			(vec![Conflict::new(1, vec![String::from("a")])], None)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_continues_after_conflict() {
+		assert_eq!(
+			simulate_lines("18d82dcc4c36cade807d7cf79700b6bbad8080b9", &[
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+				"exec make",
+				"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved",
+			])
+			.unwrap(),
+			(
+				vec![
+					Conflict::new(0, vec![String::from("a")]),
+					Conflict::new(2, vec![String::from("c"), String::from("f")])
+				],
+				None
+			)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_dropped_commit_not_applied() {
+		assert_eq!(
+			simulate_lines("1cc0456637cb220155e957c641f483e60724c581", &[
+				"drop 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base"
+			])
+			.unwrap(),
+			(vec![], None)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_reset_to_label() {
+		assert_eq!(
+			simulate_lines("7f5eac44012ea33e5bdec0df72125c1bc2b2691d", &[
+				"label onto",
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+				"reset onto",
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			])
+			.unwrap(),
+			(vec![], None)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_stops_at_merge() {
+		assert_eq!(
+			simulate_lines("1cc0456637cb220155e957c641f483e60724c581", &[
+				"merge -C 2836dcdcbd040f9157652dd3db0d584a44d4793d feature",
+				"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			])
+			.unwrap(),
+			(vec![], None)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_invalid_commit() {
+		assert_eq!(
+			simulate_lines("1cc0456637cb220155e957c641f483e60724c581", &[
+				"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+				"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+				"pick 0123456789 c1",
+				"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved",
+			])
+			.unwrap(),
+			(vec![Conflict::new(1, vec![String::from("a")])], Some(2))
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn simulate_invalid_base() {
+		assert!(simulate_lines("0123456789", &["pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base"]).is_err());
+	}
+}
@@ -77,7 +77,7 @@ impl ProcessModule for ExternalEditor {
 					self.state = ExternalEditorState::Error(e);
 				}
 				else {
-					match todo_file.load_file() {
+					match todo_file.reload_file() {
 						Ok(_) => {
 							if todo_file.is_empty() || todo_file.is_noop() {
 								self.state = ExternalEditorState::Empty;
@@ -99,7 +99,7 @@ impl ProcessModule for ExternalEditor {
 						Action::AbortRebase => result = result.exit_status(ExitStatus::Good),
 						Action::EditRebase => self.state = ExternalEditorState::Active,
 						Action::UndoAndEdit => {
-							todo_file.set_lines(self.lines.to_vec());
+							todo_file.replace_lines(self.lines.to_vec());
 							self.activate(todo_file, State::ExternalEditor);
 						},
 						Action::RestoreAndAbortEdit => {},
@@ -117,14 +117,14 @@ impl ProcessModule for ExternalEditor {
 						},
 						Action::EditRebase => self.state = ExternalEditorState::Active,
 						Action::RestoreAndAbortEdit => {
-							todo_file.set_lines(self.lines.to_vec());
+							todo_file.replace_lines(self.lines.to_vec());
 							result = result.state(State::List);
 							if let Err(err) = todo_file.write_file() {
 								result = result.error(err);
 							}
 						},
 						Action::UndoAndEdit => {
-							todo_file.set_lines(self.lines.to_vec());
+							todo_file.replace_lines(self.lines.to_vec());
 							self.activate(todo_file, State::ExternalEditor);
 						},
 					}
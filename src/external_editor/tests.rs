@@ -5,6 +5,7 @@ use crate::{
 	assert_process_result,
 	assert_rendered_output,
 	process::testutil::{process_module_test, TestContext, ViewState},
+	todo_file::{action::Action as TodoAction, edit_content::EditContext},
 };
 
 fn get_external_editor(content: &str, exit_code: &str) -> String {
@@ -134,6 +135,41 @@ fn edit_success() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn edit_success_undo() {
+	process_module_test(
+		&["pick aaa comment", "drop bbb comment"],
+		ViewState::default(),
+		&[Input::Up],
+		|mut test_context: TestContext<'_>| {
+			test_context
+				.rebase_todo_file
+				.update_range(1, 1, &EditContext::new().action(TodoAction::Pick));
+			let mut module = ExternalEditor::new(get_external_editor("pick ccc comment", "0").as_str());
+			assert_process_result!(test_context.activate(&mut module, State::List));
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Other,
+				state = State::List
+			);
+			assert_eq!(test_context.rebase_todo_file.get_lines_owned(), vec![Line::new(
+				"pick ccc comment"
+			)
+			.unwrap()]);
+			test_context.rebase_todo_file.undo();
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa comment", "pick bbb comment"]
+			);
+		},
+	);
+}
+
 #[test]
 #[serial_test::serial]
 fn empty_edit_error() {
@@ -1,7 +1,7 @@
 use crate::{
 	config::key_bindings::KeyBindings,
-	display::{Event, KeyCode, KeyModifiers, MouseEventKind},
-	input::Input,
+	display::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+	input::{Input, Mouse, MouseAction},
 };
 
 fn modifiers_to_string(modifiers: KeyModifiers, code: Option<KeyCode>) -> String {
@@ -81,23 +81,10 @@ impl<'i> InputHandler<'i> {
 					_ => code,
 				}
 			},
-			Event::Mouse(event) => {
-				format!("{}{}", modifiers_to_string(event.modifiers, None), match event.kind {
-					MouseEventKind::ScrollDown => String::from("Down"),
-					MouseEventKind::ScrollUp => String::from("Up"),
-					_ => String::from("Ignore"),
-				})
-			},
+			Event::Mouse(event) => return Self::get_mouse_event_input(mode, event),
 			Event::Resize(..) => String::from("Resize"),
 		};
 
-		// this is a hack to work around unhandled mouse events, input handling needs to be changed
-		// to properly handle dynamic inputs like mouse events
-		// TODO remove hack
-		if input == "Ignore" {
-			return Input::Ignore;
-		}
-
 		match mode {
 			InputMode::Confirm => self.get_confirm(input.as_str()),
 			InputMode::Default => Self::get_default_input(input.as_str()),
@@ -107,6 +94,39 @@ impl<'i> InputHandler<'i> {
 		}
 	}
 
+	// the mouse is not mapped to the key bindings, so rebinding the arrow keys does not change the wheel
+	fn get_mouse_event_input(mode: InputMode, event: MouseEvent) -> Input {
+		match (mode, event.kind) {
+			(InputMode::List, MouseEventKind::ScrollDown) => Self::get_mouse_input(MouseAction::ScrollDown, event),
+			(InputMode::List, MouseEventKind::ScrollUp) => Self::get_mouse_input(MouseAction::ScrollUp, event),
+			(InputMode::Raw, MouseEventKind::ScrollDown) => Input::Down,
+			(InputMode::Raw, MouseEventKind::ScrollUp) => Input::Up,
+			(_, MouseEventKind::ScrollDown) => Input::ScrollDown,
+			(_, MouseEventKind::ScrollUp) => Input::ScrollUp,
+			// only the list uses the left button, other views would handle a click as any other input
+			(InputMode::List, MouseEventKind::Down(MouseButton::Left)) => {
+				Self::get_mouse_input(MouseAction::Press, event)
+			},
+			(InputMode::List, MouseEventKind::Drag(MouseButton::Left)) => {
+				Self::get_mouse_input(MouseAction::Drag, event)
+			},
+			(InputMode::List, MouseEventKind::Up(MouseButton::Left)) => {
+				Self::get_mouse_input(MouseAction::Release, event)
+			},
+			_ => Input::Ignore,
+		}
+	}
+
+	// a mouse input reports where it was used, so the list can find the line under it
+	fn get_mouse_input(action: MouseAction, event: MouseEvent) -> Input {
+		Input::Mouse(Mouse::new(
+			action,
+			event.column as usize,
+			event.row as usize,
+			event.modifiers.contains(KeyModifiers::SHIFT),
+		))
+	}
+
 	fn get_standard_inputs(input: &str) -> Option<Input> {
 		Some(match input {
 			"Up" => Input::ScrollUp,
@@ -141,6 +161,7 @@ impl<'i> InputHandler<'i> {
 	fn get_list_input(&self, input: String) -> Input {
 		match input {
 			i if self.key_bindings.abort.contains(&i) => Input::Abort,
+			i if self.key_bindings.absorb.contains(&i) => Input::Absorb,
 			i if self.key_bindings.action_break.contains(&i) => Input::ActionBreak,
 			i if self.key_bindings.action_drop.contains(&i) => Input::ActionDrop,
 			i if self.key_bindings.action_edit.contains(&i) => Input::ActionEdit,
@@ -148,10 +169,16 @@ impl<'i> InputHandler<'i> {
 			i if self.key_bindings.action_pick.contains(&i) => Input::ActionPick,
 			i if self.key_bindings.action_reword.contains(&i) => Input::ActionReword,
 			i if self.key_bindings.action_squash.contains(&i) => Input::ActionSquash,
+			i if self.key_bindings.autosquash.contains(&i) => Input::Autosquash,
+			i if self.key_bindings.cut.contains(&i) => Input::Cut,
+			i if self.key_bindings.cycle_fixup_message.contains(&i) => Input::CycleFixupMessage,
+			i if self.key_bindings.drop_redundant.contains(&i) => Input::DropRedundant,
 			i if self.key_bindings.edit.contains(&i) => Input::Edit,
+			i if self.key_bindings.filter.contains(&i) => Input::Filter,
 			i if self.key_bindings.force_abort.contains(&i) => Input::ForceAbort,
 			i if self.key_bindings.force_rebase.contains(&i) => Input::ForceRebase,
 			i if self.key_bindings.help.contains(&i) => Input::Help,
+			i if self.key_bindings.insert_exec_template.contains(&i) => Input::InsertExecTemplate,
 			i if self.key_bindings.insert_line.contains(&i) => Input::InsertLine,
 			i if self.key_bindings.move_down.contains(&i) => Input::MoveCursorDown,
 			i if self.key_bindings.move_down_step.contains(&i) => Input::MoveCursorPageDown,
@@ -165,15 +192,29 @@ impl<'i> InputHandler<'i> {
 			i if self.key_bindings.move_up.contains(&i) => Input::MoveCursorUp,
 			i if self.key_bindings.move_up_step.contains(&i) => Input::MoveCursorPageUp,
 			i if self.key_bindings.open_in_external_editor.contains(&i) => Input::OpenInEditor,
+			i if self.key_bindings.paste_after.contains(&i) => Input::PasteAfter,
+			i if self.key_bindings.paste_before.contains(&i) => Input::PasteBefore,
 			i if self.key_bindings.rebase.contains(&i) => Input::Rebase,
 			i if self.key_bindings.redo.contains(&i) => Input::Redo,
+			i if self.key_bindings.remove_exec_template.contains(&i) => Input::RemoveExecTemplate,
 			i if self.key_bindings.remove_line.contains(&i) => Input::Delete,
+			i if self.key_bindings.search.contains(&i) => Input::Search,
+			i if self.key_bindings.search_backward.contains(&i) => Input::SearchBackward,
+			i if self.key_bindings.search_next.contains(&i) => Input::SearchNext,
+			i if self.key_bindings.search_previous.contains(&i) => Input::SearchPrevious,
+			i if self.key_bindings.select_register.contains(&i) => Input::SelectRegister,
 			i if self.key_bindings.show_commit.contains(&i) => Input::ShowCommit,
+			i if self.key_bindings.show_history.contains(&i) => Input::ShowHistory,
+			i if self.key_bindings.sort_selection.contains(&i) => Input::SortSelection,
+			i if self.key_bindings.toggle_graph.contains(&i) => Input::ToggleGraph,
 			i if self.key_bindings.toggle_visual_mode.contains(&i) => Input::ToggleVisualMode,
 			i if self.key_bindings.undo.contains(&i) => Input::Undo,
+			i if self.key_bindings.yank.contains(&i) => Input::Yank,
 			i if i.as_str() == "Exit" => Input::Exit,
 			i if i.as_str() == "Kill" => Input::Kill,
 			i if i.as_str() == "Resize" => Input::Resize,
+			// digits that are not bound to a key are the count of the next command
+			i if i.len() == 1 && i.as_bytes()[0].is_ascii_digit() => Input::Character(i.chars().next().unwrap()),
 			_ => Input::Other,
 		}
 	}
@@ -411,6 +452,7 @@ mod tests {
 		input,
 		expected,
 		case::abort(create_key_event!('q'), Input::Abort),
+		case::absorb(create_key_event!('F'), Input::Absorb),
 		case::action_break(create_key_event!('b'), Input::ActionBreak),
 		case::action_drop(create_key_event!('d'), Input::ActionDrop),
 		case::action_edit(create_key_event!('e'), Input::ActionEdit),
@@ -418,10 +460,16 @@ mod tests {
 		case::action_pick(create_key_event!('p'), Input::ActionPick),
 		case::action_reword(create_key_event!('r'), Input::ActionReword),
 		case::action_squash(create_key_event!('s'), Input::ActionSquash),
+		case::autosquash(create_key_event!('A'), Input::Autosquash),
+		case::cut(create_key_event!('x'), Input::Cut),
+		case::cycle_fixup_message(create_key_event!('u'), Input::CycleFixupMessage),
+		case::drop_redundant(create_key_event!('D'), Input::DropRedundant),
 		case::edit(create_key_event!('E'), Input::Edit),
+		case::filter(create_key_event!('|'), Input::Filter),
 		case::force_abort(create_key_event!('Q'), Input::ForceAbort),
 		case::force_rebase(create_key_event!('W'), Input::ForceRebase),
 		case::help(create_key_event!('?'), Input::Help),
+		case::insert_exec_template(create_key_event!('X'), Input::InsertExecTemplate),
 		case::insert_line(create_key_event!('I'), Input::InsertLine),
 		case::move_down(create_key_event!(code KeyCode::Down), Input::MoveCursorDown),
 		case::move_end(create_key_event!(code KeyCode::End), Input::MoveCursorEnd),
@@ -432,25 +480,39 @@ mod tests {
 		case::move_right(create_key_event!(code KeyCode::Right), Input::MoveCursorRight),
 		case::move_up(create_key_event!(code KeyCode::Up), Input::MoveCursorUp),
 		case::open_in_external_editor(create_key_event!('!'), Input::OpenInEditor),
+		case::paste_after(create_key_event!(']'), Input::PasteAfter),
+		case::paste_before(create_key_event!('['), Input::PasteBefore),
 		case::rebase(create_key_event!('w'), Input::Rebase),
 		case::redo(create_key_event!('y', "Control"), Input::Redo),
+		case::remove_exec_template(create_key_event!('R'), Input::RemoveExecTemplate),
 		case::remove_line(create_key_event!(code KeyCode::Delete), Input::Delete),
+		case::search(create_key_event!('/'), Input::Search),
+		case::search_next(create_key_event!('n'), Input::SearchNext),
+		case::search_previous(create_key_event!('N'), Input::SearchPrevious),
+		case::select_register(create_key_event!('"'), Input::SelectRegister),
 		case::show_commit(create_key_event!('c'), Input::ShowCommit),
+		case::show_history(create_key_event!('H'), Input::ShowHistory),
+		case::sort_selection(create_key_event!('S'), Input::SortSelection),
 		case::swap_selected_down(create_key_event!('j'), Input::SwapSelectedDown),
 		case::swap_selected_up(create_key_event!('k'), Input::SwapSelectedUp),
+		case::toggle_graph(create_key_event!('G'), Input::ToggleGraph),
 		case::toggle_visual_mode(create_key_event!('v'), Input::ToggleVisualMode),
 		case::undo(create_key_event!('z', "Control"), Input::Undo),
+		case::yank(create_key_event!('y'), Input::Yank),
 		case::resize(Event::Resize(0, 0), Input::Resize),
 		case::other(create_key_event!('z'), Input::Other),
+		case::digit(create_key_event!('5'), Input::Character('5')),
 		case::exit(create_key_event!('d', "Control"), Input::Exit),
 		case::exit(create_key_event!('c', "Control"), Input::Kill),
-		case::multiple_bindings(create_key_event!('7'), Input::Abort)
+		case::multiple_bindings(create_key_event!('7'), Input::Abort),
+		case::bound_digit_is_not_a_count(create_key_event!('8'), Input::ActionDrop)
 	)]
 	#[serial_test::serial]
 	fn list_mode(input: Event, expected: Input) {
 		input_handler_test(
 			|config| {
 				config.key_bindings.abort = vec![String::from('q'), String::from('7')];
+				config.key_bindings.action_drop = vec![String::from('d'), String::from('8')];
 			},
 			|input_handler: &InputHandler<'_>| {
 				assert_eq!(input_handler.get_input(InputMode::List, input), expected);
@@ -458,6 +520,66 @@ mod tests {
 		);
 	}
 
+	#[rstest(
+		mode,
+		expected,
+		case::confirm(InputMode::Confirm, Input::Ignore),
+		case::default(InputMode::Default, Input::Ignore),
+		case::list(InputMode::List, Input::Mouse(Mouse::new(MouseAction::Press, 0, 0, false))),
+		case::raw(InputMode::Raw, Input::Ignore),
+		case::show_commit(InputMode::ShowCommit, Input::Ignore)
+	)]
+	#[serial_test::serial]
+	fn left_mouse_button(mode: InputMode, expected: Input) {
+		input_handler_test(
+			|_| {},
+			|input_handler: &InputHandler<'_>| {
+				assert_eq!(
+					input_handler.get_input(mode, create_mouse_event!(MouseEventKind::Down(MouseButton::Left))),
+					expected
+				);
+			},
+		);
+	}
+
+	#[rstest(
+		mode,
+		expected,
+		case::confirm(InputMode::Confirm, Input::ScrollDown),
+		case::default(InputMode::Default, Input::ScrollDown),
+		case::list(InputMode::List, Input::Mouse(Mouse::new(MouseAction::ScrollDown, 0, 0, false))),
+		case::raw(InputMode::Raw, Input::Down),
+		case::show_commit(InputMode::ShowCommit, Input::ScrollDown)
+	)]
+	#[serial_test::serial]
+	fn mouse_scroll_down(mode: InputMode, expected: Input) {
+		input_handler_test(
+			|_| {},
+			|input_handler: &InputHandler<'_>| {
+				assert_eq!(
+					input_handler.get_input(mode, create_mouse_event!(MouseEventKind::ScrollDown)),
+					expected
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn mouse_scroll_up_list_with_rebound_up() {
+		input_handler_test(
+			|config| {
+				config.key_bindings.move_up = vec![String::from("k")];
+			},
+			|input_handler: &InputHandler<'_>| {
+				assert_eq!(
+					input_handler.get_input(InputMode::List, create_mouse_event!(MouseEventKind::ScrollUp)),
+					Input::Mouse(Mouse::new(MouseAction::ScrollUp, 0, 0, false))
+				);
+			},
+		);
+	}
+
 	#[rstest(
 		input,
 		expected,
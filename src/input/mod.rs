@@ -4,6 +4,7 @@ pub mod input_handler;
 pub enum Input {
 	// meta key bindings
 	Abort,
+	Absorb,
 	ActionBreak,
 	ActionDrop,
 	ActionEdit,
@@ -11,12 +12,18 @@ pub enum Input {
 	ActionPick,
 	ActionReword,
 	ActionSquash,
+	Autosquash,
+	Cut,
+	CycleFixupMessage,
+	DropRedundant,
 	Edit,
 	Escape,
 	Exit,
+	Filter,
 	ForceAbort,
 	ForceRebase,
 	Help,
+	InsertExecTemplate,
 	InsertLine,
 	Kill,
 	MoveCursorDown,
@@ -30,8 +37,11 @@ pub enum Input {
 	No,
 	OpenInEditor,
 	Other,
+	PasteAfter,
+	PasteBefore,
 	Rebase,
 	Redo,
+	RemoveExecTemplate,
 	ScrollBottom,
 	ScrollDown,
 	ScrollJumpDown,
@@ -40,12 +50,21 @@ pub enum Input {
 	ScrollRight,
 	ScrollTop,
 	ScrollUp,
+	Search,
+	SearchBackward,
+	SearchNext,
+	SearchPrevious,
+	SelectRegister,
 	ShowCommit,
 	ShowDiff,
+	ShowHistory,
+	SortSelection,
 	SwapSelectedDown,
 	SwapSelectedUp,
+	ToggleGraph,
 	ToggleVisualMode,
 	Undo,
+	Yank,
 	Yes,
 
 	// raw input values
@@ -66,6 +85,54 @@ pub enum Input {
 	Tab,
 	Up,
 
+	// mouse input values
+	Mouse(Mouse),
+
 	// some events should be
 	Ignore,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MouseAction {
+	Press,
+	Drag,
+	Release,
+	ScrollDown,
+	ScrollUp,
+}
+
+// a left button or wheel action, at a column and row of the terminal
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mouse {
+	action: MouseAction,
+	column: usize,
+	row: usize,
+	shift: bool,
+}
+
+impl Mouse {
+	pub(crate) const fn new(action: MouseAction, column: usize, row: usize, shift: bool) -> Self {
+		Self {
+			action,
+			column,
+			row,
+			shift,
+		}
+	}
+
+	pub(crate) const fn get_action(&self) -> MouseAction {
+		self.action
+	}
+
+	pub(crate) const fn get_column(&self) -> usize {
+		self.column
+	}
+
+	pub(crate) const fn get_row(&self) -> usize {
+		self.row
+	}
+
+	pub(crate) const fn is_shift(&self) -> bool {
+		self.shift
+	}
+}
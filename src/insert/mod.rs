@@ -4,6 +4,8 @@ mod line_type;
 #[cfg(all(unix, test))]
 mod tests;
 
+use git2::{Error, Repository};
+
 use crate::{
 	components::{Choice, Edit},
 	input::{input_handler::InputMode, Input},
@@ -14,6 +16,7 @@ use crate::{
 };
 
 pub struct Insert {
+	abbrev: Option<u32>,
 	action_choices: Choice<LineType>,
 	edit: Edit,
 	edit_view_data: ViewData,
@@ -69,10 +72,11 @@ impl ProcessModule for Insert {
 					if !content.is_empty() {
 						let line = match self.line_type {
 							LineType::Exec => Line::new_exec(content.as_str()),
-							LineType::Pick => Line::new_pick(content.as_str()),
+							LineType::Pick => Self::create_pick_line(content.as_str(), self.abbrev),
 							LineType::Label => Line::new_label(content.as_str()),
 							LineType::Reset => Line::new_reset(content.as_str()),
 							LineType::Merge => Line::new_merge(content.as_str()),
+							LineType::UpdateRef => Line::new_update_ref(content.as_str()),
 							// this should exit in the prompt state and never get here
 							LineType::Cancel => unreachable!(),
 						};
@@ -89,7 +93,7 @@ impl ProcessModule for Insert {
 }
 
 impl Insert {
-	pub(crate) fn new() -> Self {
+	pub(crate) fn new(abbrev: Option<u32>) -> Self {
 		let mut edit = Edit::new();
 		edit.set_description("Enter contents of the new line. Empty content cancels creation of a new line.");
 
@@ -103,6 +107,7 @@ impl Insert {
 				'm',
 				String::from("merge [-C <commit> | -c <commit>] <label> [# <oneline>]"),
 			),
+			(LineType::UpdateRef, 'u', String::from("update-ref <reference>")),
 			(LineType::Cancel, 'q', String::from("Cancel add line")),
 		]);
 		action_choices.set_prompt(vec![ViewLine::from("Select the type of line to insert:")]);
@@ -110,6 +115,7 @@ impl Insert {
 		edit_view_data.set_show_title(true);
 
 		Self {
+			abbrev,
 			state: InsertState::Prompt,
 			edit,
 			edit_view_data,
@@ -117,4 +123,23 @@ impl Insert {
 			line_type: LineType::Exec,
 		}
 	}
+
+	// expand or shorten the entered reference to the hash length git would write, falling back to the
+	// reference as entered if it cannot be resolved
+	fn create_pick_line(reference: &str, abbrev: Option<u32>) -> Line {
+		Self::resolve_commit(reference, abbrev).map_or_else(
+			|_| Line::new_pick(reference, ""),
+			|(hash, summary)| Line::new_pick(hash.as_str(), summary.as_str()),
+		)
+	}
+
+	fn resolve_commit(reference: &str, abbrev: Option<u32>) -> Result<(String, String), Error> {
+		let repo = Repository::open_from_env()?;
+		let commit = repo.revparse_single(reference)?.peel_to_commit()?;
+		let hash = match abbrev {
+			Some(length) => commit.id().to_string().chars().take(length as usize).collect(),
+			None => String::from(commit.as_object().short_id()?.as_str().unwrap_or("")),
+		};
+		Ok((hash, String::from(commit.summary().unwrap_or(""))))
+	}
 }
@@ -9,7 +9,7 @@ use crate::{
 #[serial_test::serial]
 fn activate() {
 	process_module_test(&[], ViewState::default(), &[], |test_context: TestContext<'_>| {
-		let mut module = Insert::new();
+		let mut module = Insert::new(test_context.config.git.abbrev);
 		assert_process_result!(test_context.activate(&mut module, State::List));
 	});
 }
@@ -18,7 +18,7 @@ fn activate() {
 #[serial_test::serial]
 fn render_prompt() {
 	process_module_test(&[], ViewState::default(), &[], |test_context: TestContext<'_>| {
-		let mut module = Insert::new();
+		let mut module = Insert::new(test_context.config.git.abbrev);
 		let view_data = test_context.build_view_data(&mut module);
 		assert_rendered_output!(
 			view_data,
@@ -32,6 +32,7 @@ fn render_prompt() {
 			"{Normal}l) label <label>",
 			"{Normal}r) reset <label>",
 			"{Normal}m) merge [-C <commit> | -c <commit>] <label> [# <oneline>]",
+			"{Normal}u) update-ref <reference>",
 			"{Normal}q) Cancel add line",
 			"",
 			"{IndicatorColor}Please choose an option."
@@ -47,7 +48,7 @@ fn prompt_cancel() {
 		ViewState::default(),
 		&[Input::Character('q')],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			assert_process_result!(
 				test_context.handle_input(&mut module),
 				input = Input::Character('q'),
@@ -71,7 +72,7 @@ fn edit_render_exec() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_n_inputs(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -109,7 +110,7 @@ fn edit_render_pick() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_n_inputs(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -136,6 +137,48 @@ fn edit_render_pick() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn edit_pick_shortens_hash() {
+	let mut inputs = vec![Input::Character('p')];
+	inputs.extend("e10b3f474644d8566947104c07acba4d6f4f4f9f".chars().map(Input::Character));
+	inputs.push(Input::Enter);
+	process_module_test(&[], ViewState::default(), &inputs, |mut test_context: TestContext<'_>| {
+		let mut module = Insert::new(test_context.config.git.abbrev);
+		test_context.handle_all_inputs(&mut module);
+		assert_eq!(
+			test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+			"pick e10b3f4 First commit"
+		);
+	});
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_pick_expands_hash_with_abbrev() {
+	process_module_test(
+		&[],
+		ViewState::default(),
+		&[
+			Input::Character('p'),
+			Input::Character('e'),
+			Input::Character('1'),
+			Input::Character('0'),
+			Input::Character('b'),
+			Input::Character('3'),
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = Insert::new(Some(12));
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"pick e10b3f474644 First commit"
+			);
+		},
+	);
+}
+
 #[test]
 #[serial_test::serial]
 fn edit_render_label() {
@@ -150,7 +193,7 @@ fn edit_render_label() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_n_inputs(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -191,7 +234,7 @@ fn edit_render_reset() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_n_inputs(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -232,7 +275,7 @@ fn edit_render_merge() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_n_inputs(&mut module, 4);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
@@ -259,6 +302,47 @@ fn edit_render_merge() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn edit_render_update_ref() {
+	process_module_test(
+		&[],
+		ViewState::default(),
+		&[
+			Input::Character('u'),
+			Input::Character('f'),
+			Input::Character('o'),
+			Input::Character('o'),
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = Insert::new(test_context.config.git.abbrev);
+			test_context.handle_n_inputs(&mut module, 4);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}Enter contents of the new line. Empty content cancels creation of a new line.",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}update-ref {Normal}foo{Normal,Underline} ",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Enter,
+				state = State::List
+			);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"update-ref foo"
+			);
+		},
+	);
+}
+
 #[test]
 #[serial_test::serial]
 fn edit_select_next_index() {
@@ -273,7 +357,7 @@ fn edit_select_next_index() {
 			Input::Enter,
 		],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_all_inputs(&mut module);
 			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
@@ -288,7 +372,7 @@ fn cancel_edit() {
 		ViewState::default(),
 		&[Input::Character('e'), Input::Enter],
 		|mut test_context: TestContext<'_>| {
-			let mut module = Insert::new();
+			let mut module = Insert::new(test_context.config.git.abbrev);
 			test_context.handle_all_inputs(&mut module);
 			assert!(test_context.rebase_todo_file.is_empty());
 		},
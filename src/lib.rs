@@ -0,0 +1,23 @@
+//! Load, edit and write git interactive rebase todo files.
+//!
+//! This is the todo file model used by the interactive rebase tool. It parses the lines of a rebase todo file,
+//! including comments and unknown lines, supports the same edit operations as the tool, records those edits in an
+//! undo history, and writes the file back out in the format git expects.
+//!
+//! ```
+//! use git_interactive_rebase_tool::todo_file::{action::Action, edit_content::EditContext, line::Line, TodoFile};
+//!
+//! let mut todo_file = TodoFile::new("git-rebase-todo", 10, "#");
+//! todo_file.set_lines(vec![
+//!     Line::new("pick aaa first commit").unwrap(),
+//!     Line::new("pick bbb second commit").unwrap(),
+//! ]);
+//! todo_file.update_range(1, 1, &EditContext::new().action(Action::Fixup));
+//! assert_eq!(todo_file.get_line(1).unwrap().to_text(), "fixup bbb second commit");
+//! todo_file.undo();
+//! assert_eq!(todo_file.get_line(1).unwrap().to_text(), "pick bbb second commit");
+//! ```
+#![deny(missing_docs)]
+
+/// The rebase todo file model.
+pub mod todo_file;
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use git2::{Error, Repository};
+
+use crate::todo_file::{action::Action, line::Line};
+
+#[derive(Debug, PartialEq)]
+pub(super) struct CommitDetails {
+	author: String,
+	paths: Vec<String>,
+}
+
+impl CommitDetails {
+	// the author is in the same "name <email>" form git uses, so either can be matched
+	pub(super) fn get_author(&self) -> &str {
+		self.author.as_str()
+	}
+
+	pub(super) fn get_paths(&self) -> &[String] {
+		&self.paths
+	}
+}
+
+fn load_details(repo: &Repository, hash: &str) -> Result<CommitDetails, Error> {
+	let commit = repo.find_commit(repo.revparse_single(hash)?.id())?;
+	let author = format!(
+		"{} <{}>",
+		commit.author().name().unwrap_or(""),
+		commit.author().email().unwrap_or("")
+	);
+	// some commits do not have parents, so everything in the tree was touched
+	let parent_tree = if commit.parent_count() == 0 {
+		None
+	}
+	else {
+		Some(commit.parent(0)?.tree()?)
+	};
+	let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+	let paths = diff
+		.deltas()
+		.filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+		.map(|path| path.to_string_lossy().to_string())
+		.collect();
+	Ok(CommitDetails { author, paths })
+}
+
+// The author and the changed paths of each commit, so lines can be found by more than what is shown. A commit does
+// not change, so only the commits without details are loaded, and commits that can not be loaded have no details.
+pub(super) fn load_commit_details(commit_details: &mut HashMap<String, CommitDetails>, lines: &[&Line]) {
+	let missing_hashes: Vec<&str> = lines
+		.iter()
+		.filter(|line| {
+			match *line.get_action() {
+				Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+					!commit_details.contains_key(line.get_hash())
+				},
+				_ => false,
+			}
+		})
+		.map(|line| line.get_hash())
+		.collect();
+	if missing_hashes.is_empty() {
+		return;
+	}
+	let repo = match Repository::open_from_env() {
+		Ok(repo) => repo,
+		Err(_) => return,
+	};
+	for hash in missing_hashes {
+		if let Ok(details) = load_details(&repo, hash) {
+			let _previous = commit_details.insert(String::from(hash), details);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use serial_test::serial;
+
+	use super::*;
+
+	fn load(lines: &[&str]) -> HashMap<String, CommitDetails> {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		let lines: Vec<Line> = lines.iter().map(|line| Line::new(line).unwrap()).collect();
+		let mut commit_details = HashMap::new();
+		load_commit_details(&mut commit_details, &lines.iter().collect::<Vec<&Line>>());
+		commit_details
+	}
+
+	#[test]
+	#[serial]
+	fn load_commit_details_commit() {
+		let commit_details = load(&["pick 1cc0456637cb220155e957c641f483e60724c581 modified"]);
+		let details = commit_details.get("1cc0456637cb220155e957c641f483e60724c581").unwrap();
+		assert_eq!(details.get_author(), "Tim Oram <dev@mitmaro.ca>");
+		assert_eq!(details.get_paths(), &[String::from("a")]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_commit_details_moved_path() {
+		let commit_details = load(&["pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved"]);
+		let details = commit_details.get("aed0fd1db3e73c0e568677ae8903a11c5fbc5659").unwrap();
+		assert_eq!(details.get_paths(), &[String::from("c"), String::from("f")]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_commit_details_without_commit() {
+		assert!(load(&["pick 0123456789 unknown", "exec make", "label onto"]).is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn load_commit_details_keeps_loaded_commits() {
+		let mut commit_details = load(&["pick 1cc0456637cb220155e957c641f483e60724c581 modified"]);
+		let _previous = commit_details.insert(String::from("aed0fd1db3e73c0e568677ae8903a11c5fbc5659"), CommitDetails {
+			author: String::from("Cached <cached@example.com>"),
+			paths: vec![],
+		});
+		let lines = [
+			Line::new("pick 1cc0456637cb220155e957c641f483e60724c581 modified").unwrap(),
+			Line::new("pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved").unwrap(),
+		];
+		load_commit_details(&mut commit_details, &lines.iter().collect::<Vec<&Line>>());
+		assert_eq!(
+			commit_details
+				.get("aed0fd1db3e73c0e568677ae8903a11c5fbc5659")
+				.unwrap()
+				.get_author(),
+			"Cached <cached@example.com>"
+		);
+		assert_eq!(commit_details.len(), 2);
+	}
+}
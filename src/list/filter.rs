@@ -0,0 +1,182 @@
+use std::{collections::HashMap, convert::TryFrom};
+
+use anyhow::{anyhow, Result};
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+	list::{commit_details::CommitDetails, search::get_match_ranges},
+	todo_file::{action::Action, line::Line},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum FilterKind {
+	Action,
+	Author,
+	Path,
+	Subject,
+}
+
+impl FilterKind {
+	pub(super) const fn get_prompt(self) -> &'static str {
+		match self {
+			Self::Action => "Show only the lines with the actions, separated by spaces:",
+			Self::Author => "Show only the commits by the author:",
+			Self::Path => "Show only the commits that change the path:",
+			Self::Subject => "Show only the commits with a subject matching the pattern:",
+		}
+	}
+}
+
+#[derive(Debug)]
+enum Matcher {
+	Actions(Vec<Action>),
+	Author(String),
+	Path(String),
+	Subject(Regex),
+}
+
+#[derive(Debug)]
+pub(super) struct Filter {
+	kind: FilterKind,
+	matcher: Matcher,
+	value: String,
+}
+
+impl Filter {
+	pub(super) fn new(kind: FilterKind, value: &str) -> Result<Self> {
+		let matcher = match kind {
+			FilterKind::Action => {
+				Matcher::Actions(
+					value
+						.split_whitespace()
+						.map(Action::try_from)
+						.collect::<Result<Vec<Action>>>()?,
+				)
+			},
+			FilterKind::Author => Matcher::Author(String::from(value)),
+			FilterKind::Path => Matcher::Path(String::from(value)),
+			FilterKind::Subject => {
+				Matcher::Subject(
+					RegexBuilder::new(value)
+						.case_insensitive(true)
+						.build()
+						.map_err(|err| anyhow!("Invalid subject pattern: {}", err))?,
+				)
+			},
+		};
+		Ok(Self {
+			kind,
+			matcher,
+			value: String::from(value),
+		})
+	}
+
+	pub(super) fn get_description(&self) -> String {
+		format!(
+			"Filtered by {}: {}",
+			match self.kind {
+				FilterKind::Action => "action",
+				FilterKind::Author => "author",
+				FilterKind::Path => "path",
+				FilterKind::Subject => "subject",
+			},
+			self.value
+		)
+	}
+
+	// lines without a commit, such as exec and label lines, only match a filter on the action
+	pub(super) fn matches(&self, line: &Line, commit_details: &HashMap<String, CommitDetails>) -> bool {
+		let details = commit_details.get(line.get_hash());
+		match self.matcher {
+			Matcher::Actions(ref actions) => actions.contains(line.get_action()),
+			Matcher::Author(ref author) => {
+				details.map_or(false, |details| !get_match_ranges(details.get_author(), author).is_empty())
+			},
+			Matcher::Path(ref path) => {
+				details.map_or(false, |details| details.get_paths().iter().any(|p| p.contains(path.as_str())))
+			},
+			Matcher::Subject(ref pattern) => line.has_reference() && pattern.is_match(line.get_content()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use rstest::rstest;
+	use serial_test::serial;
+
+	use super::*;
+	use crate::list::commit_details::load_commit_details;
+
+	const MODIFIED: &str = "pick 1cc0456637cb220155e957c641f483e60724c581 modified";
+
+	fn filter_matches(kind: FilterKind, value: &str, line: &str) -> bool {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		let line = Line::new(line).unwrap();
+		let mut commit_details = HashMap::new();
+		load_commit_details(&mut commit_details, &[&line]);
+		Filter::new(kind, value).unwrap().matches(&line, &commit_details)
+	}
+
+	#[rstest(
+		kind,
+		value,
+		line,
+		expected,
+		case::action(FilterKind::Action, "pick", MODIFIED, true),
+		case::action_abbreviation(FilterKind::Action, "p", MODIFIED, true),
+		case::action_multiple(FilterKind::Action, "fixup exec", "exec make", true),
+		case::action_no_match(FilterKind::Action, "fixup squash", MODIFIED, false),
+		case::author(FilterKind::Author, "tim", MODIFIED, true),
+		case::author_email(FilterKind::Author, "DEV@mitmaro", MODIFIED, true),
+		case::author_no_match(FilterKind::Author, "someone", MODIFIED, false),
+		case::author_without_commit(FilterKind::Author, "tim", "exec make", false),
+		case::path(FilterKind::Path, "a", MODIFIED, true),
+		case::path_no_match(FilterKind::Path, "b", MODIFIED, false),
+		case::subject(FilterKind::Subject, "^mod.*ed$", MODIFIED, true),
+		case::subject_ignores_case(FilterKind::Subject, "MODIFIED", MODIFIED, true),
+		case::subject_no_match(FilterKind::Subject, "^deleted", MODIFIED, false),
+		case::subject_unknown_commit(FilterKind::Subject, "bug", "pick aaa fix bug", true),
+		case::subject_without_commit(FilterKind::Subject, "make", "exec make", false)
+	)]
+	#[serial]
+	fn matches(kind: FilterKind, value: &str, line: &str, expected: bool) {
+		assert_eq!(filter_matches(kind, value, line), expected);
+	}
+
+	#[test]
+	fn new_invalid_action() {
+		assert_eq!(
+			Filter::new(FilterKind::Action, "pick unknown").unwrap_err().to_string(),
+			"Invalid action: unknown"
+		);
+	}
+
+	#[test]
+	fn new_invalid_subject_pattern() {
+		assert!(
+			Filter::new(FilterKind::Subject, "(")
+				.unwrap_err()
+				.to_string()
+				.starts_with("Invalid subject pattern: ")
+		);
+	}
+
+	#[test]
+	fn get_description() {
+		assert_eq!(
+			Filter::new(FilterKind::Path, "src/foo").unwrap().get_description(),
+			"Filtered by path: src/foo"
+		);
+	}
+}
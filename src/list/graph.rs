@@ -0,0 +1,189 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::todo_file::{action::Action, line::Line};
+
+// deeper branches are drawn in the last lane, so the graph does not take over the list
+const MAXIMUM_FULL_LANES: usize = 8;
+const MAXIMUM_COMPACT_LANES: usize = 3;
+
+fn get_reference(line: &Line) -> &str {
+	line.get_content().split_whitespace().next().unwrap_or("")
+}
+
+// The lines are split into branches at each `reset`, and a branch whose label is merged by another branch is drawn one
+// lane deeper than the branch that merges it. Returns nothing when there are no merged branches to draw.
+pub(super) fn get_graph_depths(lines: &[&Line]) -> Option<Vec<usize>> {
+	let mut branches = vec![];
+	let mut branch = 0;
+	for (index, line) in lines.iter().enumerate() {
+		if *line.get_action() == Action::Reset && index > 0 {
+			branch += 1;
+		}
+		branches.push(branch);
+	}
+
+	let mut labels: HashMap<&str, usize> = HashMap::new();
+	let mut merged_into: HashMap<usize, usize> = HashMap::new();
+	for (line, &branch) in lines.iter().zip(branches.iter()) {
+		match *line.get_action() {
+			Action::Label => {
+				let _previous = labels.insert(get_reference(line), branch);
+			},
+			Action::Merge => {
+				for label in line.get_content().split_whitespace() {
+					if let Some(&merged_branch) = labels.get(label) {
+						if merged_branch != branch {
+							let _previous = merged_into.entry(merged_branch).or_insert(branch);
+						}
+					}
+				}
+			},
+			_ => {},
+		}
+	}
+	if merged_into.is_empty() {
+		return None;
+	}
+
+	let branch_count = branch + 1;
+	let get_depth = |mut branch: usize| {
+		let mut depth = 0;
+		// a branch can only be merged into a later branch, but the limit guards against an invalid todo file
+		while let Some(&parent) = merged_into.get(&branch) {
+			if depth > branch_count {
+				break;
+			}
+			depth += 1;
+			branch = parent;
+		}
+		depth
+	};
+	Some(branches.into_iter().map(get_depth).collect())
+}
+
+pub(super) fn get_graph_gutter(line: &Line, depth: usize, max_depth: usize, is_full_width: bool) -> String {
+	let maximum_lanes = if is_full_width {
+		MAXIMUM_FULL_LANES
+	}
+	else {
+		MAXIMUM_COMPACT_LANES
+	};
+	let lanes = max_depth.min(maximum_lanes - 1) + 1;
+	let depth = depth.min(lanes - 1);
+	let symbol = match *line.get_action() {
+		Action::Edit | Action::Fixup | Action::Merge | Action::Pick | Action::Reword | Action::Squash => '*',
+		_ => '|',
+	};
+
+	let mut gutter = String::new();
+	for lane in 0..lanes {
+		gutter.push(match lane.cmp(&depth) {
+			Ordering::Less => '|',
+			Ordering::Equal => symbol,
+			Ordering::Greater => ' ',
+		});
+		if is_full_width {
+			gutter.push(' ');
+		}
+	}
+	if !is_full_width {
+		gutter.push(' ');
+	}
+	gutter
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|l| Line::new(l).unwrap()).collect()
+	}
+
+	fn graph_depths(lines: &[&str]) -> Option<Vec<usize>> {
+		let lines = create_lines(lines);
+		get_graph_depths(&lines.iter().collect::<Vec<&Line>>())
+	}
+
+	#[test]
+	fn get_graph_depths_without_merges() {
+		assert_eq!(
+			graph_depths(&["label onto", "pick aaa c1", "reset onto", "pick bbb c2"]),
+			None
+		);
+	}
+
+	#[test]
+	fn get_graph_depths_merged_branch() {
+		assert_eq!(
+			graph_depths(&[
+				"label onto",
+				"reset onto",
+				"pick aaa c1",
+				"label feature",
+				"reset onto",
+				"pick bbb c2",
+				"merge -C ccc feature # Merge branch 'feature'",
+			]),
+			Some(vec![0, 1, 1, 1, 0, 0, 0])
+		);
+	}
+
+	#[test]
+	fn get_graph_depths_nested_branches() {
+		assert_eq!(
+			graph_depths(&[
+				"label onto",
+				"reset onto",
+				"pick aaa c1",
+				"label inner",
+				"reset onto",
+				"merge -C bbb inner # Merge branch 'inner'",
+				"label outer",
+				"reset onto",
+				"merge -C ccc outer # Merge branch 'outer'",
+			]),
+			Some(vec![0, 2, 2, 2, 1, 1, 1, 0, 0])
+		);
+	}
+
+	#[test]
+	fn get_graph_depths_octopus_merge() {
+		assert_eq!(
+			graph_depths(&[
+				"reset onto",
+				"pick aaa c1",
+				"label one",
+				"reset onto",
+				"pick bbb c2",
+				"label two",
+				"reset onto",
+				"merge -C ccc one two # Merge branches",
+			]),
+			Some(vec![1, 1, 1, 1, 1, 1, 0, 0])
+		);
+	}
+
+	#[rstest(
+		line,
+		depth,
+		max_depth,
+		is_full_width,
+		expected,
+		case::commit("pick aaa c1", 0, 1, true, "*   "),
+		case::branch_commit("pick aaa c1", 1, 1, true, "| * "),
+		case::branch_label("label feature", 1, 1, true, "| | "),
+		case::merge("merge -C aaa feature", 0, 2, true, "*     "),
+		case::compact("pick aaa c1", 1, 1, false, "|* "),
+		case::compact_limited("pick aaa c1", 5, 5, false, "||* "),
+		case::full_limited("pick aaa c1", 9, 9, true, "| | | | | | | * ")
+	)]
+	fn get_graph_gutter(line: &str, depth: usize, max_depth: usize, is_full_width: bool, expected: &str) {
+		assert_eq!(
+			super::get_graph_gutter(&Line::new(line).unwrap(), depth, max_depth, is_full_width),
+			expected
+		);
+	}
+}
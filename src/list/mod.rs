@@ -1,34 +1,134 @@
+mod commit_details;
+mod filter;
+mod graph;
+mod register;
+mod search;
+mod sort_key;
 mod utils;
 
 #[cfg(all(unix, test))]
 mod tests;
 
-use std::cmp::min;
+use std::{
+	cmp::{max, min},
+	collections::{HashMap, HashSet},
+	convert::TryFrom,
+	fs::read_to_string,
+	mem::replace,
+	path::Path,
+	time::Duration,
+};
+
+use anyhow::{anyhow, Result};
 
 use crate::{
-	components::{Edit, Help},
+	components::{Choice, Confirm, Edit, EditForm, Help},
 	config::Config,
+	constants::MINIMUM_FULL_WINDOW_WIDTH,
 	display::display_color::DisplayColor,
-	input::{input_handler::InputMode, Input},
-	list::utils::{get_list_normal_mode_help_lines, get_list_visual_mode_help_lines, get_todo_line_segments},
+	dry_run::DryRun,
+	input::{input_handler::InputMode, Input, Mouse, MouseAction},
+	list::{
+		commit_details::{load_commit_details, CommitDetails},
+		filter::{Filter, FilterKind},
+		graph::{get_graph_depths, get_graph_gutter},
+		register::{Register, DEFAULT_REGISTER},
+		search::line_matches,
+		sort_key::{load_sort_values, SortKey},
+		utils::{
+			get_done_line_segments,
+			get_exec_template_options,
+			get_kept_line_segments,
+			get_list_normal_mode_help_lines,
+			get_list_visual_mode_help_lines,
+			get_todo_line_segments,
+			LineMarker,
+			TodoLineOptions,
+		},
+	},
 	process::{exit_status::ExitStatus, process_module::ProcessModule, process_result::ProcessResult, state::State},
-	todo_file::{action::Action, edit_content::EditContext, line::Line, TodoFile},
+	show_commit::{
+		dependencies::DependencyLoader,
+		redundant::{Redundancy, RedundancyLoader},
+	},
+	todo_file::{
+		action::Action,
+		edit_content::EditContext,
+		fixup_message::FixupMessage,
+		line::Line,
+		validator::Problem,
+		TodoFile,
+	},
 	view::{line_segment::LineSegment, view_data::ViewData, view_line::ViewLine, View},
 };
 
+// how often the list is redrawn while waiting for the dry run of the rebase, or the dependencies of the commits
+const BACKGROUND_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const MAXIMUM_CONFLICT_PATHS: usize = 5;
+const MAXIMUM_DEPENDENCY_LINES: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MouseDrag {
+	// the index of the line where the drag started, and of the line under the pointer
+	Lines(usize, usize),
+	ScrollBar,
+}
+
 #[derive(Debug, PartialEq)]
 enum ListState {
 	Normal,
 	Visual,
 	Edit,
+	EditMerge,
+	Sort,
+	Absorb,
+	InsertExecTemplate,
+	RemoveExecTemplate,
+	Search,
+	Filter,
+	FilterValue,
 }
 
 pub struct List<'l> {
+	absorb_confirm: Confirm,
+	absorb_fixups: Vec<(usize, usize)>,
+	commit_details: HashMap<String, CommitDetails>,
 	config: &'l Config,
+	// the count typed before a command, that repeats the command
+	count: Option<usize>,
+	// an input that needs the result of a background analysis, which is handled once the analysis has loaded
+	deferred_input: Option<Input>,
+	dependency_loader: DependencyLoader,
+	dry_run: DryRun,
 	edit: Edit,
+	edit_form: EditForm,
+	filter: Option<Filter>,
+	filter_choice: Choice<Option<FilterKind>>,
+	filter_edit: Edit,
+	filter_kind: FilterKind,
+	insert_exec_template_choice: Choice<Option<usize>>,
+	mouse_drag: Option<MouseDrag>,
 	normal_mode_help: Help,
+	// the orphaned fixups of the lines they were found for, so they are only searched for again after a change
+	orphaned_fixups: (Vec<Line>, HashSet<usize>),
+	redundancy_loader: RedundancyLoader,
+	register: char,
+	registers: HashMap<char, Register>,
+	remove_exec_template_choice: Choice<Option<usize>>,
+	// the lines, and whether they were filtered, when the view was last built
+	rendered_filtered: bool,
+	rendered_lines: Vec<String>,
+	search_edit: Edit,
+	search_forward: bool,
+	search_start_index: usize,
+	search_term: Option<String>,
+	selecting_register: bool,
+	show_graph: bool,
+	sort_choice: Choice<Option<SortKey>>,
 	state: ListState,
 	view_data: ViewData,
+	// the todo line index of each body line of the view, done lines have no index
+	view_line_indexes: Vec<Option<usize>>,
 	visual_index_start: Option<usize>,
 	visual_mode_help: Help,
 }
@@ -41,21 +141,56 @@ impl<'l> ProcessModule for List<'l> {
 		self.view_data.set_view_size(view_width, view_height);
 
 		match self.state {
-			ListState::Normal => self.get_normal_mode_view_data(todo_file, view),
+			ListState::Normal | ListState::Search => self.get_normal_mode_view_data(todo_file, view),
 			ListState::Visual => self.get_visual_mode_view_data(todo_file, view),
 			ListState::Edit => {
 				self.edit.update_view_data(&mut self.view_data);
 				&self.view_data
 			},
+			ListState::EditMerge => {
+				self.edit_form.update_view_data(&mut self.view_data);
+				&self.view_data
+			},
+			ListState::Sort => self.sort_choice.get_view_data(view_width, view_height),
+			ListState::Absorb => self.absorb_confirm.get_view_data(view_width, view_height),
+			ListState::InsertExecTemplate => self.insert_exec_template_choice.get_view_data(view_width, view_height),
+			ListState::RemoveExecTemplate => self.remove_exec_template_choice.get_view_data(view_width, view_height),
+			ListState::Filter => self.filter_choice.get_view_data(view_width, view_height),
+			ListState::FilterValue => {
+				self.filter_edit.update_view_data(&mut self.view_data);
+				&self.view_data
+			},
 		}
 	}
 
 	fn handle_input(&mut self, view: &mut View<'_>, todo_file: &mut TodoFile) -> ProcessResult {
-		match self.state {
+		if let Some(result) = self.handle_deferred_input(todo_file) {
+			return result;
+		}
+		if (self.dry_run.is_pending() || self.dependency_loader.is_pending() || self.redundancy_loader.is_pending())
+			&& !view.is_input_ready(BACKGROUND_POLL_INTERVAL)
+		{
+			return ProcessResult::new();
+		}
+		if self.selecting_register {
+			return self.handle_select_register_input(view);
+		}
+		let result = match self.state {
 			ListState::Normal => self.handle_normal_mode_input(view, todo_file),
 			ListState::Visual => self.handle_visual_mode_input(view, todo_file),
 			ListState::Edit => self.handle_edit_mode_input(view, todo_file),
-		}
+			ListState::EditMerge => self.handle_edit_merge_mode_input(view, todo_file),
+			ListState::Sort => self.handle_sort_input(view, todo_file),
+			ListState::Absorb => self.handle_absorb_input(view, todo_file),
+			ListState::InsertExecTemplate | ListState::RemoveExecTemplate => {
+				self.handle_exec_template_input(view, todo_file)
+			},
+			ListState::Search => self.handle_search_input(view, todo_file),
+			ListState::Filter => self.handle_filter_input(view),
+			ListState::FilterValue => self.handle_filter_value_input(view, todo_file),
+		};
+		self.select_visible_line(todo_file);
+		result
 	}
 }
 
@@ -65,19 +200,88 @@ impl<'l> List<'l> {
 		view_data.set_show_title(true);
 		view_data.set_show_help(true);
 
+		let mut sort_choice = Choice::new(vec![
+			(Some(SortKey::AuthorDate), '1', String::from("Author date")),
+			(Some(SortKey::CommitterDate), '2', String::from("Committer date")),
+			(Some(SortKey::AuthorName), '3', String::from("Author name")),
+			(Some(SortKey::Path), '4', String::from("First changed path")),
+			(None, '5', String::from("Return to the list")),
+		]);
+		sort_choice.set_prompt(vec![ViewLine::from("Sort the selected commits by:")]);
+
+		let mut insert_exec_template_choice = Choice::new(get_exec_template_options(&config.exec_templates));
+		insert_exec_template_choice.set_prompt(vec![ViewLine::from("Insert an exec line after each commit with:")]);
+		let mut remove_exec_template_choice = Choice::new(get_exec_template_options(&config.exec_templates));
+		remove_exec_template_choice.set_prompt(vec![ViewLine::from("Remove the exec lines of:")]);
+		let search_edit = Edit::new();
+
+		let mut filter_choice = Choice::new(vec![
+			(Some(FilterKind::Action), '1', String::from("Action")),
+			(Some(FilterKind::Author), '2', String::from("Author")),
+			(Some(FilterKind::Path), '3', String::from("Changed path")),
+			(Some(FilterKind::Subject), '4', String::from("Subject pattern")),
+			(None, '5', String::from("Return to the list")),
+		]);
+		filter_choice.set_prompt(vec![ViewLine::from("Show only the lines matching:")]);
+
 		Self {
+			absorb_confirm: Confirm::new(
+				"Fixup the commits into the commits they change",
+				&config.key_bindings.confirm_yes,
+				&config.key_bindings.confirm_no,
+			),
+			absorb_fixups: vec![],
+			commit_details: HashMap::new(),
 			config,
+			count: None,
+			deferred_input: None,
+			dependency_loader: DependencyLoader::new(),
+			dry_run: DryRun::new(),
 			edit: Edit::new(),
+			edit_form: EditForm::new(),
+			filter: None,
+			filter_choice,
+			filter_edit: Edit::new(),
+			filter_kind: FilterKind::Action,
+			insert_exec_template_choice,
+			mouse_drag: None,
 			normal_mode_help: Help::new_from_keybindings(&get_list_normal_mode_help_lines(&config.key_bindings)),
+			orphaned_fixups: (vec![], HashSet::new()),
+			redundancy_loader: RedundancyLoader::new(),
+			register: DEFAULT_REGISTER,
+			registers: HashMap::new(),
+			remove_exec_template_choice,
+			rendered_filtered: false,
+			rendered_lines: vec![],
+			search_edit,
+			search_forward: true,
+			search_start_index: 0,
+			search_term: None,
+			selecting_register: false,
+			show_graph: true,
+			sort_choice,
 			state: ListState::Normal,
 			view_data,
+			view_line_indexes: vec![],
 			visual_index_start: None,
 			visual_mode_help: Help::new_from_keybindings(&get_list_visual_mode_help_lines(&config.key_bindings)),
 		}
 	}
 
-	pub(crate) fn move_cursor_up(todo_file: &mut TodoFile, amount: usize) {
+	// while a filter is active, the cursor only moves between the visible lines
+	fn move_cursor_up(&self, todo_file: &mut TodoFile, amount: usize) {
 		let current_selected_line_index = todo_file.get_selected_line_index();
+		if self.filter.is_some() {
+			let visible_indexes = self.get_visible_indexes(todo_file);
+			let position = visible_indexes
+				.iter()
+				.position(|&index| index >= current_selected_line_index)
+				.unwrap_or(visible_indexes.len());
+			if let Some(&index) = visible_indexes.get(position.saturating_sub(amount)) {
+				todo_file.set_selected_line_index(index);
+			}
+			return;
+		}
 		todo_file.set_selected_line_index(
 			if amount > current_selected_line_index {
 				0
@@ -88,27 +292,334 @@ impl<'l> List<'l> {
 		);
 	}
 
-	pub(crate) fn move_cursor_down(rebase_todo: &mut TodoFile, amount: usize) {
+	fn move_cursor_down(&self, rebase_todo: &mut TodoFile, amount: usize) {
 		let current_selected_line_index = rebase_todo.get_selected_line_index();
+		if self.filter.is_some() {
+			let visible_indexes = self.get_visible_indexes(rebase_todo);
+			let position = visible_indexes
+				.iter()
+				.position(|&index| index >= current_selected_line_index)
+				.unwrap_or(visible_indexes.len());
+			if let Some(&index) = visible_indexes.get(min(position + amount, visible_indexes.len().saturating_sub(1))) {
+				rebase_todo.set_selected_line_index(index);
+			}
+			return;
+		}
 		rebase_todo.set_selected_line_index(current_selected_line_index + amount);
 	}
 
-	fn set_selected_line_action(&self, rebase_todo: &mut TodoFile, action: Action) {
-		let start_index = rebase_todo.get_selected_line_index();
-		let end_index = self.visual_index_start.unwrap_or(start_index);
+	fn is_visible(&self, line: &Line) -> bool {
+		self.filter
+			.as_ref()
+			.map_or(true, |filter| filter.matches(line, &self.commit_details))
+	}
+
+	fn get_visible_indexes(&self, todo_file: &TodoFile) -> Vec<usize> {
+		todo_file
+			.iter()
+			.enumerate()
+			.filter(|&(_, line)| self.is_visible(line))
+			.map(|(index, _)| index)
+			.collect()
+	}
+
+	// the lines hidden by a filter are never changed, even when they are within the selected range
+	fn get_selected_indexes(&self, todo_file: &TodoFile) -> Vec<usize> {
+		let selected_index = todo_file.get_selected_line_index();
+		let visual_index = self.visual_index_start.unwrap_or(selected_index);
+		(min(selected_index, visual_index)..=max(selected_index, visual_index))
+			.filter(|&index| todo_file.get_line(index).map_or(false, |line| self.is_visible(line)))
+			.collect()
+	}
 
-		rebase_todo.update_range(start_index, end_index, &EditContext::new().action(action));
+	// a change can hide the selected line, and the filter is removed when no line is left to show
+	fn select_visible_line(&mut self, todo_file: &mut TodoFile) {
+		if self.filter.is_none() || todo_file.get_selected_line().map_or(true, |line| self.is_visible(line)) {
+			return;
+		}
+		let selected_index = todo_file.get_selected_line_index();
+		let visible_indexes = self.get_visible_indexes(todo_file);
+		match visible_indexes
+			.iter()
+			.find(|&&index| index > selected_index)
+			.or_else(|| visible_indexes.last())
+		{
+			Some(&index) => todo_file.set_selected_line_index(index),
+			None => self.filter = None,
+		}
+	}
+
+	fn update_selected_lines(&self, rebase_todo: &mut TodoFile, edit_context: &EditContext) {
+		if self.filter.is_some() {
+			rebase_todo.update_lines(&self.get_selected_indexes(rebase_todo), edit_context);
+		}
+		else {
+			let start_index = rebase_todo.get_selected_line_index();
+			let end_index = self.visual_index_start.unwrap_or(start_index);
+			rebase_todo.update_range(start_index, end_index, edit_context);
+		}
+	}
+
+	// a count changes the selected line and the lines after it, as a single change
+	fn set_selected_line_action(&self, rebase_todo: &mut TodoFile, action: Action, count: usize) {
+		let edit_context = EditContext::new().action(action);
+		if self.state == ListState::Normal && count > 1 {
+			let selected_index = rebase_todo.get_selected_line_index();
+			if self.filter.is_some() {
+				let indexes: Vec<usize> = self
+					.get_visible_indexes(rebase_todo)
+					.into_iter()
+					.filter(|&index| index >= selected_index)
+					.take(count)
+					.collect();
+				let _updated = rebase_todo.update_lines(&indexes, &edit_context);
+			}
+			else {
+				rebase_todo.update_range(selected_index, selected_index + count - 1, &edit_context);
+			}
+		}
+		else {
+			self.update_selected_lines(rebase_todo, &edit_context);
+		}
 		if self.state == ListState::Normal && self.config.auto_select_next {
-			Self::move_cursor_down(rebase_todo, 1);
+			self.move_cursor_down(rebase_todo, count);
+		}
+	}
+
+	fn cycle_selected_line_fixup_message(&self, rebase_todo: &mut TodoFile) {
+		// plain -> -C -> -c -> plain, the next option of the selected line is set on every selected line
+		let option = match rebase_todo.get_selected_line().and_then(Line::get_option) {
+			None => Some(FixupMessage::Use),
+			Some(FixupMessage::Use) => Some(FixupMessage::Edit),
+			Some(FixupMessage::Edit) => None,
+		};
+		self.update_selected_lines(rebase_todo, &EditContext::new().option(option));
+	}
+
+	// the target of a fixup must be an earlier line, so the order matters and moving lines reloads the analysis
+	fn update_orphaned_fixups(&mut self, todo_file: &TodoFile) {
+		if !todo_file.iter().eq(self.orphaned_fixups.0.iter()) {
+			self.orphaned_fixups = (
+				todo_file.get_lines_owned(),
+				todo_file.get_orphaned_fixups().into_iter().collect(),
+			);
+		}
+	}
+
+	fn update_commit_analysis(&mut self, todo_file: &TodoFile) {
+		let mut hashes: Vec<String> = todo_file
+			.iter()
+			.filter(|line| line.has_reference() && *line.get_action() != Action::Merge)
+			.map(|line| String::from(line.get_hash()))
+			.collect();
+		hashes.sort();
+		hashes.dedup();
+		self.dependency_loader.update(&hashes);
+		// the onto commit is only known during a rebase
+		let onto = read_to_string(Path::new(todo_file.get_filepath()).with_file_name("onto")).ok();
+		self.redundancy_loader.update(onto.as_deref().map(str::trim), &hashes);
+	}
+
+	fn drop_redundant(&self, todo_file: &mut TodoFile) {
+		let indexes: Vec<usize> = todo_file
+			.iter()
+			.enumerate()
+			.filter(|&(_, line)| self.is_visible(line) && self.get_redundancy(line).is_some())
+			.map(|(index, _)| index)
+			.collect();
+		todo_file.drop_lines(&indexes);
+	}
+
+	fn handle_deferred_input(&mut self, todo_file: &mut TodoFile) -> Option<ProcessResult> {
+		let input = self.deferred_input?;
+		self.update_commit_analysis(todo_file);
+		let mut result = ProcessResult::new();
+		match input {
+			Input::Absorb if !self.dependency_loader.is_pending() => {
+				if let Err(err) = self.start_absorb(todo_file) {
+					result = result.error(err);
+				}
+			},
+			Input::DropRedundant if !self.redundancy_loader.is_pending() => self.drop_redundant(todo_file),
+			_ => return None,
+		}
+		self.deferred_input = None;
+		Some(result)
+	}
+
+	fn get_redundancy(&self, line: &Line) -> Option<Redundancy> {
+		if !line.has_reference() || matches!(*line.get_action(), Action::Drop | Action::Merge) {
+			return None;
+		}
+		self.redundancy_loader.get_redundancy(line.get_hash())
+	}
+
+	// a commit that is placed above a commit it depends on would be applied before the changes it builds on
+	fn get_misplaced_dependencies(&self, todo_file: &TodoFile, index: usize) -> Vec<usize> {
+		let line = match todo_file.get_line(index) {
+			Some(line) if line.has_reference() && *line.get_action() != Action::Drop => line,
+			_ => return vec![],
+		};
+		self.dependency_loader
+			.get_dependencies()
+			.get_depends_on(line.get_hash())
+			.iter()
+			.filter_map(|hash| {
+				todo_file
+					.iter()
+					.enumerate()
+					.skip(index + 1)
+					.find(|&(_, other)| other.get_hash() == hash && *other.get_action() != Action::Drop)
+					.map(|(other_index, _)| other_index)
+			})
+			.collect()
+	}
+
+	// a commit clearly belongs in an earlier commit when all the lines it changes were last changed by that commit
+	fn get_fixup_target(&self, todo_file: &TodoFile, index: usize) -> Option<usize> {
+		let line = todo_file.get_line(index)?;
+		if !line.has_reference() || matches!(*line.get_action(), Action::Drop | Action::Merge) {
+			return None;
+		}
+		let mut targets: Vec<usize> = self
+			.dependency_loader
+			.get_dependencies()
+			.get_depends_on(line.get_hash())
+			.iter()
+			.filter_map(|hash| {
+				todo_file
+					.iter()
+					.position(|other| other.get_hash() == hash && *other.get_action() != Action::Drop)
+			})
+			.collect();
+		targets.sort_unstable();
+		targets.dedup();
+		match targets.as_slice() {
+			&[target_index] if target_index < index => Some(target_index),
+			_ => None,
+		}
+	}
+
+	fn start_absorb(&mut self, todo_file: &TodoFile) -> Result<()> {
+		if self.filter.is_some() {
+			return Err(anyhow!("Lines can not be moved while the list is filtered"));
+		}
+		let selected_index = todo_file.get_selected_line_index();
+		let visual_index = self.visual_index_start.unwrap_or(selected_index);
+		let fixups: Vec<(usize, usize)> = (min(selected_index, visual_index)..=max(selected_index, visual_index))
+			.filter(|&index| todo_file.get_line(index).map_or(false, |line| self.is_visible(line)))
+			.filter_map(|index| self.get_fixup_target(todo_file, index).map(|target| (index, target)))
+			.collect();
+		// a commit that is moved can not also be the target of another commit
+		self.absorb_fixups = fixups
+			.iter()
+			.filter(|&&(_, target_index)| !fixups.iter().any(|&(index, _)| index == target_index))
+			.copied()
+			.collect();
+		if self.absorb_fixups.is_empty() {
+			return Err(anyhow!("No earlier commit found that the selected commits change"));
+		}
+
+		let describe = |index: usize| {
+			let line = todo_file.get_line(index).unwrap();
+			format!("{:8} {}", &line.get_hash()[0..min(line.get_hash().len(), 8)], line.get_content())
+		};
+		self.absorb_confirm.set_details(
+			self.absorb_fixups
+				.iter()
+				.map(|&(index, target_index)| {
+					ViewLine::from(format!("  {} → {}", describe(index), describe(target_index)))
+				})
+				.collect(),
+		);
+		self.state = ListState::Absorb;
+		Ok(())
+	}
+
+	fn push_dependency_lines(
+		view_data: &mut ViewData,
+		todo_file: &TodoFile,
+		title: &str,
+		hashes: &[&str],
+		misplaced: &[usize],
+	) {
+		let indexes: Vec<usize> = hashes
+			.iter()
+			.filter_map(|&hash| todo_file.iter().position(|line| line.get_hash() == hash))
+			.collect();
+		if indexes.is_empty() {
+			return;
+		}
+		view_data.push_trailing_line(ViewLine::new_empty_line());
+		view_data.push_trailing_line(ViewLine::from(title));
+		for &index in indexes.iter().take(MAXIMUM_DEPENDENCY_LINES) {
+			let line = todo_file.get_line(index).unwrap();
+			let text = format!("  {:8} {}", &line.get_hash()[0..min(line.get_hash().len(), 8)], line.get_content());
+			view_data.push_trailing_line(if misplaced.contains(&index) {
+				ViewLine::from(vec![
+					LineSegment::new(text.as_str()),
+					LineSegment::new_with_color(" (below this commit)", DisplayColor::WarningColor),
+				])
+			}
+			else {
+				ViewLine::from(text)
+			});
+		}
+		if indexes.len() > MAXIMUM_DEPENDENCY_LINES {
+			view_data.push_trailing_line(ViewLine::from(format!(
+				"  ... and {} more",
+				indexes.len() - MAXIMUM_DEPENDENCY_LINES
+			)));
 		}
 	}
 
 	fn update_list_view_data(&mut self, todo_file: &TodoFile, view_width: usize, view_height: usize) {
 		self.view_data.clear();
 		self.view_data.set_view_size(view_width, view_height);
+		self.view_line_indexes.clear();
+		self.rendered_filtered = self.filter.is_some();
+		self.rendered_lines = todo_file.iter().map(Line::to_text).collect();
+		// a count that is being typed is shown after the filter, and a slow analysis is shown while it loads
+		let loading_status = match self.deferred_input {
+			Some(Input::Absorb) => Some("Loading dependencies"),
+			Some(Input::DropRedundant) => Some("Finding redundant commits"),
+			_ if self.redundancy_loader.is_pending_for(BACKGROUND_POLL_INTERVAL) => Some("Finding redundant commits"),
+			_ => None,
+		};
+		let title_status = self
+			.filter
+			.as_ref()
+			.map(Filter::get_description)
+			.into_iter()
+			.chain(self.count.map(|count| count.to_string()))
+			.chain(loading_status.map(String::from))
+			.collect::<Vec<String>>()
+			.join("  ");
+		self.view_data
+			.set_title_status(Some(title_status.as_str()).filter(|status| !status.is_empty()));
 		let is_visual_mode = self.state == ListState::Visual;
 		let selected_index = todo_file.get_selected_line_index();
 		let visual_index = self.visual_index_start.unwrap_or(selected_index);
+		let selected_done_index = todo_file.get_selected_done_line_index();
+		// the done lines are never changed, so they are hidden along with everything else a filter does not match
+		let done_lines = if self.filter.is_some() {
+			&[]
+		}
+		else {
+			todo_file.get_done_lines()
+		};
+
+		for (index, line) in done_lines.iter().enumerate() {
+			let is_cursor_line = selected_done_index == Some(index);
+			self.view_line_indexes.push(None);
+			self.view_data.push_line(
+				ViewLine::new_with_pinned_segments(
+					get_done_line_segments(line, is_cursor_line, index + 1 == done_lines.len(), view_width),
+					2,
+				)
+				.set_selected(is_cursor_line),
+			);
+		}
 
 		if todo_file.is_empty() {
 			self.view_data
@@ -118,24 +629,197 @@ impl<'l> List<'l> {
 				)));
 		}
 		else {
+			self.dry_run.update(todo_file);
+			self.update_commit_analysis(todo_file);
+			let conflicts = self.dry_run.get_conflicts().to_vec();
+			let failed_index = self.dry_run.get_failed_index();
+			let problem_lines: HashSet<usize> = todo_file.get_problems().iter().map(Problem::get_index).collect();
+			self.update_orphaned_fixups(todo_file);
+			let orphaned_fixups = &self.orphaned_fixups.1;
+			let graph_depths = if self.show_graph {
+				get_graph_depths(&todo_file.iter().collect::<Vec<&Line>>())
+			}
+			else {
+				None
+			};
+			let max_graph_depth = graph_depths.as_ref().and_then(|depths| depths.iter().max().copied());
+			let search_term = self.search_term.clone().filter(|term| !term.is_empty());
+			let cut_range = self
+				.registers
+				.values()
+				.find_map(|content| content.get_cut_range(todo_file));
 			for (index, line) in todo_file.iter().enumerate() {
+				if !self.is_visible(line) {
+					continue;
+				}
+				// the kept lines are only shown with all of the lines, since they do not match any filter
+				if self.filter.is_none() {
+					for kept_line in line.get_kept_lines() {
+						self.view_line_indexes.push(None);
+						self.view_data.push_line(ViewLine::new_with_pinned_segments(
+							get_kept_line_segments(kept_line, view_width),
+							1,
+						));
+					}
+				}
+				self.view_line_indexes.push(Some(index));
+				let is_cursor_line = selected_done_index.is_none() && selected_index == index;
 				let selected_line = is_visual_mode
 					&& ((visual_index <= selected_index && index >= visual_index && index <= selected_index)
 						|| (visual_index > selected_index && index >= selected_index && index <= visual_index));
+				let graph_gutter = graph_depths.as_ref().map(|depths| {
+					get_graph_gutter(
+						line,
+						depths[index],
+						max_graph_depth.unwrap_or(0),
+						view_width >= MINIMUM_FULL_WINDOW_WIDTH,
+					)
+				});
+				let marker = if cut_range.map_or(false, |(start_index, end_index)| {
+					index >= start_index && index <= end_index
+				}) {
+					Some(LineMarker::Cut)
+				}
+				else if problem_lines.contains(&index) {
+					Some(LineMarker::Problem)
+				}
+				else if conflicts.iter().any(|c| c.get_index() == index) {
+					Some(LineMarker::Conflict)
+				}
+				else if failed_index == Some(index) {
+					Some(LineMarker::Unchecked)
+				}
+				else if let Some(redundancy) = self.get_redundancy(line) {
+					Some(match redundancy {
+						Redundancy::Empty => LineMarker::Empty,
+						Redundancy::Upstream => LineMarker::Upstream,
+					})
+				}
+				else if self.get_misplaced_dependencies(todo_file, index).is_empty() {
+					None
+				}
+				else {
+					Some(LineMarker::Dependency)
+				};
+				// in a narrow view, the marker of a selected line is a segment of its own
+				let pinned_segments = if *line.get_action() == Action::Exec { 2 } else { 3 }
+					+ usize::from(graph_gutter.is_some())
+					+ usize::from(
+						view_width < MINIMUM_FULL_WINDOW_WIDTH && marker.is_some() && (is_cursor_line || selected_line),
+					);
 				self.view_data.push_line(
 					ViewLine::new_with_pinned_segments(
-						get_todo_line_segments(line, selected_index == index, selected_line, view_width),
-						if *line.get_action() == Action::Exec { 2 } else { 3 },
+						get_todo_line_segments(
+							line,
+							&TodoLineOptions {
+								graph_gutter: graph_gutter.as_deref(),
+								is_cursor_line,
+								is_orphaned_fixup: orphaned_fixups.contains(&index),
+								marker,
+								search_term: search_term.as_deref(),
+								selected: selected_line,
+							},
+							view_width,
+						),
+						if line.get_option().is_some() {
+							pinned_segments + 1
+						}
+						else {
+							pinned_segments
+						},
 					)
-					.set_selected(selected_index == index || selected_line),
+					.set_selected(is_cursor_line || selected_line),
 				);
 			}
+
+			if selected_done_index.is_none() {
+				if let Some(line) = todo_file.get_selected_line() {
+					if let Some(redundancy) = self.get_redundancy(line) {
+						self.view_data.push_trailing_line(ViewLine::new_empty_line());
+						self.view_data
+							.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+								match redundancy {
+									Redundancy::Empty => "This commit is empty",
+									Redundancy::Upstream => "This commit is already upstream and will be skipped",
+								},
+								DisplayColor::WarningColor,
+							)));
+					}
+					let hash = line.get_hash();
+					let dependencies = self.dependency_loader.get_dependencies();
+					let depends_on: Vec<&str> = dependencies.get_depends_on(hash).iter().map(String::as_str).collect();
+					let depended_on_by = dependencies.get_depended_on_by(hash);
+					let misplaced = self.get_misplaced_dependencies(todo_file, selected_index);
+					Self::push_dependency_lines(&mut self.view_data, todo_file, "Depends on:", &depends_on, &misplaced);
+					Self::push_dependency_lines(
+						&mut self.view_data,
+						todo_file,
+						"Depended on by:",
+						&depended_on_by,
+						&[],
+					);
+				}
+			}
+
+			if selected_done_index.is_none() {
+				if let Some(conflict) = conflicts.iter().find(|c| c.get_index() == selected_index) {
+					self.view_data.push_trailing_line(ViewLine::new_empty_line());
+					self.view_data
+						.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+							"Applying this commit would conflict in:",
+							DisplayColor::WarningColor,
+						)));
+					for path in conflict.get_paths().iter().take(MAXIMUM_CONFLICT_PATHS) {
+						self.view_data.push_trailing_line(ViewLine::from(format!("  {}", path)));
+					}
+					if conflict.get_paths().len() > MAXIMUM_CONFLICT_PATHS {
+						self.view_data.push_trailing_line(ViewLine::from(format!(
+							"  ... and {} more",
+							conflict.get_paths().len() - MAXIMUM_CONFLICT_PATHS
+						)));
+					}
+				}
+				else if failed_index == Some(selected_index) {
+					self.view_data.push_trailing_line(ViewLine::new_empty_line());
+					self.view_data
+						.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+							"This line could not be applied, so the lines after it were not checked",
+							DisplayColor::WarningColor,
+						)));
+				}
+			}
+		}
+		if self.state == ListState::Search {
+			let mut segments = self.search_edit.get_line_segments(true);
+			let search_term = self.search_edit.get_content();
+			if !search_term.is_empty()
+				&& !todo_file
+					.iter()
+					.any(|line| self.is_visible(line) && line_matches(line, search_term.as_str(), &self.commit_details))
+			{
+				segments.push(LineSegment::new_with_color("  No matching lines", DisplayColor::WarningColor));
+			}
+			self.view_data.push_trailing_line(ViewLine::new_empty_line());
+			self.view_data.push_trailing_line(ViewLine::new_pinned(segments));
 		}
 		self.view_data.rebuild();
-		if let Some(visual_index) = self.visual_index_start {
-			self.view_data.ensure_line_visible(visual_index);
+		if let Some(done_index) = selected_done_index {
+			self.view_data.ensure_line_visible(done_index);
+			return;
+		}
+		// hidden lines have no row, and the done and kept lines have rows without a line
+		let get_row = |index: usize| {
+			self.view_line_indexes
+				.iter()
+				.position(|&view_index| view_index.map_or(false, |view_index| view_index >= index))
+				.unwrap_or_else(|| self.view_line_indexes.len())
+		};
+		let visual_row = self.visual_index_start.map(get_row);
+		let selected_row = get_row(selected_index);
+		if let Some(visual_row) = visual_row {
+			self.view_data.ensure_line_visible(visual_row);
 		}
-		self.view_data.ensure_line_visible(selected_index);
+		self.view_data.ensure_line_visible(selected_row);
 	}
 
 	fn get_visual_mode_view_data(&mut self, todo_file: &TodoFile, view: &View<'_>) -> &ViewData {
@@ -167,6 +851,7 @@ impl<'l> List<'l> {
 	fn handle_common_list_input(
 		&mut self,
 		input: Input,
+		count: usize,
 		view: &View<'_>,
 		rebase_todo: &mut TodoFile,
 	) -> Option<ProcessResult> {
@@ -174,12 +859,12 @@ impl<'l> List<'l> {
 		match input {
 			Input::MoveCursorLeft => self.view_data.scroll_left(),
 			Input::MoveCursorRight => self.view_data.scroll_right(),
-			Input::MoveCursorDown => Self::move_cursor_down(rebase_todo, 1),
-			Input::MoveCursorUp => Self::move_cursor_up(rebase_todo, 1),
-			Input::MoveCursorPageDown => Self::move_cursor_down(rebase_todo, view.get_view_size().height() / 2),
-			Input::MoveCursorPageUp => Self::move_cursor_up(rebase_todo, view.get_view_size().height() / 2),
-			Input::MoveCursorHome => rebase_todo.set_selected_line_index(0),
-			Input::MoveCursorEnd => rebase_todo.set_selected_line_index(rebase_todo.get_max_selected_line_index()),
+			Input::MoveCursorDown => self.move_cursor_down(rebase_todo, count),
+			Input::MoveCursorUp => self.move_cursor_up(rebase_todo, count),
+			Input::MoveCursorPageDown => self.move_cursor_down(rebase_todo, view.get_view_size().height() / 2),
+			Input::MoveCursorPageUp => self.move_cursor_up(rebase_todo, view.get_view_size().height() / 2),
+			Input::MoveCursorHome => self.move_cursor_up(rebase_todo, rebase_todo.get_max_selected_line_index()),
+			Input::MoveCursorEnd => self.move_cursor_down(rebase_todo, rebase_todo.get_max_selected_line_index()),
 			Input::Abort => result = result.state(State::ConfirmAbort),
 			Input::ForceAbort => {
 				rebase_todo.set_lines(vec![]);
@@ -187,35 +872,18 @@ impl<'l> List<'l> {
 			},
 			Input::Rebase => result = result.state(State::ConfirmRebase),
 			Input::ForceRebase => result = result.exit_status(ExitStatus::Good),
-			Input::SwapSelectedDown => {
-				let start_index = rebase_todo.get_selected_line_index();
-				let end_index = self.visual_index_start.unwrap_or(start_index);
-
-				if rebase_todo.swap_range_down(start_index, end_index) {
-					if let Some(visual_index_start) = self.visual_index_start {
-						self.visual_index_start = Some(visual_index_start + 1);
-					}
-
-					Self::move_cursor_down(rebase_todo, 1);
-				}
+			Input::SwapSelectedDown | Input::SwapSelectedUp if self.filter.is_some() => {
+				result = result.error(anyhow!("Lines can not be moved while the list is filtered"));
 			},
-			Input::SwapSelectedUp => {
-				let start_index = rebase_todo.get_selected_line_index();
-				let end_index = self.visual_index_start.unwrap_or(start_index);
-
-				if rebase_todo.swap_range_up(start_index, end_index) {
-					if let Some(visual_index_start) = self.visual_index_start {
-						self.visual_index_start = Some(visual_index_start - 1);
-					}
-					Self::move_cursor_up(rebase_todo, 1);
-				}
-			},
-			Input::ActionDrop => self.set_selected_line_action(rebase_todo, Action::Drop),
-			Input::ActionEdit => self.set_selected_line_action(rebase_todo, Action::Edit),
-			Input::ActionFixup => self.set_selected_line_action(rebase_todo, Action::Fixup),
-			Input::ActionPick => self.set_selected_line_action(rebase_todo, Action::Pick),
-			Input::ActionReword => self.set_selected_line_action(rebase_todo, Action::Reword),
-			Input::ActionSquash => self.set_selected_line_action(rebase_todo, Action::Squash),
+			Input::SwapSelectedDown => self.move_selected_down(rebase_todo, count),
+			Input::SwapSelectedUp => self.move_selected_up(rebase_todo, count),
+			Input::ActionDrop => self.set_selected_line_action(rebase_todo, Action::Drop, count),
+			Input::ActionEdit => self.set_selected_line_action(rebase_todo, Action::Edit, count),
+			Input::ActionFixup => self.set_selected_line_action(rebase_todo, Action::Fixup, count),
+			Input::ActionPick => self.set_selected_line_action(rebase_todo, Action::Pick, count),
+			Input::ActionReword => self.set_selected_line_action(rebase_todo, Action::Reword, count),
+			Input::ActionSquash => self.set_selected_line_action(rebase_todo, Action::Squash, count),
+			Input::CycleFixupMessage => self.cycle_selected_line_fixup_message(rebase_todo),
 			Input::Undo => {
 				if let Some((start_index, end_index)) = rebase_todo.undo() {
 					rebase_todo.set_selected_line_index(start_index);
@@ -246,7 +914,12 @@ impl<'l> List<'l> {
 				let start_index = rebase_todo.get_selected_line_index();
 				let end_index = self.visual_index_start.unwrap_or(start_index);
 
-				rebase_todo.remove_lines(start_index, end_index);
+				if self.filter.is_some() {
+					rebase_todo.remove_lines_at(&self.get_selected_indexes(rebase_todo));
+				}
+				else {
+					rebase_todo.remove_lines(start_index, end_index);
+				}
 				let new_index = min(start_index, end_index);
 
 				rebase_todo.set_selected_line_index(new_index);
@@ -255,7 +928,58 @@ impl<'l> List<'l> {
 					self.visual_index_start = Some(rebase_todo.get_selected_line_index());
 				}
 			},
+			Input::Absorb => {
+				self.update_commit_analysis(rebase_todo);
+				if self.filter.is_none() && self.dependency_loader.is_pending() {
+					self.deferred_input = Some(Input::Absorb);
+				}
+				else if let Err(err) = self.start_absorb(rebase_todo) {
+					result = result.error(err);
+				}
+			},
+			Input::InsertExecTemplate => {
+				if self.config.exec_templates.is_empty() {
+					result = result.error(anyhow!("No exec templates are configured"));
+				}
+				else {
+					self.state = ListState::InsertExecTemplate;
+				}
+			},
+			Input::Cut if self.filter.is_some() => {
+				result = result.error(anyhow!("Lines can not be moved while the list is filtered"));
+			},
+			Input::Cut | Input::Yank => {
+				let register = self.take_register();
+				let indexes = self.get_selected_indexes(rebase_todo);
+				if let Some(&first_index) = indexes.first() {
+					let lines = indexes.iter().filter_map(|&index| rebase_todo.get_line(index)).cloned().collect();
+					let content = if input == Input::Cut {
+						// only one set of lines is waiting to be moved at a time
+						for content in self.registers.values_mut() {
+							content.clear_cut();
+						}
+						Register::new_cut(lines, first_index)
+					}
+					else {
+						Register::new(lines)
+					};
+					let _previous = self.registers.insert(register, content);
+				}
+				self.state = ListState::Normal;
+				self.visual_index_start = None;
+			},
+			Input::SelectRegister => self.selecting_register = true,
+			Input::Mouse(mouse) => {
+				if let Err(err) = self.handle_mouse_input(mouse, rebase_todo) {
+					result = result.error(err);
+				}
+			},
+			Input::ToggleGraph => self.show_graph = !self.show_graph,
+			// the next match follows the direction of the search
+			Input::SearchNext => self.move_to_search_match(rebase_todo, self.search_forward),
+			Input::SearchPrevious => self.move_to_search_match(rebase_todo, !self.search_forward),
 			Input::OpenInEditor => result = result.state(State::ExternalEditor),
+			Input::ShowHistory => result = result.state(State::UndoHistory),
 			Input::ToggleVisualMode => {
 				if self.state == ListState::Visual {
 					self.state = ListState::Normal;
@@ -280,6 +1004,15 @@ impl<'l> List<'l> {
 		Some(result)
 	}
 
+	// the wheel moves the cursor in the same way as the arrow keys, without depending on their key bindings
+	fn get_list_input(view: &mut View<'_>) -> Input {
+		match view.get_input(InputMode::List) {
+			Input::Mouse(mouse) if mouse.get_action() == MouseAction::ScrollDown => Input::MoveCursorDown,
+			Input::Mouse(mouse) if mouse.get_action() == MouseAction::ScrollUp => Input::MoveCursorUp,
+			input => input,
+		}
+	}
+
 	fn handle_normal_mode_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
 		if self.normal_mode_help.is_active() {
 			let input = view.get_input(InputMode::Default);
@@ -287,8 +1020,23 @@ impl<'l> List<'l> {
 			return ProcessResult::new().input(input);
 		}
 
-		let input = view.get_input(InputMode::List);
-		if let Some(result) = self.handle_common_list_input(input, view, rebase_todo) {
+		let input = Self::get_list_input(view);
+		if self.handle_count_input(input) {
+			return ProcessResult::new().input(input);
+		}
+		let count = self.take_count(rebase_todo);
+		if let Some(done_index) = rebase_todo.get_selected_done_line_index() {
+			self.handle_done_line_input(input, view, rebase_todo, done_index)
+		}
+		else if input == Input::MoveCursorUp
+			&& rebase_todo.get_selected_line_index() == 0
+			&& !rebase_todo.get_done_lines().is_empty()
+			&& self.filter.is_none()
+		{
+			rebase_todo.set_selected_done_line_index(Some(rebase_todo.get_done_lines().len() - 1));
+			ProcessResult::new().input(input)
+		}
+		else if let Some(result) = self.handle_common_list_input(input, count, view, rebase_todo) {
 			result
 		}
 		else {
@@ -312,17 +1060,28 @@ impl<'l> List<'l> {
 							.map_or(false, |line| line.get_action() == &Action::Break);
 						if selected_action_is_break {
 							rebase_todo.remove_lines(selected_line_index, selected_line_index);
-							Self::move_cursor_up(rebase_todo, 1);
+							self.move_cursor_up(rebase_todo, 1);
 						}
 						else {
 							rebase_todo.add_line(selected_line_index + 1, Line::new_break());
-							Self::move_cursor_down(rebase_todo, 1);
+							self.move_cursor_down(rebase_todo, 1);
 						}
 					}
 				},
 				Input::Edit => {
 					if let Some(selected_line) = rebase_todo.get_selected_line() {
-						if selected_line.is_editable() {
+						if selected_line.get_action() == &Action::Merge {
+							self.state = ListState::EditMerge;
+							self.edit_form.clear();
+							self.edit_form
+								.set_description(format!("Modifying line: {}", selected_line.to_text()).as_str());
+							let option = selected_line.get_option().map(FixupMessage::as_string).unwrap_or_default();
+							self.edit_form.add_field("Option:  ", option.as_str());
+							self.edit_form.add_field("Commit:  ", selected_line.get_hash());
+							self.edit_form.add_field("Labels:  ", selected_line.get_content());
+							self.edit_form.add_field("Oneline: ", selected_line.get_comment());
+						}
+						else if selected_line.is_editable() {
 							self.state = ListState::Edit;
 							self.edit.set_content(selected_line.get_content());
 							self.edit
@@ -333,12 +1092,102 @@ impl<'l> List<'l> {
 					}
 				},
 				Input::InsertLine => result = result.state(State::Insert),
+				Input::Search | Input::SearchBackward => {
+					self.state = ListState::Search;
+					self.search_forward = input == Input::Search;
+					self.search_edit.clear();
+					self.search_edit.set_label(if self.search_forward { "/" } else { "?" });
+					self.search_term = None;
+					self.search_start_index = rebase_todo.get_selected_line_index();
+					load_commit_details(&mut self.commit_details, &rebase_todo.iter().collect::<Vec<&Line>>());
+				},
+				Input::PasteAfter | Input::PasteBefore => {
+					if let Err(err) = self.paste(rebase_todo, input == Input::PasteAfter) {
+						result = result.error(err);
+					}
+				},
+				Input::Filter => {
+					if self.filter.is_some() {
+						self.filter = None;
+					}
+					else {
+						self.state = ListState::Filter;
+						load_commit_details(&mut self.commit_details, &rebase_todo.iter().collect::<Vec<&Line>>());
+					}
+				},
+				Input::Autosquash => {
+					if self.filter.is_some() {
+						result = result.error(anyhow!("Lines can not be moved while the list is filtered"));
+					}
+					else {
+						rebase_todo.autosquash();
+					}
+				},
+				Input::RemoveExecTemplate => {
+					if self.config.exec_templates.is_empty() {
+						result = result.error(anyhow!("No exec templates are configured"));
+					}
+					else {
+						self.state = ListState::RemoveExecTemplate;
+					}
+				},
+				Input::DropRedundant => {
+					self.update_commit_analysis(rebase_todo);
+					if self.redundancy_loader.is_pending() {
+						self.deferred_input = Some(Input::DropRedundant);
+					}
+					else {
+						self.drop_redundant(rebase_todo);
+					}
+				},
 				_ => {},
 			}
 			result
 		}
 	}
 
+	// the applied lines are read only, so only movement, viewing the commit and leaving the list are allowed
+	fn handle_done_line_input(
+		&mut self,
+		input: Input,
+		view: &View<'_>,
+		rebase_todo: &mut TodoFile,
+		done_index: usize,
+	) -> ProcessResult {
+		let mut result = ProcessResult::new().input(input);
+		match input {
+			Input::MoveCursorUp => rebase_todo.set_selected_done_line_index(Some(done_index.saturating_sub(1))),
+			Input::MoveCursorDown => {
+				if done_index + 1 < rebase_todo.get_done_lines().len() {
+					rebase_todo.set_selected_done_line_index(Some(done_index + 1));
+				}
+				else {
+					rebase_todo.set_selected_done_line_index(None);
+					rebase_todo.set_selected_line_index(0);
+				}
+			},
+			Input::MoveCursorHome => rebase_todo.set_selected_done_line_index(Some(0)),
+			Input::ShowCommit => {
+				if rebase_todo.get_selected_done_line().map_or(false, Line::has_reference) {
+					result = result.state(State::ShowCommit);
+				}
+			},
+			Input::MoveCursorLeft
+			| Input::MoveCursorRight
+			| Input::Abort
+			| Input::ForceAbort
+			| Input::Rebase
+			| Input::ForceRebase
+			| Input::Help => {
+				if let Some(common_result) = self.handle_common_list_input(input, 1, view, rebase_todo) {
+					result = common_result;
+				}
+			},
+			_ => {},
+		}
+		result
+	}
+
 	fn handle_visual_mode_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
 		if self.visual_mode_help.is_active() {
 			let input = view.get_input(InputMode::Default);
@@ -346,11 +1195,459 @@ impl<'l> List<'l> {
 			return ProcessResult::new().input(input);
 		}
 
-		let input = view.get_input(InputMode::List);
-		self.handle_common_list_input(input, view, rebase_todo)
+		let input = Self::get_list_input(view);
+		if self.handle_count_input(input) {
+			return ProcessResult::new().input(input);
+		}
+		let count = self.take_count(rebase_todo);
+		if input == Input::SortSelection {
+			if self.filter.is_some() {
+				return ProcessResult::new()
+					.input(input)
+					.error(anyhow!("Lines can not be moved while the list is filtered"));
+			}
+			// the commits around a label, reset or merge belong to different branches, so they are not sorted together
+			if self
+				.get_selected_indexes(rebase_todo)
+				.into_iter()
+				.filter_map(|index| rebase_todo.get_line(index))
+				.any(|line| matches!(*line.get_action(), Action::Label | Action::Merge | Action::Reset))
+			{
+				return ProcessResult::new()
+					.input(input)
+					.error(anyhow!("Lines with a label, reset or merge can not be sorted"));
+			}
+			self.state = ListState::Sort;
+			return ProcessResult::new().input(input);
+		}
+		self.handle_common_list_input(input, count, view, rebase_todo)
 			.map_or_else(|| ProcessResult::new().input(input), |result| result)
 	}
 
+	fn handle_sort_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Default);
+		let mut result = ProcessResult::new().input(input);
+		if let Some(sort_key) = self.sort_choice.handle_input(input).copied() {
+			self.state = ListState::Visual;
+			if let Some(sort_key) = sort_key {
+				let selected_index = rebase_todo.get_selected_line_index();
+				let visual_index = self.visual_index_start.unwrap_or(selected_index);
+				let first_index = min(selected_index, visual_index);
+				let last_index = max(selected_index, visual_index);
+				let lines: Vec<Line> = rebase_todo
+					.iter()
+					.skip(first_index)
+					.take(last_index - first_index + 1)
+					.cloned()
+					.collect();
+				match load_sort_values(&lines, sort_key) {
+					Ok(values) => {
+						rebase_todo.sort_range(first_index, last_index, &values);
+					},
+					Err(err) => result = result.error(err),
+				}
+			}
+		}
+		result
+	}
+
+	fn handle_absorb_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Confirm);
+		if let Some(confirmed) = self.absorb_confirm.handle_input(input) {
+			if confirmed {
+				let first_hash = self
+					.absorb_fixups
+					.first()
+					.and_then(|&(index, _)| rebase_todo.get_line(index))
+					.map(|line| String::from(line.get_hash()));
+				rebase_todo.fixup_into(&self.absorb_fixups);
+				if let Some(index) = rebase_todo.iter().position(|line| {
+					Some(line.get_hash()) == first_hash.as_deref() && *line.get_action() == Action::Fixup
+				}) {
+					rebase_todo.set_selected_line_index(index);
+				}
+				self.visual_index_start = None;
+				self.state = ListState::Normal;
+			}
+			else if self.visual_index_start.is_some() {
+				self.state = ListState::Visual;
+			}
+			else {
+				self.state = ListState::Normal;
+			}
+		}
+		ProcessResult::new().input(input)
+	}
+
+	fn handle_exec_template_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Default);
+		let choice = if self.state == ListState::InsertExecTemplate {
+			self.insert_exec_template_choice.handle_input(input).copied()
+		}
+		else {
+			self.remove_exec_template_choice.handle_input(input).copied()
+		};
+		if let Some(choice) = choice {
+			if let Some(template_index) = choice {
+				let command = self.config.exec_templates[template_index].1.as_str();
+				if self.state == ListState::InsertExecTemplate {
+					if self.filter.is_some() {
+						let indexes = if self.visual_index_start.is_some() {
+							self.get_selected_indexes(rebase_todo)
+						}
+						else {
+							self.get_visible_indexes(rebase_todo)
+						};
+						rebase_todo.add_exec_after_lines(&indexes, command);
+					}
+					else {
+						// without a selection, the exec line is added after every commit
+						let (start_index, end_index) = self.visual_index_start.map_or_else(
+							|| (0, rebase_todo.get_max_selected_line_index()),
+							|visual_index| (visual_index, rebase_todo.get_selected_line_index()),
+						);
+						rebase_todo.add_exec_after_commits(start_index, end_index, command);
+					}
+				}
+				else if self.filter.is_some() {
+					let indexes: Vec<usize> = self
+						.get_visible_indexes(rebase_todo)
+						.into_iter()
+						.filter(|&index| {
+							rebase_todo.get_line(index).map_or(false, |line| {
+								*line.get_action() == Action::Exec && line.get_content() == command
+							})
+						})
+						.collect();
+					rebase_todo.remove_lines_at(&indexes);
+				}
+				else {
+					rebase_todo.remove_exec_lines(command);
+				}
+				self.visual_index_start = None;
+			}
+			self.state = if self.visual_index_start.is_some() {
+				ListState::Visual
+			}
+			else {
+				ListState::Normal
+			};
+		}
+		ProcessResult::new().input(input)
+	}
+
+	fn handle_search_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Raw);
+		match input {
+			Input::Enter => self.state = ListState::Normal,
+			Input::Escape => {
+				self.search_term = None;
+				rebase_todo.set_selected_line_index(self.search_start_index);
+				self.state = ListState::Normal;
+			},
+			_ => {
+				if self.search_edit.handle_input(input) {
+					self.search_term = Some(self.search_edit.get_content());
+					// the line where the search started is included, so a longer term keeps the matching line selected
+					let index = self
+						.find_search_match(rebase_todo, self.search_start_index, self.search_forward, true)
+						.unwrap_or(self.search_start_index);
+					rebase_todo.set_selected_line_index(index);
+				}
+			},
+		}
+		ProcessResult::new().input(input)
+	}
+
+	// searching wraps around the ends of the list
+	fn find_search_match(&self, todo_file: &TodoFile, start: usize, forward: bool, inclusive: bool) -> Option<usize> {
+		let term = self.search_term.as_deref().filter(|term| !term.is_empty())?;
+		let lines: Vec<&Line> = todo_file.iter().collect();
+		let length = lines.len();
+		let offsets = if inclusive { 0..length } else { 1..length + 1 };
+		offsets
+			.map(|offset| {
+				if forward {
+					(start + offset) % length
+				}
+				else {
+					(start + length - offset % length) % length
+				}
+			})
+			.find(|&index| self.is_visible(lines[index]) && line_matches(lines[index], term, &self.commit_details))
+	}
+
+	fn move_to_search_match(&self, rebase_todo: &mut TodoFile, forward: bool) {
+		let selected_index = rebase_todo.get_selected_line_index();
+		if let Some(index) = self.find_search_match(rebase_todo, selected_index, forward, false) {
+			rebase_todo.set_selected_line_index(index);
+		}
+	}
+
+	fn swap_selected_down(&mut self, rebase_todo: &mut TodoFile) -> bool {
+		let start_index = rebase_todo.get_selected_line_index();
+		let end_index = self.visual_index_start.unwrap_or(start_index);
+
+		if !rebase_todo.swap_range_down(start_index, end_index) {
+			return false;
+		}
+		if let Some(visual_index_start) = self.visual_index_start {
+			self.visual_index_start = Some(visual_index_start + 1);
+		}
+		self.move_cursor_down(rebase_todo, 1);
+		true
+	}
+
+	fn swap_selected_up(&mut self, rebase_todo: &mut TodoFile) -> bool {
+		let start_index = rebase_todo.get_selected_line_index();
+		let end_index = self.visual_index_start.unwrap_or(start_index);
+
+		if !rebase_todo.swap_range_up(start_index, end_index) {
+			return false;
+		}
+		if let Some(visual_index_start) = self.visual_index_start {
+			self.visual_index_start = Some(visual_index_start - 1);
+		}
+		self.move_cursor_up(rebase_todo, 1);
+		true
+	}
+
+	// a count moves the selected lines as a single change, stopping at the end of the list
+	fn move_selected_down(&mut self, rebase_todo: &mut TodoFile, count: usize) {
+		if count == 1 {
+			let _swapped = self.swap_selected_down(rebase_todo);
+			return;
+		}
+		let selected_index = rebase_todo.get_selected_line_index();
+		let end_index = self.visual_index_start.unwrap_or(selected_index);
+		let last_index = max(selected_index, end_index);
+		let amount = min(count, rebase_todo.get_max_selected_line_index().saturating_sub(last_index));
+		if amount == 0 || !rebase_todo.move_range(selected_index, end_index, last_index + amount + 1) {
+			return;
+		}
+		self.visual_index_start = self.visual_index_start.map(|index| index + amount);
+		rebase_todo.set_selected_line_index(selected_index + amount);
+	}
+
+	fn move_selected_up(&mut self, rebase_todo: &mut TodoFile, count: usize) {
+		if count == 1 {
+			let _swapped = self.swap_selected_up(rebase_todo);
+			return;
+		}
+		let selected_index = rebase_todo.get_selected_line_index();
+		let end_index = self.visual_index_start.unwrap_or(selected_index);
+		let amount = min(count, min(selected_index, end_index));
+		if amount == 0 || !rebase_todo.move_range(selected_index, end_index, min(selected_index, end_index) - amount) {
+			return;
+		}
+		self.visual_index_start = self.visual_index_start.map(|index| index - amount);
+		rebase_todo.set_selected_line_index(selected_index - amount);
+	}
+
+	// Digits build a count for the next command, a leading zero is not a count. The key bindings are looked up first,
+	// so a digit that is bound to a key arrives as that input and only unbound digits reach here. Inputs that are not
+	// typed by the user, such as a resize, keep the count for the next command.
+	fn handle_count_input(&mut self, input: Input) -> bool {
+		match input {
+			Input::Character(c) if c.is_ascii_digit() && (c != '0' || self.count.is_some()) => {
+				let digit = c.to_digit(10).unwrap_or(0) as usize;
+				self.count = Some(self.count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+				true
+			},
+			Input::Ignore | Input::Resize => true,
+			_ => false,
+		}
+	}
+
+	// a count past the end of the list does the same as one reaching it, and keeps the index arithmetic in range
+	fn take_count(&mut self, rebase_todo: &TodoFile) -> usize {
+		min(self.count.take().unwrap_or(1), rebase_todo.get_max_selected_line_index() + 1)
+	}
+
+	// the rows map to the lines of the last built view, so a click that arrives after the lines or the filter changed,
+	// and before the view was built again, is ignored
+	fn is_view_current(&self, todo_file: &TodoFile) -> bool {
+		self.rendered_filtered == self.filter.is_some()
+			&& todo_file.iter().map(Line::to_text).eq(self.rendered_lines.iter().cloned())
+			&& self
+				.get_visible_indexes(todo_file)
+				.into_iter()
+				.eq(self.view_line_indexes.iter().filter_map(|index| *index))
+	}
+
+	fn get_todo_index_at_row(&self, row: usize) -> Option<usize> {
+		self.view_data
+			.get_line_index_at_row(row)
+			.and_then(|line_index| self.view_line_indexes.get(line_index).copied().flatten())
+	}
+
+	// A click selects a line, and a shift click extends the selection to it. Dragging the selected lines moves them
+	// when they are released, as a single change, and dragging the scroll bar moves through the list.
+	fn handle_mouse_input(&mut self, mouse: Mouse, rebase_todo: &mut TodoFile) -> Result<()> {
+		if !self.is_view_current(rebase_todo) {
+			self.mouse_drag = None;
+			return Ok(());
+		}
+		match (mouse.get_action(), self.mouse_drag) {
+			(MouseAction::Press, _) if self.view_data.is_scroll_bar_column(mouse.get_column()) => {
+				self.mouse_drag = Some(MouseDrag::ScrollBar);
+				self.select_scroll_bar_line(rebase_todo, mouse.get_row());
+			},
+			(MouseAction::Press, _) => {
+				if let Some(index) = self.get_todo_index_at_row(mouse.get_row()) {
+					let selected_index = rebase_todo.get_selected_line_index();
+					let visual_index = self.visual_index_start.unwrap_or(selected_index);
+					if mouse.is_shift() {
+						if self.visual_index_start.is_none() {
+							self.state = ListState::Visual;
+							self.visual_index_start = Some(selected_index);
+						}
+						rebase_todo.set_selected_line_index(index);
+					}
+					// a click on the selected lines keeps them selected, so they can be dragged
+					else if index < min(selected_index, visual_index) || index > max(selected_index, visual_index) {
+						self.state = ListState::Normal;
+						self.visual_index_start = None;
+						rebase_todo.set_selected_line_index(index);
+					}
+					self.mouse_drag = Some(MouseDrag::Lines(index, index));
+				}
+			},
+			(MouseAction::Drag, Some(MouseDrag::ScrollBar)) => {
+				self.select_scroll_bar_line(rebase_todo, mouse.get_row());
+			},
+			(MouseAction::Drag, Some(MouseDrag::Lines(start_index, _))) => {
+				if let Some(index) = self.get_todo_index_at_row(mouse.get_row()) {
+					if index != start_index && self.filter.is_some() {
+						self.mouse_drag = None;
+						return Err(anyhow!("Lines can not be moved while the list is filtered"));
+					}
+					self.mouse_drag = Some(MouseDrag::Lines(start_index, index));
+				}
+			},
+			(MouseAction::Drag, None) | (MouseAction::ScrollDown, _) | (MouseAction::ScrollUp, _) => {},
+			(MouseAction::Release, Some(MouseDrag::Lines(start_index, index))) => {
+				self.mouse_drag = None;
+				if index > start_index {
+					self.move_selected_down(rebase_todo, index - start_index);
+				}
+				else if index < start_index {
+					self.move_selected_up(rebase_todo, start_index - index);
+				}
+			},
+			(MouseAction::Release, _) => self.mouse_drag = None,
+		}
+		Ok(())
+	}
+
+	// the done lines have no todo line to select, so the first todo line after them is used
+	fn select_scroll_bar_line(&mut self, rebase_todo: &mut TodoFile, row: usize) {
+		if let Some(line_index) = self.view_data.get_scroll_bar_line_index(row) {
+			if let Some(index) = self.view_line_indexes.iter().skip(line_index).find_map(|index| *index) {
+				self.state = ListState::Normal;
+				self.visual_index_start = None;
+				rebase_todo.set_selected_line_index(index);
+			}
+		}
+	}
+
+	fn take_register(&mut self) -> char {
+		replace(&mut self.register, DEFAULT_REGISTER)
+	}
+
+	fn handle_select_register_input(&mut self, view: &mut View<'_>) -> ProcessResult {
+		let input = view.get_input(InputMode::Raw);
+		self.selecting_register = false;
+		if let Input::Character(register) = input {
+			if register.is_ascii_alphanumeric() || register == DEFAULT_REGISTER {
+				self.register = register;
+			}
+		}
+		ProcessResult::new().input(input)
+	}
+
+	// lines that were cut are moved by a paste, while copied lines are added again
+	fn paste(&mut self, rebase_todo: &mut TodoFile, after: bool) -> Result<()> {
+		let register = self.take_register();
+		let index = if after && !rebase_todo.is_empty() {
+			rebase_todo.get_selected_line_index() + 1
+		}
+		else {
+			rebase_todo.get_selected_line_index()
+		};
+		let content = self
+			.registers
+			.get_mut(&register)
+			.ok_or_else(|| anyhow!("Nothing in register {}", register))?;
+		if content.is_cut() {
+			if self.filter.is_some() {
+				return Err(anyhow!("Lines can not be moved while the list is filtered"));
+			}
+			let (start_index, end_index) = content
+				.get_cut_range(rebase_todo)
+				.ok_or_else(|| anyhow!("The cut lines have changed, cut them again to move them"))?;
+			content.clear_cut();
+			if rebase_todo.move_range(start_index, end_index, index) {
+				rebase_todo.set_selected_line_index(if index > end_index {
+					index - (end_index - start_index + 1)
+				}
+				else {
+					index
+				});
+			}
+		}
+		else {
+			rebase_todo.add_lines(index, content.get_lines());
+			rebase_todo.set_selected_line_index(index);
+		}
+		Ok(())
+	}
+
+	fn handle_filter_input(&mut self, view: &mut View<'_>) -> ProcessResult {
+		let input = view.get_input(InputMode::Default);
+		if let Some(choice) = self.filter_choice.handle_input(input).copied() {
+			if let Some(filter_kind) = choice {
+				self.state = ListState::FilterValue;
+				self.filter_kind = filter_kind;
+				self.filter_edit.clear();
+				self.filter_edit.set_description(filter_kind.get_prompt());
+			}
+			else {
+				self.state = ListState::Normal;
+			}
+		}
+		ProcessResult::new().input(input)
+	}
+
+	fn handle_filter_value_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Raw);
+		let mut result = ProcessResult::new().input(input);
+		match input {
+			Input::Escape => self.state = ListState::Normal,
+			Input::Enter => {
+				self.state = ListState::Normal;
+				let value = self.filter_edit.get_content();
+				if !value.trim().is_empty() {
+					match Filter::new(self.filter_kind, value.trim()) {
+						Ok(filter) => {
+							if rebase_todo.iter().any(|line| filter.matches(line, &self.commit_details)) {
+								self.filter = Some(filter);
+							}
+							else {
+								result = result.error(anyhow!("No lines match the filter"));
+							}
+						},
+						Err(err) => result = result.error(err),
+					}
+				}
+			},
+			_ => {
+				let _handled = self.filter_edit.handle_input(input);
+			},
+		}
+		result
+	}
+
 	fn handle_edit_mode_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
 		let input = view.get_input(InputMode::Raw);
 		if !self.edit.handle_input(input) && input == Input::Enter {
@@ -365,4 +1662,43 @@ impl<'l> List<'l> {
 		}
 		ProcessResult::new().input(input)
 	}
+
+	fn handle_edit_merge_mode_input(&mut self, view: &mut View<'_>, rebase_todo: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Raw);
+		if !self.edit_form.handle_input(input) && input == Input::Enter {
+			// git needs a label to merge, so the form stays open until one is given
+			if self.edit_form.get_values()[2].trim().is_empty() {
+				return ProcessResult::new()
+					.input(input)
+					.error(anyhow!("A merge needs at least one label"));
+			}
+			let selected_index = rebase_todo.get_selected_line_index();
+			rebase_todo.update_range(
+				selected_index,
+				selected_index,
+				&EditContext::new().content(Self::build_merge_content(&self.edit_form.get_values()).as_str()),
+			);
+			self.visual_index_start = None;
+			self.state = ListState::Normal;
+		}
+		ProcessResult::new().input(input)
+	}
+
+	fn build_merge_content(values: &[String]) -> String {
+		let mut parts = vec![];
+		let commit = values[1].trim();
+		if !commit.is_empty() {
+			// without a valid option, default to reusing the original message
+			let option = FixupMessage::try_from(values[0].trim()).unwrap_or(FixupMessage::Use);
+			parts.push(option.as_string());
+			parts.push(String::from(commit));
+		}
+		parts.push(String::from(values[2].trim()));
+		let comment = values[3].trim();
+		if !comment.is_empty() {
+			parts.push(String::from("#"));
+			parts.push(String::from(comment));
+		}
+		parts.join(" ")
+	}
 }
@@ -0,0 +1,97 @@
+use crate::todo_file::{line::Line, TodoFile};
+
+// the register used when no other register was chosen
+pub(super) const DEFAULT_REGISTER: char = '"';
+
+#[derive(Debug)]
+pub(super) struct Register {
+	lines: Vec<Line>,
+	// the index of the first cut line, so a paste can move the lines instead of copying them
+	cut_index: Option<usize>,
+}
+
+impl Register {
+	pub(super) fn new(lines: Vec<Line>) -> Self {
+		Self { lines, cut_index: None }
+	}
+
+	pub(super) fn new_cut(lines: Vec<Line>, index: usize) -> Self {
+		Self {
+			lines,
+			cut_index: Some(index),
+		}
+	}
+
+	pub(super) fn get_lines(&self) -> &[Line] {
+		&self.lines
+	}
+
+	// the cut lines can only be moved while they are unchanged and still where they were cut from
+	pub(super) fn get_cut_range(&self, todo_file: &TodoFile) -> Option<(usize, usize)> {
+		let index = self.cut_index?;
+		let end_index = index + self.lines.len() - 1;
+		if (index..=end_index)
+			.map(|i| todo_file.get_line(i))
+			.eq(self.lines.iter().map(Some))
+		{
+			Some((index, end_index))
+		}
+		else {
+			None
+		}
+	}
+
+	pub(super) const fn is_cut(&self) -> bool {
+		self.cut_index.is_some()
+	}
+
+	pub(super) fn clear_cut(&mut self) {
+		self.cut_index = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::todo_file::{action::Action, edit_content::EditContext};
+
+	fn create_todo_file(lines: &[&str]) -> TodoFile {
+		let mut todo_file = TodoFile::new("", 10, "#");
+		todo_file.set_lines(lines.iter().map(|line| Line::new(line).unwrap()).collect());
+		todo_file
+	}
+
+	#[test]
+	fn get_cut_range() {
+		let todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let register = Register::new_cut(
+			vec![Line::new("pick bbb c2").unwrap(), Line::new("pick ccc c3").unwrap()],
+			1,
+		);
+		assert_eq!(register.get_cut_range(&todo_file), Some((1, 2)));
+	}
+
+	#[test]
+	fn get_cut_range_changed_line() {
+		let mut todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let register = Register::new_cut(vec![Line::new("pick bbb c2").unwrap()], 1);
+		todo_file.update_range(1, 1, &EditContext::new().action(Action::Drop));
+		assert!(register.get_cut_range(&todo_file).is_none());
+	}
+
+	#[test]
+	fn get_cut_range_moved_line() {
+		let mut todo_file = create_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		let register = Register::new_cut(vec![Line::new("pick bbb c2").unwrap()], 1);
+		todo_file.remove_lines(0, 0);
+		assert!(register.get_cut_range(&todo_file).is_none());
+	}
+
+	#[test]
+	fn get_cut_range_without_cut() {
+		let todo_file = create_todo_file(&["pick aaa c1"]);
+		let register = Register::new(vec![Line::new("pick aaa c1").unwrap()]);
+		assert!(!register.is_cut());
+		assert!(register.get_cut_range(&todo_file).is_none());
+	}
+}
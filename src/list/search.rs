@@ -0,0 +1,99 @@
+use std::{collections::HashMap, ops::Range};
+
+use crate::{
+	list::commit_details::CommitDetails,
+	todo_file::line::Line,
+};
+
+// matching ignores ASCII case, which keeps the byte ranges valid for the original text
+pub(super) fn get_match_ranges(text: &str, term: &str) -> Vec<Range<usize>> {
+	let mut ranges = vec![];
+	if term.is_empty() {
+		return ranges;
+	}
+	let text = text.to_ascii_lowercase();
+	let term = term.to_ascii_lowercase();
+	let mut start = 0;
+	while let Some(offset) = text[start..].find(term.as_str()) {
+		ranges.push(start + offset..start + offset + term.len());
+		start += offset + term.len();
+	}
+	ranges
+}
+
+pub(super) fn line_matches(line: &Line, term: &str, commit_details: &HashMap<String, CommitDetails>) -> bool {
+	if term.is_empty() {
+		return false;
+	}
+	let contains = |text: &str| !get_match_ranges(text, term).is_empty();
+	(line.has_reference() && contains(line.get_hash()))
+		|| contains(line.get_content())
+		|| commit_details.get(line.get_hash()).map_or(false, |details| {
+			contains(details.get_author()) || details.get_paths().iter().any(|path| contains(path))
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use rstest::rstest;
+	use serial_test::serial;
+
+	use super::*;
+	use crate::list::commit_details::load_commit_details;
+
+	#[rstest(
+		text,
+		term,
+		expected,
+		case::empty_term("abc", "", vec![]),
+		case::no_match("abc", "d", vec![]),
+		case::single("abc", "b", vec![1..2]),
+		case::multiple("abcabc", "bc", vec![1..3, 4..6]),
+		case::ignores_case("Fix Bug", "bug", vec![4..7]),
+		case::non_overlapping("aaaa", "aa", vec![0..2, 2..4])
+	)]
+	fn get_match_ranges(text: &str, term: &str, expected: Vec<Range<usize>>) {
+		assert_eq!(super::get_match_ranges(text, term), expected);
+	}
+
+	#[rstest(
+		line,
+		term,
+		expected,
+		case::hash("pick aaabbb c1", "abb", true),
+		case::content("pick aaa Fix the bug", "BUG", true),
+		case::exec("exec cargo test", "cargo", true),
+		case::label_without_hash("label feature", "feature", true),
+		case::no_match("pick aaa c1", "c2", false),
+		case::empty_term("pick aaa c1", "", false)
+	)]
+	fn line_matches_line(line: &str, term: &str, expected: bool) {
+		assert_eq!(
+			line_matches(&Line::new(line).unwrap(), term, &HashMap::new()),
+			expected
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn line_matches_author_and_paths() {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		let line = Line::new("pick 1cc0456637cb220155e957c641f483e60724c581 modified").unwrap();
+		let mut commit_details = HashMap::new();
+		load_commit_details(&mut commit_details, &[&line]);
+		assert!(line_matches(&line, "tim oram", &commit_details));
+		assert!(line_matches(&line, "dev@mitmaro", &commit_details));
+		assert!(line_matches(&line, "a", &commit_details));
+		assert!(!line_matches(&line, "not a path", &commit_details));
+	}
+}
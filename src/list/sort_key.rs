@@ -0,0 +1,142 @@
+use anyhow::{anyhow, Result};
+use git2::{Error, Repository};
+
+use crate::todo_file::{action::Action, line::Line};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum SortKey {
+	AuthorDate,
+	AuthorName,
+	CommitterDate,
+	Path,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum SortValue {
+	Text(String),
+	Time(i64),
+}
+
+fn load_sort_value(repo: &Repository, hash: &str, sort_key: SortKey) -> Result<SortValue, Error> {
+	let commit = repo.find_commit(repo.revparse_single(hash)?.id())?;
+	Ok(match sort_key {
+		SortKey::AuthorDate => SortValue::Time(commit.author().when().seconds()),
+		SortKey::AuthorName => SortValue::Text(String::from(commit.author().name().unwrap_or(""))),
+		SortKey::CommitterDate => SortValue::Time(commit.committer().when().seconds()),
+		SortKey::Path => {
+			// some commits do not have parents, so everything in the tree was touched
+			let parent_tree = if commit.parent_count() == 0 {
+				None
+			}
+			else {
+				Some(commit.parent(0)?.tree()?)
+			};
+			let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+			let path = diff
+				.deltas()
+				.next()
+				.and_then(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+				.map_or_else(String::new, |path| path.to_string_lossy().to_string());
+			SortValue::Text(path)
+		},
+	})
+}
+
+// lines that are not commits, such as exec or break, and fixup or squash commits have no value, so they stay with the
+// commit above them
+pub(super) fn load_sort_values(lines: &[Line], sort_key: SortKey) -> Result<Vec<Option<SortValue>>> {
+	let repo = Repository::open_from_env().map_err(|err| anyhow!(err).context("Error opening repository"))?;
+	lines
+		.iter()
+		.map(|line| {
+			match *line.get_action() {
+				Action::Drop | Action::Edit | Action::Pick | Action::Reword => {
+					load_sort_value(&repo, line.get_hash(), sort_key)
+						.map(Some)
+						.map_err(|err| anyhow!(err).context(anyhow!("Error loading commit: {}", line.get_hash())))
+				},
+				_ => Ok(None),
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use rstest::rstest;
+	use serial_test::serial;
+
+	use super::*;
+
+	fn set_git_dir() {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+	}
+
+	#[rstest(
+		sort_key,
+		expected,
+		case::author_date(SortKey::AuthorDate, SortValue::Time(1_580_170_536)),
+		case::author_name(SortKey::AuthorName, SortValue::Text(String::from("Tim Oram"))),
+		case::committer_date(SortKey::CommitterDate, SortValue::Time(1_580_172_067))
+	)]
+	#[serial]
+	fn load_sort_values_commit(sort_key: SortKey, expected: SortValue) {
+		set_git_dir();
+		let lines = vec![Line::new("pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 c1").unwrap()];
+		assert_eq!(load_sort_values(&lines, sort_key).unwrap(), vec![Some(expected)]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_sort_values_path() {
+		set_git_dir();
+		let lines = vec![
+			Line::new("pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 moved").unwrap(),
+			Line::new("pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted").unwrap(),
+		];
+		assert_eq!(load_sort_values(&lines, SortKey::Path).unwrap(), vec![
+			Some(SortValue::Text(String::from("c"))),
+			Some(SortValue::Text(String::from("b")))
+		]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_sort_values_without_commit() {
+		set_git_dir();
+		let lines = vec![Line::new("exec make").unwrap(), Line::new_break()];
+		assert_eq!(load_sort_values(&lines, SortKey::AuthorDate).unwrap(), vec![None, None]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_sort_values_fixup_and_squash() {
+		set_git_dir();
+		let lines = vec![
+			Line::new("fixup 18d82dcc4c36cade807d7cf79700b6bbad8080b9 c1").unwrap(),
+			Line::new("squash 7f5eac44012ea33e5bdec0df72125c1bc2b2691d c2").unwrap(),
+		];
+		assert_eq!(load_sort_values(&lines, SortKey::AuthorDate).unwrap(), vec![None, None]);
+	}
+
+	#[test]
+	#[serial]
+	fn load_sort_values_invalid_commit() {
+		set_git_dir();
+		let lines = vec![Line::new("pick 0123456789 c1").unwrap()];
+		assert_eq!(
+			format!("{:#}", load_sort_values(&lines, SortKey::AuthorDate).unwrap_err()),
+			"Error loading commit: 0123456789: revspec '0123456789' not found; class=Reference (4); code=NotFound (-3)"
+		);
+	}
+}
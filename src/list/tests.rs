@@ -1,3 +1,5 @@
+use rstest::rstest;
+
 use super::*;
 use crate::{
 	assert_process_result,
@@ -60,7 +62,7 @@ fn render_full() {
 				"{Normal}   {ActionEdit}edit   {Normal}11111111 {Normal}comment 7",
 				"{Normal}   {ActionLabel}label  {Normal}ref",
 				"{Normal}   {ActionReset}reset  {Normal}ref",
-				"{Normal}   {ActionMerge}merge  {Normal}command"
+				"{WarningColor}!  {ActionMerge}merge  {Normal}command"
 			);
 		},
 	);
@@ -107,7 +109,7 @@ fn render_compact() {
 				"{Normal} {ActionEdit}e {Normal}111 {Normal}comment 7",
 				"{Normal} {ActionLabel}l {Normal}ref",
 				"{Normal} {ActionReset}t {Normal}ref",
-				"{Normal} {ActionMerge}m {Normal}command"
+				"{WarningColor}!{ActionMerge}m {Normal}command"
 			);
 		},
 	);
@@ -115,31 +117,31 @@ fn render_compact() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_1() {
+fn render_kept_lines() {
 	process_module_test(
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			"pick aaaaaaaa comment 1",
+			"",
+			"# a comment",
+			"frobnicate bbbbbbbb",
+			"pick cccccccc comment 2",
+			"# the help",
 		],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
-		},
-		&[Input::MoveCursorDown],
-		|mut test_context: TestContext<'_>| {
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa \
+				 {Normal(selected)}comment 1",
+				"{Normal}   ",
+				"{Normal}   {Normal,Dimmed}# a comment",
+				"{Normal}   {Normal,Dimmed}frobnicate bbbbbbbb",
+				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}comment 2"
 			);
 		},
 	);
@@ -147,31 +149,44 @@ fn move_cursor_down_1() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_view_end() {
+fn render_kept_lines_compact() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		&["# a comment", "pick aaa c1"],
 		ViewState {
-			size: Size::new(120, 4),
+			size: Size::new(30, 100),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorDown; 2],
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal} {Normal,Dimmed}# a comment",
+				"{Normal(selected)}>{ActionPick(selected)}p {Normal(selected)}aaa {Normal(selected)}c1"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_kept_lines_filtered() {
+	process_module_test(
+		&["# a comment", "pick aaa c1"],
+		ViewState::default(),
+		&filter_inputs('1', "pick"),
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
-				"{TITLE}{HELP}",
+				"{TITLE}{STATUS:Filtered by action: pick}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c3"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
 			);
 		},
 	);
@@ -179,20 +194,41 @@ fn move_cursor_down_view_end() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_scroll_1() {
+fn kept_line_is_read_only() {
 	process_module_test(
+		&["pick aaa c1", "# a comment", "pick bbb c2"],
+		ViewState::default(),
 		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
+			Input::MoveCursorDown,
+			Input::MoveCursorUp,
+			mouse(MouseAction::Press, 5, 2),
+			mouse(MouseAction::Release, 5, 2),
+			Input::ActionDrop,
 		],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"drop aaa c1"
+			);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(1).unwrap().get_kept_lines(),
+				&[String::from("# a comment")]
+			);
 		},
-		&[Input::MoveCursorDown; 3],
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn kept_line_moves_with_line() {
+	process_module_test(
+		&["pick aaa c1", "# a comment", "pick bbb c2"],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::SwapSelectedUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -201,9 +237,9 @@ fn move_cursor_down_scroll_1() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
+				"{Normal}   {Normal,Dimmed}# a comment",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1"
 			);
 		},
 	);
@@ -211,20 +247,33 @@ fn move_cursor_down_scroll_1() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_scroll_bottom() {
+fn render_update_ref() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
+		&["pick aaaaaaaa comment 1", "update-ref refs/heads/feature"],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa \
+				 {Normal(selected)}comment 1",
+				"{Normal}   {ActionUpdateRef}update-ref {Normal}refs/heads/feature"
+			);
 		},
-		&[Input::MoveCursorDown; 4],
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_problem_markers() {
+	process_module_test(
+		&["pick aaaa c1", "pick bbbb c2", "pick aaaa c1", "exec  "],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -233,9 +282,11 @@ fn move_cursor_down_scroll_bottom() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c5"
+				"{Normal}   {ActionPick}pick   {Normal}aaaa     {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbbb     {Normal}c2",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaaa     \
+				 {Normal(selected)}c1",
+				"{WarningColor}!  {ActionExec}exec   {Normal} "
 			);
 		},
 	);
@@ -243,33 +294,24 @@ fn move_cursor_down_scroll_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_scroll_bottom_move_up_one() {
+fn render_problem_markers_compact() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&["fixup aaaa c1", "pick bbbb c2"],
 		ViewState {
-			size: Size::new(120, 4),
+			size: Size::new(30, 100),
 			..ViewState::default()
 		},
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorUp,
-		],
-		|mut test_context: TestContext<'_>| {
+		&[],
+		|test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 4);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				"{Normal(selected)}>{ActionFixup(selected)}f{WarningColor(selected)}!{Normal(selected)}aaa \
+				 {Normal(selected)}c1",
+				"{Normal} {ActionPick}p {Normal}bbb {Normal}c2"
 			);
 		},
 	);
@@ -277,35 +319,32 @@ fn move_cursor_down_scroll_bottom_move_up_one() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_scroll_bottom_move_up_top() {
+fn render_merge() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
-		},
 		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
+			"label feature",
+			"label one",
+			"merge -C aaaaaaaaaa feature # Merge branch 'feature'",
+			"merge -c bbbbbbbbbb one feature",
+			"merge one",
 		],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 4);
-			test_context.build_view_data(&mut module);
-			test_context.handle_n_inputs(&mut module, 3);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal}   {ActionLabel}label  {Normal}feature",
+				"{Normal}   {ActionLabel}label  {Normal}one",
+				"{Normal(selected)} > {ActionMerge(selected)}merge  {ActionMerge(selected)}-C \
+				 {Normal(selected)}aaaaaaaa {Normal(selected)}feature{Normal(selected),Dimmed} # Merge branch \
+				 'feature'",
+				"{Normal}   {ActionMerge}merge  {ActionMerge}-c {Normal}bbbbbbbb {Normal}one feature",
+				"{Normal}   {ActionMerge}merge  {Normal}one"
 			);
 		},
 	);
@@ -313,14 +352,14 @@ fn move_cursor_down_scroll_bottom_move_up_top() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_up_attempt_above_top() {
+fn render_merge_compact() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&["label feature", "merge -C aaaaaaaaaa feature # Merge branch 'feature'"],
 		ViewState {
-			size: Size::new(120, 4),
+			size: Size::new(30, 100),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorUp, Input::MoveCursorUp],
+		&[Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -329,35 +368,72 @@ fn move_cursor_up_attempt_above_top() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal} {ActionLabel}l {Normal}feature",
+				"{Normal(selected)}>{ActionMerge(selected)}m {ActionMerge(selected)}-C {Normal(selected)}aaa \
+				 {Normal(selected)}feature{Normal(selected),Dimmed} # Merge bran"
 			);
 		},
 	);
 }
 
+const GRAPH_TODO: &[&str] = &[
+	"label onto",
+	"reset onto",
+	"pick aaa c1",
+	"label feature",
+	"reset onto",
+	"pick bbb c2",
+	"merge -C ccc feature # Merge branch 'feature'",
+];
+
 #[test]
 #[serial_test::serial]
-fn move_cursor_down_attempt_below_bottom() {
+fn render_graph() {
+	process_module_test(GRAPH_TODO, ViewState::default(), &[], |test_context: TestContext<'_>| {
+		let mut module = List::new(test_context.config);
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Normal(selected)} > {IndicatorColor(selected)}|   {ActionLabel(selected)}label  \
+			 {Normal(selected)}onto",
+			"{Normal}   {IndicatorColor}| | {ActionReset}reset  {Normal}onto",
+			"{Normal}   {IndicatorColor}| * {ActionPick}pick   {Normal}aaa      {Normal}c1",
+			"{Normal}   {IndicatorColor}| | {ActionLabel}label  {Normal}feature",
+			"{Normal}   {IndicatorColor}|   {ActionReset}reset  {Normal}onto",
+			"{Normal}   {IndicatorColor}*   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+			"{Normal}   {IndicatorColor}*   {ActionMerge}merge  {ActionMerge}-C {Normal}ccc      \
+			 {Normal}feature{Normal,Dimmed} # Merge branch 'feature'"
+		);
+	});
+}
+
+#[test]
+#[serial_test::serial]
+fn render_graph_compact() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		GRAPH_TODO,
 		ViewState {
-			size: Size::new(120, 4),
+			size: Size::new(30, 100),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorDown; 4],
-		|mut test_context: TestContext<'_>| {
+		&[],
+		|test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
+				"{Normal(selected)}>{IndicatorColor(selected)}|  {ActionLabel(selected)}l {Normal(selected)}onto",
+				"{Normal} {IndicatorColor}|| {ActionReset}t {Normal}onto",
+				"{Normal} {IndicatorColor}|* {ActionPick}p {Normal}aaa {Normal}c1",
+				"{Normal} {IndicatorColor}|| {ActionLabel}l {Normal}feature",
+				"{Normal} {IndicatorColor}|  {ActionReset}t {Normal}onto",
+				"{Normal} {IndicatorColor}*  {ActionPick}p {Normal}bbb {Normal}c2",
+				"{Normal} {IndicatorColor}*  {ActionMerge}m {ActionMerge}-C {Normal}ccc {Normal}feature{Normal,Dimmed} \
+				 # Merge b"
 			);
 		},
 	);
@@ -365,25 +441,27 @@ fn move_cursor_down_attempt_below_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_up_from_top() {
+fn toggle_graph() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
-		},
-		&[Input::MoveCursorPageUp],
+		GRAPH_TODO,
+		ViewState::default(),
+		&[Input::ToggleGraph],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ToggleGraph);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal(selected)} > {ActionLabel(selected)}label  {Normal(selected)}onto",
+				"{Normal}   {ActionReset}reset  {Normal}onto",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionLabel}label  {Normal}feature",
+				"{Normal}   {ActionReset}reset  {Normal}onto",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionMerge}merge  {ActionMerge}-C {Normal}ccc      \
+				 {Normal}feature{Normal,Dimmed} # Merge branch 'feature'"
 			);
 		},
 	);
@@ -391,7 +469,37 @@ fn move_cursor_page_up_from_top() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_up_from_one_page_down() {
+fn visual_mode_swap_commit_with_update_ref() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"update-ref refs/heads/feature",
+			"pick bbb c2",
+		],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::SwapSelectedDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_lines_owned(),
+				vec![
+					Line::new("pick bbb c2").unwrap(),
+					Line::new("pick aaa c1").unwrap(),
+					Line::new("update-ref refs/heads/feature").unwrap(),
+				]
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn move_cursor_down_1() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -399,26 +507,24 @@ fn move_cursor_page_up_from_one_page_down() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
-			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::MoveCursorPageUp],
+		&[Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 2);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -426,7 +532,7 @@ fn move_cursor_page_up_from_one_page_down() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_up_from_one_page_down_plus_1() {
+fn move_cursor_down_view_end() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -434,31 +540,24 @@ fn move_cursor_page_up_from_one_page_down_plus_1() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
-			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorPageUp,
-		],
+		&[Input::MoveCursorDown; 2],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 3);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c3"
 			);
 		},
 	);
@@ -466,7 +565,7 @@ fn move_cursor_page_up_from_one_page_down_plus_1() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_up_from_one_page_down_minus_1() {
+fn move_cursor_down_scroll_1() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -474,26 +573,24 @@ fn move_cursor_page_up_from_one_page_down_minus_1() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
-			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::MoveCursorPageUp],
+		&[Input::MoveCursorDown; 3],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 2);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c4"
 			);
 		},
 	);
@@ -501,7 +598,7 @@ fn move_cursor_page_up_from_one_page_down_minus_1() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_up_from_bottom() {
+fn move_cursor_down_scroll_bottom() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -509,33 +606,24 @@ fn move_cursor_page_up_from_bottom() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
-			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorPageUp,
-		],
+		&[Input::MoveCursorDown; 4],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 5);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c6"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c5"
 			);
 		},
 	);
@@ -543,45 +631,23 @@ fn move_cursor_page_up_from_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_home() {
+fn move_cursor_down_scroll_bottom_move_up_one() {
 	process_module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		ViewState::default(),
-		&[Input::MoveCursorEnd],
-		|mut test_context: TestContext<'_>| {
-			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 5);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
-			);
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
 		},
-	);
-}
-
-#[test]
-#[serial_test::serial]
-fn move_cursor_page_end() {
-	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		ViewState::default(),
 		&[
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
-			Input::MoveCursorHome,
+			Input::MoveCursorDown,
+			Input::MoveCursorUp,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 5);
+			test_context.handle_n_inputs(&mut module, 4);
 			test_context.build_view_data(&mut module);
 			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
@@ -589,10 +655,10 @@ fn move_cursor_page_end() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c3",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
@@ -600,16 +666,9 @@ fn move_cursor_page_end() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_down_from_bottom() {
+fn move_cursor_down_scroll_bottom_move_up_top() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-			"pick aaa c6",
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
@@ -619,22 +678,23 @@ fn move_cursor_page_down_from_bottom() {
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorPageDown,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 5);
+			test_context.handle_n_inputs(&mut module, 4);
 			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_n_inputs(&mut module, 3);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c6"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -642,40 +702,25 @@ fn move_cursor_page_down_from_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_down_one_from_bottom() {
+fn move_cursor_up_attempt_above_top() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-			"pick aaa c6",
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorPageDown,
-		],
+		&[Input::MoveCursorUp, Input::MoveCursorUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 4);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c6"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -683,39 +728,26 @@ fn move_cursor_page_down_one_from_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn move_cursor_page_down_one_page_from_bottom() {
+fn move_cursor_down_attempt_below_bottom() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-			"pick aaa c6",
-		],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorPageDown,
-		],
+		&[Input::MoveCursorDown; 4],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 3);
-			test_context.build_view_data(&mut module);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c6"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c4"
 			);
 		},
 	);
@@ -723,14 +755,14 @@ fn move_cursor_page_down_one_page_from_bottom() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start() {
+fn move_cursor_page_up_from_top() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ToggleVisualMode],
+		&[Input::MoveCursorPageUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -740,8 +772,8 @@ fn visual_mode_start() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -749,26 +781,34 @@ fn visual_mode_start() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_cursor_down_one() {
+fn move_cursor_page_up_from_one_page_down() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+			"pick aaa c6",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ToggleVisualMode, Input::MoveCursorDown],
+		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::MoveCursorPageUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 2);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -776,7 +816,7 @@ fn visual_mode_start_cursor_down_one() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_move_down_below_view() {
+fn move_cursor_page_up_from_one_page_down_plus_1() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -784,30 +824,32 @@ fn visual_mode_start_move_down_below_view() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
+			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
 		&[
-			Input::ToggleVisualMode,
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
+			Input::MoveCursorPageUp,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 3);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
 				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
@@ -815,7 +857,7 @@ fn visual_mode_start_move_down_below_view() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_cursor_page_down() {
+fn move_cursor_page_up_from_one_page_down_minus_1() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -823,25 +865,26 @@ fn visual_mode_start_cursor_page_down() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
+			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ToggleVisualMode, Input::MoveCursorPageDown],
+		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::MoveCursorPageUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 2);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c3"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3"
 			);
 		},
 	);
@@ -849,7 +892,7 @@ fn visual_mode_start_cursor_page_down() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_cursor_page_down_below_view() {
+fn move_cursor_page_up_from_bottom() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -857,29 +900,34 @@ fn visual_mode_start_cursor_page_down_below_view() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
+			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
 		&[
-			Input::ToggleVisualMode,
-			Input::MoveCursorPageDown,
-			Input::MoveCursorPageDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorPageUp,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 5);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
 				 {Normal(selected)}c4",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c5"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c6"
 			);
 		},
 	);
@@ -887,38 +935,57 @@ fn visual_mode_start_cursor_page_down_below_view() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_cursor_from_bottom_move_up() {
+fn move_cursor_page_home() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
-		ViewState {
-			size: Size::new(120, 4),
-			..ViewState::default()
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		ViewState::default(),
+		&[Input::MoveCursorEnd],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 5);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c4"
+			);
 		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn move_cursor_page_end() {
+	process_module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		ViewState::default(),
 		&[
-			Input::MoveCursorPageDown,
-			Input::MoveCursorPageDown,
-			Input::MoveCursorPageDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorHome,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 5);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c5"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c2",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c3",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4"
 			);
 		},
 	);
@@ -926,7 +993,7 @@ fn visual_mode_start_cursor_from_bottom_move_up() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_start_cursor_from_bottom_to_top() {
+fn move_cursor_page_down_from_bottom() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -934,33 +1001,34 @@ fn visual_mode_start_cursor_from_bottom_to_top() {
 			"pick aaa c3",
 			"pick aaa c4",
 			"pick aaa c5",
+			"pick aaa c6",
 		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
 		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
 			Input::MoveCursorPageDown,
-			Input::MoveCursorPageDown,
-			Input::MoveCursorPageDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorPageUp,
-			Input::MoveCursorPageUp,
-			Input::MoveCursorPageUp,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 5);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c6"
 			);
 		},
 	);
@@ -968,23 +1036,41 @@ fn visual_mode_start_cursor_from_bottom_to_top() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_drop() {
+fn move_cursor_page_down_one_from_bottom() {
 	process_module_test(
-		&["pick aaa c1"],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+			"pick aaa c6",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionDrop],
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorPageDown,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 4);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c6"
 			);
 		},
 	);
@@ -992,23 +1078,40 @@ fn change_selected_line_to_drop() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_edit() {
+fn move_cursor_page_down_one_page_from_bottom() {
 	process_module_test(
-		&["pick aaa c1"],
+		&[
+			"pick aaa c1",
+			"pick aaa c2",
+			"pick aaa c3",
+			"pick aaa c4",
+			"pick aaa c5",
+			"pick aaa c6",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionEdit],
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorPageDown,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
+			test_context.handle_n_inputs(&mut module, 3);
+			test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionEdit(selected)}edit   {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c4",
+				"{WarningColor}!  {ActionPick}pick   {Normal}aaa      {Normal}c5",
+				"{WarningColor(selected)}!> {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c6"
 			);
 		},
 	);
@@ -1016,14 +1119,14 @@ fn change_selected_line_to_edit() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_fixup() {
+fn visual_mode_start() {
 	process_module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionFixup],
+		&[Input::ToggleVisualMode],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1032,7 +1135,9 @@ fn change_selected_line_to_fixup() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionFixup(selected)}fixup  {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3"
 			);
 		},
 	);
@@ -1040,14 +1145,14 @@ fn change_selected_line_to_fixup() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_pick() {
+fn visual_mode_start_cursor_down_one() {
 	process_module_test(
-		&["drop aaa c1"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionPick],
+		&[Input::ToggleVisualMode, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1056,7 +1161,10 @@ fn change_selected_line_to_pick() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3"
 			);
 		},
 	);
@@ -1064,14 +1172,25 @@ fn change_selected_line_to_pick() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_reword() {
+fn visual_mode_start_move_down_below_view() {
 	process_module_test(
-		&["pick aaa c1"],
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionReword],
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1080,7 +1199,11 @@ fn change_selected_line_to_reword() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4"
 			);
 		},
 	);
@@ -1088,14 +1211,20 @@ fn change_selected_line_to_reword() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_to_squash() {
+fn visual_mode_start_cursor_page_down() {
 	process_module_test(
-		&["pick aaa c1"],
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionSquash],
+		&[Input::ToggleVisualMode, Input::MoveCursorPageDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1104,7 +1233,11 @@ fn change_selected_line_to_squash() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionSquash(selected)}squash {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ccc      {Normal(selected)}c3"
 			);
 		},
 	);
@@ -1112,14 +1245,24 @@ fn change_selected_line_to_squash() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_toggle_break_add() {
+fn visual_mode_start_cursor_page_down_below_view() {
 	process_module_test(
-		&["pick aaa c1"],
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionBreak],
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorPageDown,
+			Input::MoveCursorPageDown,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1128,8 +1271,11 @@ fn change_selected_line_toggle_break_add() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionBreak(selected)}break  "
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}c4",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}eee      {Normal(selected)}c5"
 			);
 		},
 	);
@@ -1137,14 +1283,26 @@ fn change_selected_line_toggle_break_add() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_toggle_break_remove() {
+fn visual_mode_start_cursor_from_bottom_move_up() {
 	process_module_test(
-		&["pick aaa c1", "break"],
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::MoveCursorDown, Input::ActionBreak],
+		&[
+			Input::MoveCursorPageDown,
+			Input::MoveCursorPageDown,
+			Input::MoveCursorPageDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1153,7 +1311,10 @@ fn change_selected_line_toggle_break_remove() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}eee      \
+				 {Normal(selected)}c5"
 			);
 		},
 	);
@@ -1161,14 +1322,28 @@ fn change_selected_line_toggle_break_remove() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_toggle_break_above_existing() {
+fn visual_mode_start_cursor_from_bottom_to_top() {
 	process_module_test(
-		&["pick aaa c1", "break"],
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionBreak],
+		&[
+			Input::MoveCursorPageDown,
+			Input::MoveCursorPageDown,
+			Input::MoveCursorPageDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorPageUp,
+			Input::MoveCursorPageUp,
+			Input::MoveCursorPageUp,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1178,7 +1353,10 @@ fn change_selected_line_toggle_break_above_existing() {
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionBreak}break  "
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3"
 			);
 		},
 	);
@@ -1186,26 +1364,23 @@ fn change_selected_line_toggle_break_above_existing() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_auto_select_next_with_next_line() {
+fn change_selected_line_to_drop() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2"],
+		&["pick aaa c1"],
 		ViewState {
 			size: Size::new(120, 4),
 			..ViewState::default()
 		},
-		&[Input::ActionSquash],
+		&[Input::ActionDrop],
 		|mut test_context: TestContext<'_>| {
-			let mut config = test_context.config.clone();
-			config.auto_select_next = true;
-			let mut module = List::new(&config);
+			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionSquash}squash {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2"
+				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}aaa      {Normal(selected)}c1"
 			);
 		},
 	);
@@ -1213,11 +1388,14 @@ fn change_selected_line_auto_select_next_with_next_line() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_swap_down() {
+fn change_selected_line_to_edit() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		ViewState::default(),
-		&[Input::SwapSelectedDown],
+		&["pick aaa c1"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionEdit],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1226,9 +1404,7 @@ fn change_selected_line_swap_down() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c3"
+				"{Normal(selected)} > {ActionEdit(selected)}edit   {Normal(selected)}aaa      {Normal(selected)}c1"
 			);
 		},
 	);
@@ -1236,11 +1412,14 @@ fn change_selected_line_swap_down() {
 
 #[test]
 #[serial_test::serial]
-fn change_selected_line_swap_up() {
+fn change_selected_line_to_fixup() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		ViewState::default(),
-		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::SwapSelectedUp],
+		&["pick aaa c1"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionFixup],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
@@ -1249,9 +1428,8 @@ fn change_selected_line_swap_up() {
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c3",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c2"
+				"{WarningColor(selected)}!> {ActionFixup(selected)}fixup  {Normal(selected)}aaa      \
+				 {Normal(selected)}c1"
 			);
 		},
 	);
@@ -1259,17 +1437,23 @@ fn change_selected_line_swap_up() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_show_commit_when_hash_available() {
+fn render_fixup_with_option() {
 	process_module_test(
-		&["pick aaa c1"],
+		&["pick cccccccc comment 0", "fixup -C aaaaaaaa comment 1", "fixup -c bbbbbbbb comment 2"],
 		ViewState::default(),
-		&[Input::ShowCommit],
+		&[Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::ShowCommit,
-				state = State::ShowCommit
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}comment 0",
+				"{Normal(selected)} > {ActionFixup(selected)}fixup  {ActionFixup(selected)}-C \
+				 {Normal(selected)}aaaaaaaa {Normal(selected)}comment 1",
+				"{Normal}   {ActionFixup}fixup  {ActionFixup}-c {Normal}bbbbbbbb {Normal}comment 2"
 			);
 		},
 	);
@@ -1277,82 +1461,113 @@ fn normal_mode_show_commit_when_hash_available() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_show_commit_when_no_selected_line() {
+fn cycle_fixup_message() {
 	process_module_test(
-		&[],
+		&["fixup aaa c1"],
 		ViewState::default(),
-		&[Input::ShowCommit],
+		&[
+			Input::CycleFixupMessage,
+			Input::CycleFixupMessage,
+			Input::CycleFixupMessage,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_option(), Some(FixupMessage::Use));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_option(), Some(FixupMessage::Edit));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_option(), None);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_do_not_show_commit_when_hash_not_available() {
+fn cycle_fixup_message_not_fixup() {
 	process_module_test(
-		&["exec echo foo"],
+		&["pick aaa c1"],
 		ViewState::default(),
-		&[Input::ShowCommit],
+		&[Input::CycleFixupMessage],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_option(), None);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_abort() {
+fn visual_mode_cycle_fixup_message() {
 	process_module_test(
-		&["pick aaa c1"],
+		&["fixup aaa c1", "fixup bbb c2", "pick ccc c3"],
 		ViewState::default(),
-		&[Input::Abort],
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::CycleFixupMessage,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::Abort,
-				state = State::ConfirmAbort
-			);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_option(), Some(FixupMessage::Use));
+			assert_eq!(test_context.rebase_todo_file.get_line(1).unwrap().get_option(), Some(FixupMessage::Use));
+			assert_eq!(test_context.rebase_todo_file.get_line(2).unwrap().get_option(), None);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_force_abort() {
+fn visual_mode_cycle_fixup_message_mixed_options() {
 	process_module_test(
-		&["pick aaa c1"],
+		&["fixup -C aaa c1", "fixup bbb c2", "fixup -c ccc c3"],
 		ViewState::default(),
-		&[Input::ForceAbort],
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::CycleFixupMessage,
+			Input::CycleFixupMessage,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::ForceAbort,
-				exit_status = ExitStatus::Good
-			);
-			assert!(test_context.rebase_todo_file.is_empty());
+			test_context.handle_n_inputs(&mut module, 4);
+			for index in 0..3 {
+				assert_eq!(test_context.rebase_todo_file.get_line(index).unwrap().get_option(), None);
+			}
+			test_context.handle_input(&mut module);
+			for index in 0..3 {
+				assert_eq!(
+					test_context.rebase_todo_file.get_line(index).unwrap().get_option(),
+					Some(FixupMessage::Use)
+				);
+			}
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_rebase() {
+fn change_selected_line_to_pick() {
 	process_module_test(
-		&["pick aaa c1"],
-		ViewState::default(),
-		&[Input::Rebase],
+		&["drop aaa c1"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionPick],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::Rebase,
-				state = State::ConfirmRebase
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
 			);
 		},
 	);
@@ -1360,81 +1575,122 @@ fn normal_mode_rebase() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_force_rebase() {
+fn change_selected_line_to_reword() {
 	process_module_test(
 		&["pick aaa c1"],
-		ViewState::default(),
-		&[Input::ForceRebase],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionReword],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::ForceRebase,
-				exit_status = ExitStatus::Good
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c1"
 			);
-			assert!(!test_context.rebase_todo_file.is_noop());
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_edit_with_edit_content() {
+fn change_selected_line_to_squash() {
 	process_module_test(
-		&["exec echo foo"],
-		ViewState::default(),
-		&[Input::Edit],
+		&["pick aaa c1"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionSquash],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
-			assert_eq!(module.state, ListState::Edit);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor(selected)}!> {ActionSquash(selected)}squash {Normal(selected)}aaa      \
+				 {Normal(selected)}c1"
+			);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_edit_without_edit_content() {
+fn change_selected_line_toggle_break_add() {
 	process_module_test(
 		&["pick aaa c1"],
-		ViewState::default(),
-		&[Input::Edit],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionBreak],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
-			assert_eq!(module.state, ListState::Normal);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionBreak(selected)}break  "
+			);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_edit_without_selected_line() {
+fn change_selected_line_toggle_break_remove() {
 	process_module_test(
-		&[],
-		ViewState::default(),
-		&[Input::Edit],
+		&["pick aaa c1", "break"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::MoveCursorDown, Input::ActionBreak],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
-			assert_eq!(module.state, ListState::Normal);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+			);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_insert_line() {
+fn change_selected_line_toggle_break_above_existing() {
 	process_module_test(
-		&[],
-		ViewState::default(),
-		&[Input::InsertLine],
+		&["pick aaa c1", "break"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionBreak],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::InsertLine,
-				state = State::Insert
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionBreak}break  "
 			);
 		},
 	);
@@ -1442,17 +1698,26 @@ fn normal_mode_insert_line() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_open_external_editor() {
+fn change_selected_line_auto_select_next_with_next_line() {
 	process_module_test(
-		&["pick aaa c1"],
-		ViewState::default(),
-		&[Input::OpenInEditor],
+		&["pick aaa c1", "pick bbb c2"],
+		ViewState {
+			size: Size::new(120, 4),
+			..ViewState::default()
+		},
+		&[Input::ActionSquash],
 		|mut test_context: TestContext<'_>| {
-			let mut module = List::new(test_context.config);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::OpenInEditor,
-				state = State::ExternalEditor
+			let mut config = test_context.config.clone();
+			config.auto_select_next = true;
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor}!  {ActionSquash}squash {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
 			);
 		},
 	);
@@ -1460,72 +1725,63 @@ fn normal_mode_open_external_editor() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_undo() {
+fn change_selected_line_swap_down() {
 	process_module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		ViewState::default(),
-		&[Input::ActionDrop, Input::Undo],
+		&[Input::SwapSelectedDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3"
 			);
-			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_undo_visual_mode_change() {
+fn change_selected_line_swap_up() {
 	process_module_test(
-		&["pick aaa c1", "pick bbb c2"],
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
 		ViewState::default(),
-		&[
-			Input::ToggleVisualMode,
-			Input::Down,
-			Input::ActionDrop,
-			Input::ToggleVisualMode,
-			Input::Undo,
-		],
+		&[Input::MoveCursorDown, Input::MoveCursorDown, Input::SwapSelectedUp],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ccc      {Normal(selected)}c3",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2"
 			);
-			assert_eq!(module.state, ListState::Visual);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_redo() {
+fn normal_mode_show_commit_when_hash_available() {
 	process_module_test(
-		&["drop aaa c1"],
+		&["pick aaa c1"],
 		ViewState::default(),
-		&[Input::ActionPick, Input::Undo, Input::Redo],
+		&[Input::ShowCommit],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
-			test_context.handle_input(&mut module);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Redo);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ShowCommit,
+				state = State::ShowCommit
 			);
 		},
 	);
@@ -1533,93 +1789,3269 @@ fn normal_mode_redo() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_redo_visual_mode_change() {
+fn normal_mode_show_commit_merge_with_commit() {
 	process_module_test(
-		&["drop aaa c1", "drop bbb c2"],
+		&["merge -C aaa feature # Merge branch 'feature'"],
 		ViewState::default(),
-		&[
-			Input::ToggleVisualMode,
-			Input::Down,
-			Input::ActionPick,
-			Input::Undo,
-			Input::ToggleVisualMode,
-			Input::Redo,
-		],
+		&[Input::ShowCommit],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ShowCommit,
+				state = State::ShowCommit
 			);
-			assert_eq!(module.state, ListState::Visual);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_remove_line_first() {
+fn normal_mode_show_commit_merge_without_commit() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		&["merge feature"],
 		ViewState::default(),
-		&[Input::Delete],
+		&[Input::ShowCommit],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
-				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
-				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
-			);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_remove_line_end() {
+fn normal_mode_show_commit_when_no_selected_line() {
+	process_module_test(
+		&[],
+		ViewState::default(),
+		&[Input::ShowCommit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_do_not_show_commit_when_hash_not_available() {
+	process_module_test(
+		&["exec echo foo"],
+		ViewState::default(),
+		&[Input::ShowCommit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_abort() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Abort],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Abort,
+				state = State::ConfirmAbort
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_force_abort() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ForceAbort],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ForceAbort,
+				exit_status = ExitStatus::Good
+			);
+			assert!(test_context.rebase_todo_file.is_empty());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_rebase() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Rebase],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Rebase,
+				state = State::ConfirmRebase
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_force_rebase() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ForceRebase],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ForceRebase,
+				exit_status = ExitStatus::Good
+			);
+			assert!(!test_context.rebase_todo_file.is_noop());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_edit_with_edit_content() {
+	process_module_test(
+		&["exec echo foo"],
+		ViewState::default(),
+		&[Input::Edit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
+			assert_eq!(module.state, ListState::Edit);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_edit_without_edit_content() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Edit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_edit_without_selected_line() {
+	process_module_test(
+		&[],
+		ViewState::default(),
+		&[Input::Edit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Edit);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_insert_line() {
+	process_module_test(
+		&[],
+		ViewState::default(),
+		&[Input::InsertLine],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::InsertLine,
+				state = State::Insert
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_open_external_editor() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::OpenInEditor],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::OpenInEditor,
+				state = State::ExternalEditor
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_autosquash() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1", "pick ddd amend! c2"],
+		ViewState::default(),
+		&[Input::Autosquash, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Autosquash);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "fixup ccc fixup! c1", "pick bbb c2", "fixup -C ddd amend! c2"]
+			);
+			test_context.handle_input(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1", "pick ddd amend! c2"]
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_orphaned_fixup() {
+	process_module_test(
+		&["pick aaaaaaaa c1", "pick bbbbbbbb fixup! c9"],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {WarningColor}fixup! c9"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_show_history() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ShowHistory],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ShowHistory,
+				state = State::UndoHistory
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_undo() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ActionDrop, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_undo_visual_mode_change() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::Down,
+			Input::ActionDrop,
+			Input::ToggleVisualMode,
+			Input::Undo,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_redo() {
+	process_module_test(
+		&["drop aaa c1"],
+		ViewState::default(),
+		&[Input::ActionPick, Input::Undo, Input::Redo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			test_context.handle_input(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Redo);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_redo_visual_mode_change() {
+	process_module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::Down,
+			Input::ActionPick,
+			Input::Undo,
+			Input::ToggleVisualMode,
+			Input::Redo,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_remove_line_first() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[Input::Delete],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_remove_line_end() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::Delete,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_toggle_visual_mode() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ToggleVisualMode);
+			assert_eq!(module.visual_index_start, Some(0));
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_other_input() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Other],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Other);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_top_bottom() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionReword,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}ccc      {Normal(selected)}c3"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_bottom_top() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::ActionReword,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}ccc      \
+				 {Normal(selected)}c3"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_drop() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionDrop,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionDrop(selected)}drop   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionDrop(selected)}drop   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_edit() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionEdit,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionEdit(selected)}edit   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionEdit(selected)}edit   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionEdit(selected)}edit   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_fixup() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionFixup,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionFixup(selected)}fixup  {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionFixup(selected)}fixup  {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionFixup(selected)}fixup  {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_pick() {
+	process_module_test(
+		&[
+			"drop aaa c1",
+			"drop bbb c2",
+			"drop ccc c3",
+			"drop ddd c4",
+			"drop eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionPick,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionDrop}drop   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_reword() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionReword,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_action_change_squash() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ActionSquash,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected),Dimmed} > {ActionSquash(selected)}squash {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionSquash(selected)}squash {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionSquash(selected)}squash {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_abort() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::Abort],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Abort,
+				state = State::ConfirmAbort
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_force_abort() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::ForceAbort],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ForceAbort,
+				exit_status = ExitStatus::Good
+			);
+			assert!(test_context.rebase_todo_file.is_empty());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_rebase() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::Rebase],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Rebase,
+				state = State::ConfirmRebase
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_force_rebase() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::ForceRebase],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ForceRebase,
+				exit_status = ExitStatus::Good
+			);
+			assert!(!test_context.rebase_todo_file.is_noop());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_down_from_top_to_bottom_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::SwapSelectedDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_down_from_bottom_to_top_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::SwapSelectedDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}c4"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_up_from_top_to_bottom_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::SwapSelectedUp,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_up_from_bottom_to_top_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::SwapSelectedUp,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_down_to_limit_from_bottom_to_top_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::SwapSelectedDown,
+			Input::SwapSelectedDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}c4"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_down_to_limit_from_top_to_bottom_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::SwapSelectedDown,
+			Input::SwapSelectedDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_up_to_limit_from_top_to_bottom_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::SwapSelectedUp,
+			Input::SwapSelectedUp,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_swap_up_to_limit_from_bottom_to_top_selection() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::SwapSelectedUp,
+			Input::SwapSelectedUp,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ccc      \
+				 {Normal(selected)}c3",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_toggle_visual_mode() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::ToggleVisualMode],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ToggleVisualMode);
+			assert_eq!(module.visual_index_start, None);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_open_external_editor() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::OpenInEditor],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::OpenInEditor,
+				state = State::ExternalEditor
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_undo() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::Down, Input::ActionDrop, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_undo_normal_mode_change() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		ViewState::default(),
+		&[Input::ActionDrop, Input::ToggleVisualMode, Input::Down, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_redo() {
+	process_module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::Down,
+			Input::ActionPick,
+			Input::Undo,
+			Input::Redo,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
+				 {Normal(selected)}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+#[test]
+#[serial_test::serial]
+fn visual_mode_redo_normal_mode_change() {
+	process_module_test(
+		&["drop aaa c1", "drop bbb c2"],
+		ViewState::default(),
+		&[
+			Input::ActionPick,
+			Input::ToggleVisualMode,
+			Input::Down,
+			Input::Undo,
+			Input::Redo,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionDrop}drop   {Normal}bbb      {Normal}c2"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_remove_lines_start_index_first() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::Delete,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_remove_lines_end_index_first() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::Delete,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
+				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_remove_lines_start_index_last() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::Delete,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_remove_lines_end_index_last() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::Delete,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
+			);
+			assert_eq!(
+				module.visual_index_start.unwrap(),
+				test_context.rebase_todo_file.get_selected_line_index()
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_other_input() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Other],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Other);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_mode_render() {
+	process_module_test(
+		&["exec foo"],
+		ViewState::default(),
+		&[Input::Edit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Modifying line: exec foo",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}exec {Normal}foo{Normal,Underline} ",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_mode_handle_input() {
+	process_module_test(
+		&["exec foo"],
+		ViewState::default(),
+		&[Input::Edit, Input::Backspace, Input::Enter],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_content(), "fo");
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_merge_mode_render() {
+	process_module_test(
+		&["merge -C aaa feature # Merge branch 'feature'"],
+		ViewState::default(),
+		&[Input::Edit],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::EditMerge);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Modifying line: merge -C aaa feature # Merge branch 'feature'",
+				"",
+				"{BODY}",
+				"{Normal,Dimmed}Option:  {Normal}-C{Normal,Underline} ",
+				"{Normal,Dimmed}Commit:  {Normal}aaa",
+				"{Normal,Dimmed}Labels:  {Normal}feature",
+				"{Normal,Dimmed}Oneline: {Normal}Merge branch 'feature'",
+				"{TRAILING}",
+				"{IndicatorColor}Tab to change field, Enter to finish"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_merge_mode_handle_input() {
+	process_module_test(
+		&["merge -C aaa feature # Merge branch 'feature'"],
+		ViewState::default(),
+		&[
+			Input::Edit,
+			Input::Backspace,
+			Input::Character('c'),
+			Input::Tab,
+			Input::Tab,
+			Input::Character('2'),
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"merge -c aaa feature2 # Merge branch 'feature'"
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_merge_mode_add_commit_defaults_option() {
+	process_module_test(
+		&["merge feature"],
+		ViewState::default(),
+		&[
+			Input::Edit,
+			Input::Tab,
+			Input::Character('a'),
+			Input::BackTab,
+			Input::BackTab,
+			Input::Character('x'),
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"merge -C a feature # x"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_merge_mode_remove_commit() {
+	process_module_test(
+		&["merge -C aaa feature"],
+		ViewState::default(),
+		&[
+			Input::Edit,
+			Input::Tab,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"merge feature"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn edit_merge_mode_empty_label() {
+	process_module_test(
+		&["merge -C aaa feature"],
+		ViewState::default(),
+		&[
+			Input::Edit,
+			Input::Tab,
+			Input::Tab,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Backspace,
+			Input::Enter,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_n_inputs(&mut module, 10);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Enter,
+				error = anyhow::anyhow!("A merge needs at least one label")
+			);
+			assert_eq!(module.state, ListState::EditMerge);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"merge -C aaa feature"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn scroll_right() {
+	process_module_test(
+		&[
+			"pick aaaaaaaaaaaa this comment needs to be longer than the width of the view",
+			"pick bbbbbbbbbbbb this comment needs to be longer than the width of the view",
+			"pick cccccccccccc this comment needs to be longer than the width of the view",
+		],
+		ViewState {
+			size: Size::new(50, 4),
+			..ViewState::default()
+		},
+		&[Input::MoveCursorRight; 3],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa {Normal(selected)}s \
+				 comment needs to be longer th",
+				"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal}s comment needs to be longer th",
+				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}s comment needs to be longer th"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn scroll_left() {
+	process_module_test(
+		&[
+			"pick aaaaaaaaaaaa this comment needs to be longer than the width of the view",
+			"pick bbbbbbbbbbbb this comment needs to be longer than the width of the view",
+			"pick cccccccccccc this comment needs to be longer than the width of the view",
+		],
+		ViewState {
+			size: Size::new(50, 4),
+			..ViewState::default()
+		},
+		&[
+			Input::MoveCursorRight,
+			Input::MoveCursorRight,
+			Input::MoveCursorRight,
+			Input::MoveCursorLeft,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa {Normal(selected)}is \
+				 comment needs to be longer t",
+				"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal}is comment needs to be longer t",
+				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}is comment needs to be longer t"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_help() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState {
+			size: Size::new(200, 100),
+			..ViewState::default()
+		},
+		&[Input::Help],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			module.state = ListState::Normal;
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal,Underline} Key      Action{Normal,Underline}{Pad  ,184}",
+				"{BODY}",
+				"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
+				"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
+				"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
+				"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
+				"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
+				"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
+				"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
+				"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
+				"{IndicatorColor} q       {Normal,Dimmed}|{Normal}Abort interactive rebase",
+				"{IndicatorColor} Q       {Normal,Dimmed}|{Normal}Immediately abort interactive rebase",
+				"{IndicatorColor} w       {Normal,Dimmed}|{Normal}Write interactive rebase file",
+				"{IndicatorColor} W       {Normal,Dimmed}|{Normal}Immediately write interactive rebase file",
+				"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Enter visual mode",
+				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
+				"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show commit information",
+				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commit down",
+				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commit up",
+				"{IndicatorColor} b       {Normal,Dimmed}|{Normal}Toggle break action",
+				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commit to be picked",
+				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commit to be reworded",
+				"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commit to be edited",
+				"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commit to be squashed",
+				"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commit to be fixed-up",
+				"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commit to be dropped",
+				"{IndicatorColor} u       {Normal,Dimmed}|{Normal}Cycle fixup message option (-C, -c)",
+				"{IndicatorColor} A       {Normal,Dimmed}|{Normal}Move fixup, squash and amend commits under their \
+				 targets",
+				"{IndicatorColor} F       {Normal,Dimmed}|{Normal}Fixup selected commit into the commit it changes",
+				"{IndicatorColor} D       {Normal,Dimmed}|{Normal}Drop all empty and already upstream commits",
+				"{IndicatorColor} X       {Normal,Dimmed}|{Normal}Insert an exec template after each commit",
+				"{IndicatorColor} R       {Normal,Dimmed}|{Normal}Remove the exec lines of an exec template",
+				"{IndicatorColor} E       {Normal,Dimmed}|{Normal}Edit an exec action's command",
+				"{IndicatorColor} I       {Normal,Dimmed}|{Normal}Insert a new line",
+				"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected line",
+				"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
+				"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
+				"{IndicatorColor} H       {Normal,Dimmed}|{Normal}Show the undo history",
+				"{IndicatorColor} G       {Normal,Dimmed}|{Normal}Toggle the branch graph",
+				"{IndicatorColor} /       {Normal,Dimmed}|{Normal}Search the todo list",
+				"{IndicatorColor} Z       {Normal,Dimmed}|{Normal}Search the todo list backwards",
+				"{IndicatorColor} n       {Normal,Dimmed}|{Normal}Move to the next match",
+				"{IndicatorColor} N       {Normal,Dimmed}|{Normal}Move to the previous match",
+				"{IndicatorColor} |       {Normal,Dimmed}|{Normal}Filter the todo list, or remove the filter",
+				"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Cut the selected line, to move it with a paste",
+				"{IndicatorColor} y       {Normal,Dimmed}|{Normal}Copy the selected line",
+				"{IndicatorColor} ]       {Normal,Dimmed}|{Normal}Paste below the selected line",
+				"{IndicatorColor} [       {Normal,Dimmed}|{Normal}Paste above the selected line",
+				"{IndicatorColor} \"       {Normal,Dimmed}|{Normal}Choose the register for the next cut, copy or paste",
+				"{IndicatorColor} !       {Normal,Dimmed}|{Normal}Open the todo file in the default editor",
+				"{TRAILING}",
+				"{IndicatorColor}Press any key to close"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_help_input() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Help, Input::SwapSelectedDown],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			module.state = ListState::Normal;
+			test_context.handle_all_inputs(&mut module);
+			assert!(!module.normal_mode_help.is_active());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_help() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState {
+			size: Size::new(200, 100),
+			..ViewState::default()
+		},
+		&[Input::Help],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			module.state = ListState::Visual;
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal,Underline} Key      Action{Normal,Underline}{Pad  ,184}",
+				"{BODY}",
+				"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
+				"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
+				"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
+				"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
+				"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
+				"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
+				"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
+				"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
+				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
+				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commits down",
+				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commits up",
+				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
+				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
+				"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commits to be edited",
+				"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commits to be squashed",
+				"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commits to be fixed-up",
+				"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commits to be dropped",
+				"{IndicatorColor} u       {Normal,Dimmed}|{Normal}Cycle fixup message option (-C, -c)",
+				"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected lines",
+				"{IndicatorColor} S       {Normal,Dimmed}|{Normal}Sort the selected commits",
+				"{IndicatorColor} F       {Normal,Dimmed}|{Normal}Fixup selected commits into the commits they change",
+				"{IndicatorColor} X       {Normal,Dimmed}|{Normal}Insert an exec template after each selected commit",
+				"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
+				"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
+				"{IndicatorColor} H       {Normal,Dimmed}|{Normal}Show the undo history",
+				"{IndicatorColor} G       {Normal,Dimmed}|{Normal}Toggle the branch graph",
+				"{IndicatorColor} n       {Normal,Dimmed}|{Normal}Move to the next match",
+				"{IndicatorColor} N       {Normal,Dimmed}|{Normal}Move to the previous match",
+				"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Cut the selected lines, to move them with a paste",
+				"{IndicatorColor} y       {Normal,Dimmed}|{Normal}Copy the selected lines",
+				"{IndicatorColor} \"       {Normal,Dimmed}|{Normal}Choose the register for the next cut or copy",
+				"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Exit visual mode",
+				"{TRAILING}",
+				"{IndicatorColor}Press any key to close"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_help_input() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::Help, Input::SwapSelectedDown],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			module.state = ListState::Visual;
+			test_context.handle_all_inputs(&mut module);
+			assert!(!module.visual_mode_help.is_active());
+		},
+	);
+}
+
+// this can technically never happen, but it's worth testing, just in case of an invalid state
+#[test]
+#[serial_test::serial]
+fn render_noop_list() {
+	process_module_test(
+		&["break"],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.rebase_todo_file.remove_lines(0, 0);
+			test_context.rebase_todo_file.add_line(0, Line::new("noop").unwrap());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {Normal(selected)}noop   "
+			);
+		},
+	);
+}
+
+fn set_done_lines(test_context: &mut TestContext<'_>, lines: &[&str]) {
+	test_context
+		.rebase_todo_file
+		.set_done_lines(lines.iter().map(|l| Line::new(l).unwrap()).collect());
+}
+
+#[test]
+#[serial_test::serial]
+fn render_done_lines() {
+	process_module_test(
+		&["pick cccccccc comment 3"],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaaaaaaa comment 1", "edit bbbbbbbb comment 2"]);
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {Normal,Dimmed}pick   aaaaaaaa {Normal,Dimmed}comment 1",
+				"{Normal}   {IndicatorColor}edit   bbbbbbbb {IndicatorColor}comment 2{IndicatorColor} (stopped here)",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}cccccccc \
+				 {Normal(selected)}comment 3"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_done_lines_compact() {
+	process_module_test(
+		&["pick cccccccc comment 3"],
+		ViewState {
+			size: Size::new(30, 100),
+			..ViewState::default()
+		},
+		&[],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaaaaaaa comment 1", "exec make"]);
+			test_context.rebase_todo_file.set_selected_done_line_index(Some(0));
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)}>{Normal(selected),Dimmed}p aaa {Normal(selected),Dimmed}comment 1",
+				"{Normal} {IndicatorColor}x {IndicatorColor}make{IndicatorColor} (stopped here)",
+				"{Normal} {ActionPick}p {Normal}ccc {Normal}comment 3"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn done_lines_move_cursor_up_and_down() {
+	process_module_test(
+		&["pick ccc c3", "pick ddd c4"],
+		ViewState::default(),
+		&[
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::MoveCursorUp,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+		],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaa c1", "pick bbb c2"]);
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_done_line_index(), Some(1));
+			test_context.handle_input(&mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_done_line_index(), Some(0));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_done_line_index(), Some(1));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_done_line_index(), None);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn done_lines_without_done_lines_move_cursor_up() {
+	process_module_test(
+		&["pick ccc c3"],
+		ViewState::default(),
+		&[Input::MoveCursorUp],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_done_line_index(), None);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn done_lines_show_commit() {
+	process_module_test(
+		&["pick ccc c3"],
+		ViewState::default(),
+		&[Input::MoveCursorUp, Input::ShowCommit],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaa c1"]);
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::ShowCommit,
+				state = State::ShowCommit
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn done_lines_show_commit_without_hash() {
+	process_module_test(
+		&["pick ccc c3"],
+		ViewState::default(),
+		&[Input::MoveCursorUp, Input::ShowCommit],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["exec make"]);
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::ShowCommit);
+		},
+	);
+}
+
+#[rstest(
+	input,
+	case::action_drop(Input::ActionDrop),
+	case::action_break(Input::ActionBreak),
+	case::edit(Input::Edit),
+	case::delete(Input::Delete),
+	case::swap_selected_up(Input::SwapSelectedUp),
+	case::toggle_visual_mode(Input::ToggleVisualMode),
+	case::insert_line(Input::InsertLine)
+)]
+#[serial_test::serial]
+fn done_lines_are_read_only(input: Input) {
+	process_module_test(
+		&["pick ccc c3"],
+		ViewState::default(),
+		&[Input::MoveCursorUp, input],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaa c1"]);
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(test_context.handle_input(&mut module), input = input);
+			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().to_text(), "pick ccc c3");
+			assert_eq!(test_context.rebase_todo_file.get_done_lines()[0].to_text(), "pick aaa c1");
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn done_lines_abort() {
+	process_module_test(
+		&["pick ccc c3"],
+		ViewState::default(),
+		&[Input::MoveCursorUp, Input::Abort],
+		|mut test_context: TestContext<'_>| {
+			set_done_lines(&mut test_context, &["pick aaa c1"]);
+			let mut module = List::new(test_context.config);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Abort,
+				state = State::ConfirmAbort
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_sort_selection_render_choice() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::SortSelection],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal}Sort the selected commits by:",
+				"",
+				"{BODY}",
+				"{Normal}1) Author date",
+				"{Normal}2) Committer date",
+				"{Normal}3) Author name",
+				"{Normal}4) First changed path",
+				"{Normal}5) Return to the list",
+				"",
+				"{IndicatorColor}Please choose an option."
+			);
+		},
+	);
+}
+
+#[rstest(
+	choice,
+	expected,
+	case::author_date('1', &[
+		"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+		"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+		"exec make",
+		"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+	]),
+	case::committer_date('2', &[
+		"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+		"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+		"exec make",
+		"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+	]),
+	case::author_name('3', &[
+		"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+		"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+		"exec make",
+		"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+	]),
+	case::return_to_list('5', &[
+		"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+		"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+		"exec make",
+		"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+	])
+)]
+#[serial_test::serial]
+fn visual_mode_sort_selection(choice: char, expected: &[&str]) {
+	process_module_test(
+		&[
+			"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+			"exec make",
+			"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+		],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorEnd,
+			Input::SortSelection,
+			Input::Character(choice),
+			Input::Undo,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 4);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				expected
+			);
+			test_context.handle_input(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().get_hash(),
+				"aed0fd1db3e73c0e568677ae8903a11c5fbc5659"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_sort_selection_keeps_fixup_with_target() {
+	process_module_test(
+		&[
+			"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+			"fixup 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+		],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorEnd,
+			Input::SortSelection,
+			Input::Character('1'),
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d Add base files",
+					"pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 File status - moved",
+					"fixup 18d82dcc4c36cade807d7cf79700b6bbad8080b9 Empty commit title",
+				]
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_sort_selection_invalid_commit() {
+	process_module_test(
+		&["pick aaaaaaaaaa c1", "pick bbbbbbbbbb c2"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::SortSelection,
+			Input::Character('1'),
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Character('1'),
+				error = anyhow::anyhow!(
+					"Error loading commit: aaaaaaaaaa: revspec 'aaaaaaaaaa' not found; class=Reference (4); \
+					 code=NotFound (-3)"
+				)
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_sort_selection_with_branch_lines() {
+	process_module_test(
+		&["pick aaa c1", "label feature", "pick bbb c2"],
+		ViewState::default(),
+		&[
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::SortSelection,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::SortSelection,
+				error = anyhow::anyhow!("Lines with a label, reset or merge can not be sorted")
+			);
+			assert_eq!(module.state, ListState::Visual);
+		},
+	);
+}
+
+fn set_in_progress_todo_file(test_context: &mut TestContext<'_>, rebase_dir: &std::path::Path, onto: &str) {
+	std::fs::write(rebase_dir.join("onto"), onto).unwrap();
+	let lines = test_context.rebase_todo_file.get_lines_owned();
+	test_context.rebase_todo_file = TodoFile::new(rebase_dir.join("git-rebase-todo").to_str().unwrap(), 1, "#");
+	test_context.rebase_todo_file.set_lines(lines);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_dry_run_conflict() {
+	process_module_test(
+		&[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "1cc0456637cb220155e957c641f483e60724c581");
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dry_run.wait();
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor(selected)}^> {ActionPick(selected)}pick   {Normal(selected)}d8547963 \
+				 {Normal(selected)}deleted",
+				"{WarningColor}x  {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base{WarningColor} (below this commit)"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_dry_run_conflict_selected() {
+	process_module_test(
+		&[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "1cc0456637cb220155e957c641f483e60724c581");
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dry_run.wait();
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor}^  {ActionPick}pick   {Normal}d8547963 {Normal}deleted",
+				"{WarningColor(selected)}x> {ActionPick(selected)}pick   {Normal(selected)}7f5eac44 \
+				 {Normal(selected)}base",
+				"{TRAILING}",
+				"",
+				"{Normal}Depended on by:",
+				"{Normal}  d8547963 deleted",
+				"",
+				"{WarningColor}Applying this commit would conflict in:",
+				"{Normal}  a"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_dry_run_unchecked_selected() {
+	process_module_test(
+		&[
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+			"pick 0123456789 c1",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "1cc0456637cb220155e957c641f483e60724c581");
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dry_run.wait();
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor}^  {ActionPick}pick   {Normal}d8547963 {Normal}deleted",
+				"{WarningColor(selected)}?> {ActionPick(selected)}pick   {Normal(selected)}01234567 \
+				 {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{TRAILING}",
+				"",
+				"{WarningColor}This line could not be applied, so the lines after it were not checked"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn dry_run_pending_without_input() {
+	process_module_test(
+		&["pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted"],
+		ViewState::default(),
+		&[Input::MoveCursorDown],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "1cc0456637cb220155e957c641f483e60724c581");
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			assert!(module.dry_run.is_pending());
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::MoveCursorDown);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_depends_on() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}1cc04566 \
+				 {Normal(selected)}modified",
+				"{Normal}   {ActionPick}pick   {Normal}c1ac7f2c {Normal}added",
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_dependency_lines_limited() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"pick bbb c2",
+			"pick ccc c3",
+			"pick ddd c4",
+			"pick eee c5",
+			"pick fff c6",
+			"pick 111 c7",
+		],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut view_data = ViewData::new();
+			view_data.set_view_size(120, 20);
+			List::push_dependency_lines(
+				&mut view_data,
+				&test_context.rebase_todo_file,
+				"Depends on:",
+				&["aaa", "bbb", "ccc", "ddd", "eee", "fff", "111"],
+				&[],
+			);
+			view_data.rebuild();
+			assert_rendered_output!(
+				&view_data,
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  aaa      c1",
+				"{Normal}  bbb      c2",
+				"{Normal}  ccc      c3",
+				"{Normal}  ddd      c4",
+				"{Normal}  eee      c5",
+				"{Normal}  ... and 2 more"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_depended_on_by() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+		],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}7f5eac44 {Normal(selected)}base",
+				"{Normal}   {ActionPick}pick   {Normal}1cc04566 {Normal}modified",
+				"{Normal}   {ActionPick}pick   {Normal}d8547963 {Normal}deleted",
+				"{TRAILING}",
+				"",
+				"{Normal}Depended on by:",
+				"{Normal}  1cc04566 modified",
+				"{Normal}  d8547963 deleted"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_moved_above_dependency() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::SwapSelectedUp],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			let _ = test_context.build_view_data(&mut module);
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor(selected)}^> {ActionPick(selected)}pick   {Normal(selected)}1cc04566 \
+				 {Normal(selected)}modified",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base{WarningColor} (below this commit)"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_moved_above_dropped_dependency() {
+	process_module_test(
+		&[
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			"drop 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}1cc04566 {Normal}modified",
+				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}7f5eac44 {Normal(selected)}base",
+				"{TRAILING}",
+				"",
+				"{Normal}Depended on by:",
+				"{Normal}  1cc04566 modified"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_moved_above_dependency_compact() {
+	process_module_test(
+		&[
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState {
+			size: Size::new(30, 100),
+			..ViewState::default()
+		},
+		&[],
+		|mut test_context: TestContext<'_>| {
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor}^{ActionPick}p {Normal}1cc {Normal}modified",
+				"{Normal(selected)}>{ActionPick(selected)}p {Normal(selected)}7f5 {Normal(selected)}base",
+				"{TRAILING}",
+				"",
+				"{Normal}Depended on by:",
+				"{Normal}  1cc04566 modified"
+			);
+		},
+	);
+}
+
+// the absorb waits for the dependencies, which are loaded in the background
+fn wait_for_absorb(test_context: &mut TestContext<'_>, module: &mut List<'_>) {
+	module.dependency_loader.wait();
+	assert_process_result!(test_context.handle_input(module));
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_absorb() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::MoveCursorEnd, Input::Absorb, Input::Yes, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(module.state, ListState::Normal);
+			wait_for_absorb(&mut test_context, &mut module);
+			assert_eq!(module.state, ListState::Absorb);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::Yes);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{Normal(selected)} > {ActionFixup(selected)}fixup  {Normal(selected)}1cc04566 \
+				 {Normal(selected)}modified",
+				"{Normal}   {ActionPick}pick   {Normal}c1ac7f2c {Normal}added",
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base"
+			);
+			test_context.handle_input(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(2).unwrap().to_text(),
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn absorb_render_confirm() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::Absorb],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			wait_for_absorb(&mut test_context, &mut module);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Fixup the commits into the commits they change (y/n)? ",
+				"",
+				"{Normal}  1cc04566 modified → 7f5eac44 base"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_absorb() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+			"pick d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+		],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorEnd,
+			Input::Absorb,
+			Input::Yes,
+		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 4);
+			wait_for_absorb(&mut test_context, &mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+					"fixup 1cc0456637cb220155e957c641f483e60724c581 modified",
+					"fixup d85479638307e4db37e1f1f2c3c807f7ff36a0ff deleted",
+					"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+				]
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_absorb_cancel() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::MoveCursorEnd, Input::Absorb, Input::No],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			wait_for_absorb(&mut test_context, &mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(2).unwrap().to_text(),
+				"pick 1cc0456637cb220155e957c641f483e60724c581 modified"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_absorb_target_selected() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::MoveCursorDown, Input::Absorb, Input::Yes],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
+			wait_for_absorb(&mut test_context, &mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(1).unwrap().to_text(),
+				"fixup 1cc0456637cb220155e957c641f483e60724c581 modified"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn absorb_without_target() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+		],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::Absorb],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 2);
+			module.dependency_loader.wait();
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				error = anyhow::anyhow!("No earlier commit found that the selected commits change")
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn absorb_while_dependencies_load() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::Absorb],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(module.deferred_input, Some(Input::Absorb));
+			module.dependency_loader.wait();
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{STATUS:Loading dependencies}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}1cc04566 \
+				 {Normal(selected)}modified",
+				"{TRAILING}",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base"
+			);
+			assert_process_result!(test_context.handle_input(&mut module));
+			assert_eq!(module.deferred_input, None);
+			assert_eq!(module.state, ListState::Absorb);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn absorb_while_filtered() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.extend(&[Input::MoveCursorEnd, Input::Absorb]);
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick c1ac7f2c32f9e00012f409572d223c9457ae497b added",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&inputs,
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 8);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Absorb,
+				error = anyhow::anyhow!("Lines can not be moved while the list is filtered")
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_empty_commit() {
+	process_module_test(
+		&[
+			"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+		],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor(selected)}_> {ActionPick(selected)}pick   {Normal(selected)}18d82dcc \
+				 {Normal(selected)}empty",
+				"{Normal}   {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{TRAILING}",
+				"",
+				"{WarningColor}This commit is empty"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_upstream_commit() {
+	process_module_test(
+		&[
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "d85479638307e4db37e1f1f2c3c807f7ff36a0ff");
+			test_context.rebase_todo_file.set_selected_line_index(1);
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			module.dry_run.wait();
+			module.dependency_loader.wait();
+			module.redundancy_loader.wait();
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{WarningColor}x  {ActionPick}pick   {Normal}7f5eac44 {Normal}base",
+				"{WarningColor(selected)}=> {ActionPick(selected)}pick   {Normal(selected)}1cc04566 \
+				 {Normal(selected)}modified",
+				"{TRAILING}",
+				"",
+				"{WarningColor}This commit is already upstream and will be skipped",
+				"",
+				"{Normal}Depends on:",
+				"{Normal}  7f5eac44 base"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_dropped_empty_commit() {
+	process_module_test(
+		&["drop 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty"],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}18d82dcc {Normal(selected)}empty"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn render_finding_redundant_commits() {
+	process_module_test(
+		&["pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty"],
+		ViewState::default(),
+		&[Input::DropRedundant],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{STATUS:Finding redundant commits}{HELP}",
+				"{BODY}",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}18d82dcc {Normal(selected)}empty"
+			);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn normal_mode_drop_redundant() {
+	process_module_test(
+		&[
+			"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty",
+			"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+			"exec make",
+			"pick 1cc0456637cb220155e957c641f483e60724c581 modified",
+		],
+		ViewState::default(),
+		&[Input::DropRedundant, Input::Undo],
+		|mut test_context: TestContext<'_>| {
+			let rebase_dir = tempfile::tempdir().unwrap();
+			set_in_progress_todo_file(&mut test_context, rebase_dir.path(), "d85479638307e4db37e1f1f2c3c807f7ff36a0ff");
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::DropRedundant);
+			assert_eq!(module.deferred_input, Some(Input::DropRedundant));
+			module.redundancy_loader.wait();
+			assert_process_result!(test_context.handle_input(&mut module));
+			assert_eq!(module.deferred_input, None);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"drop 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty",
+					"pick 7f5eac44012ea33e5bdec0df72125c1bc2b2691d base",
+					"exec make",
+					"drop 1cc0456637cb220155e957c641f483e60724c581 modified",
+				]
+			);
+			test_context.handle_input(&mut module);
+			assert_eq!(
+				test_context.rebase_todo_file.get_line(0).unwrap().to_text(),
+				"pick 18d82dcc4c36cade807d7cf79700b6bbad8080b9 empty"
+			);
+		},
+	);
+}
+
+fn create_exec_template_config(config: &Config) -> Config {
+	let mut config = config.clone();
+	config.exec_templates = vec![
+		(String::from("build"), String::from("cargo build")),
+		(String::from("test"), String::from("cargo test --all")),
+	];
+	config
+}
+
+#[test]
+fn insert_exec_template_render_choice() {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[Input::InsertExecTemplate],
+		|mut test_context: TestContext<'_>| {
+			let config = create_exec_template_config(test_context.config);
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{Normal}Insert an exec line after each commit with:",
+				"",
+				"{BODY}",
+				"{Normal}1) build (cargo build)",
+				"{Normal}2) test (cargo test --all)",
+				"{Normal}3) Return to the list",
+				"",
+				"{IndicatorColor}Please choose an option."
+			);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_insert_exec_template() {
+	process_module_test(
+		&["pick aaa c1", "fixup bbb c2", "pick ccc c3"],
+		ViewState::default(),
+		&[Input::InsertExecTemplate, Input::Character('2')],
+		|mut test_context: TestContext<'_>| {
+			let config = create_exec_template_config(test_context.config);
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"pick aaa c1",
+					"fixup bbb c2",
+					"exec cargo test --all",
+					"pick ccc c3",
+					"exec cargo test --all"
+				]
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_insert_exec_template() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		ViewState::default(),
+		&[
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::InsertExecTemplate,
+			Input::Character('1'),
+		],
+		|mut test_context: TestContext<'_>| {
+			let config = create_exec_template_config(test_context.config);
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(module.visual_index_start, None);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec![
+					"pick aaa c1",
+					"pick bbb c2",
+					"exec cargo build",
+					"pick ccc c3",
+					"exec cargo build"
+				]
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_insert_exec_template_return_to_list() {
+	process_module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		ViewState::default(),
+		&[Input::ToggleVisualMode, Input::InsertExecTemplate, Input::Character('3')],
+		|mut test_context: TestContext<'_>| {
+			let config = create_exec_template_config(test_context.config);
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(test_context.rebase_todo_file.get_max_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn normal_mode_remove_exec_template() {
+	process_module_test(
+		&[
+			"pick aaa c1",
+			"exec cargo build",
+			"exec cargo test --all",
+			"pick bbb c2",
+			"exec cargo build",
+		],
+		ViewState::default(),
+		&[Input::RemoveExecTemplate, Input::Character('1')],
+		|mut test_context: TestContext<'_>| {
+			let config = create_exec_template_config(test_context.config);
+			let mut module = List::new(&config);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(Line::to_text)
+					.collect::<Vec<String>>(),
+				vec!["pick aaa c1", "exec cargo test --all", "pick bbb c2"]
+			);
+		},
+	);
+}
+
+#[rstest(input, case::insert(Input::InsertExecTemplate), case::remove(Input::RemoveExecTemplate))]
+fn exec_template_without_templates(input: Input) {
+	process_module_test(
+		&["pick aaa c1"],
+		ViewState::default(),
+		&[input],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = input,
+				error = anyhow::anyhow!("No exec templates are configured")
+			);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+const SEARCH_TODO: &[&str] = &["pick aaa c1", "pick bbb fix bug", "pick ccc c3", "pick ddd another bug"];
+
+#[test]
+#[serial_test::serial]
+fn search_render() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		SEARCH_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::Delete,
+			Input::Search,
+			Input::Character('b'),
+			Input::Character('u'),
+			Input::Character('g'),
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Search);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
+				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}fix \
+				 {IndicatorColor(selected),Underline}bug",
 				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4"
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}another {IndicatorColor,Underline}bug",
+				"{TRAILING}",
+				"",
+				"{Normal,Dimmed}/{Normal}bug{Normal,Underline} "
 			);
 		},
 	);
@@ -1627,213 +5059,206 @@ fn normal_mode_remove_line_end() {
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_toggle_visual_mode() {
+fn search_matches_hash() {
 	process_module_test(
-		&["pick aaa c1"],
+		SEARCH_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode],
+		&[Input::Search, Input::Character('C'), Input::Character('c')],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::ToggleVisualMode);
-			assert_eq!(module.visual_index_start, Some(0));
-			assert_eq!(module.state, ListState::Visual);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_other_input() {
+fn search_without_match() {
 	process_module_test(
-		&["pick aaa c1"],
+		SEARCH_TODO,
 		ViewState::default(),
-		&[Input::Other],
+		&[Input::MoveCursorDown, Input::Search, Input::Character('z')],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Other);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      \
+				 {Normal(selected)}fix bug",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}another bug",
+				"{TRAILING}",
+				"",
+				"{Normal,Dimmed}/{Normal}z{Normal,Underline} {WarningColor}  No matching lines"
+			);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_top_bottom() {
+fn search_escape_restores_selected_line() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		SEARCH_TODO,
 		ViewState::default(),
-		&[
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionReword,
-		],
+		&[Input::MoveCursorDown, Input::Search, Input::Character('c'), Input::Escape],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c3"
-			);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(module.search_term, None);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_bottom_top() {
+fn search_next_and_previous() {
 	process_module_test(
-		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		SEARCH_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::ActionReword,
+			Input::Search,
+			Input::Character('b'),
+			Input::Character('u'),
+			Input::Character('g'),
+			Input::Enter,
+			Input::SearchNext,
+			Input::SearchNext,
+			Input::SearchPrevious,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c3"
-			);
+			test_context.handle_n_inputs(&mut module, 5);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_drop() {
+fn search_backward() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		SEARCH_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionDrop,
+			Input::SearchBackward,
+			Input::Character('b'),
+			Input::Character('u'),
+			Input::Character('g'),
+			Input::Enter,
+			Input::SearchNext,
+			Input::SearchPrevious,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
+			test_context.handle_n_inputs(&mut module, 4);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 			assert_rendered_output!(
-				view_data,
+				test_context.build_view_data(&mut module),
 				"{TITLE}{HELP}",
 				"{BODY}",
 				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionDrop(selected)}drop   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionDrop(selected)}drop   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionDrop(selected)}drop   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}fix {IndicatorColor,Underline}bug",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      \
+				 {Normal(selected)}another {IndicatorColor(selected),Underline}bug",
+				"{TRAILING}",
+				"",
+				"{Normal,Dimmed}?{Normal}bug{Normal,Underline} "
 			);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_edit() {
+fn search_next_without_search() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		SEARCH_TODO,
+		ViewState::default(),
+		&[Input::SearchNext],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(test_context.handle_input(&mut module), input = Input::SearchNext);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn visual_mode_search_next_extends_selection() {
+	process_module_test(
+		SEARCH_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
+			Input::Search,
+			Input::Character('c'),
+			Input::Character('3'),
+			Input::Enter,
+			Input::MoveCursorHome,
 			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionEdit,
+			Input::SearchNext,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionEdit(selected)}edit   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionEdit(selected)}edit   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionEdit(selected)}edit   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(module.visual_index_start, Some(0));
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
+const FILTER_TODO: &[&str] = &["pick aaa c1", "fixup bbb c2", "pick ccc c3", "fixup ddd c4", "exec make"];
+
+fn filter_inputs(kind: char, value: &str) -> Vec<Input> {
+	let mut inputs = vec![Input::Filter, Input::Character(kind)];
+	inputs.extend(value.chars().map(Input::Character));
+	inputs.push(Input::Enter);
+	inputs
+}
+
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_fixup() {
+fn filter_render() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		FILTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionFixup,
-		],
+		&filter_inputs('1', "fixup"),
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
-				"{TITLE}{HELP}",
+				"{TITLE}{STATUS:Filtered by action: fixup}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionFixup(selected)}fixup  {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionFixup(selected)}fixup  {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionFixup(selected)}fixup  {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+				"{Normal(selected)} > {ActionFixup(selected)}fixup  {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal}   {ActionFixup}fixup  {Normal}ddd      {Normal}c4"
 			);
 		},
 	);
@@ -1841,39 +5266,26 @@ fn visual_mode_action_change_fixup() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_pick() {
+fn filter_value_render() {
 	process_module_test(
-		&[
-			"drop aaa c1",
-			"drop aaa c2",
-			"drop aaa c3",
-			"drop aaa c4",
-			"drop aaa c5",
-		],
+		FILTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionPick,
-		],
+		&[Input::Filter, Input::Character('1'), Input::Character('f')],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::FilterValue);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Show only the lines with the actions, separated by spaces:",
+				"",
 				"{BODY}",
-				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionDrop}drop   {Normal}aaa      {Normal}c5"
+				"{Normal}f{Normal,Underline} ",
+				"{TRAILING}",
+				"{IndicatorColor}Enter to finish"
 			);
 		},
 	);
@@ -1881,39 +5293,48 @@ fn visual_mode_action_change_pick() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_reword() {
+fn filter_move_cursor_skips_hidden_lines() {
+	let mut inputs = filter_inputs('1', "fixup exec");
+	inputs.extend(&[Input::MoveCursorDown, Input::MoveCursorDown, Input::MoveCursorUp, Input::MoveCursorHome]);
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		FILTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionReword,
-		],
+		&inputs,
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 13);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 4);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn filter_visual_mode_changes_visible_lines() {
+	let mut inputs = filter_inputs('1', "fixup");
+	inputs.extend(&[Input::ToggleVisualMode, Input::MoveCursorDown, Input::ActionSquash]);
+	process_module_test(
+		FILTER_TODO,
+		ViewState::default(),
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionReword(selected)}reword {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionReword(selected)}reword {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			assert_eq!(
+				test_context
+					.rebase_todo_file
+					.iter()
+					.map(|line| *line.get_action())
+					.collect::<Vec<Action>>(),
+				vec![Action::Pick, Action::Squash, Action::Pick, Action::Squash, Action::Exec]
 			);
 		},
 	);
@@ -1921,157 +5342,158 @@ fn visual_mode_action_change_reword() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_action_change_squash() {
+fn filter_delete_removes_visible_lines() {
+	let mut inputs = filter_inputs('1', "fixup");
+	inputs.extend(&[Input::ToggleVisualMode, Input::MoveCursorDown, Input::Delete]);
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		FILTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ActionSquash,
-		],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected),Dimmed} > {ActionSquash(selected)}squash {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionSquash(selected)}squash {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionSquash(selected)}squash {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			assert_eq!(
+				test_context.rebase_todo_file.get_lines_owned(),
+				vec![
+					Line::new("pick aaa c1").unwrap(),
+					Line::new("pick ccc c3").unwrap(),
+					Line::new("exec make").unwrap(),
+				]
 			);
+			// nothing is left to show, so the filter is removed
+			assert!(module.filter.is_none());
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_abort() {
+fn filter_refuses_moving_lines() {
+	let mut inputs = filter_inputs('1', "fixup");
+	inputs.push(Input::SwapSelectedDown);
 	process_module_test(
-		&["pick aaa c1"],
+		FILTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::Abort],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
+			test_context.handle_n_inputs(&mut module, 8);
 			assert_process_result!(
 				test_context.handle_input(&mut module),
-				input = Input::Abort,
-				state = State::ConfirmAbort
+				input = Input::SwapSelectedDown,
+				error = anyhow::anyhow!("Lines can not be moved while the list is filtered")
 			);
+			assert_eq!(test_context.rebase_todo_file.get_line(1).unwrap().get_hash(), "bbb");
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_force_abort() {
+fn filter_removed() {
+	let mut inputs = filter_inputs('1', "fixup");
+	inputs.push(Input::Filter);
 	process_module_test(
-		&["pick aaa c1"],
+		FILTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::ForceAbort],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::ForceAbort,
-				exit_status = ExitStatus::Good
-			);
-			assert!(test_context.rebase_todo_file.is_empty());
+			test_context.handle_all_inputs(&mut module);
+			assert!(module.filter.is_none());
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_rebase() {
+fn filter_escape() {
 	process_module_test(
-		&["pick aaa c1"],
+		FILTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::Rebase],
+		&[Input::Filter, Input::Character('1'), Input::Character('f'), Input::Escape],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert!(module.filter.is_none());
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn filter_invalid() {
+	let inputs = filter_inputs('1', "z");
+	process_module_test(
+		FILTER_TODO,
+		ViewState::default(),
+		&inputs,
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 3);
 			assert_process_result!(
 				test_context.handle_input(&mut module),
-				input = Input::Rebase,
-				state = State::ConfirmRebase
+				input = Input::Enter,
+				error = anyhow::anyhow!("Invalid action: z")
 			);
+			assert!(module.filter.is_none());
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_force_rebase() {
+fn filter_without_match() {
+	let inputs = filter_inputs('1', "drop");
 	process_module_test(
-		&["pick aaa c1"],
+		FILTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::ForceRebase],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
+			test_context.handle_n_inputs(&mut module, 6);
 			assert_process_result!(
 				test_context.handle_input(&mut module),
-				input = Input::ForceRebase,
-				exit_status = ExitStatus::Good
+				input = Input::Enter,
+				error = anyhow::anyhow!("No lines match the filter")
 			);
-			assert!(!test_context.rebase_todo_file.is_noop());
+			assert!(module.filter.is_none());
 		},
 	);
 }
 
+const REGISTER_TODO: &[&str] = &["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"];
+
+fn todo_hashes(test_context: &TestContext<'_>) -> Vec<String> {
+	test_context
+		.rebase_todo_file
+		.iter()
+		.map(|line| String::from(line.get_hash()))
+		.collect()
+}
+
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_down_from_top_to_bottom_selection() {
+fn cut_render() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::SwapSelectedDown,
-		],
+		&[Input::Cut, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
 			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
 				view_data,
 				"{TITLE}{HELP}",
 				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
+				"{WarningColor}-  {ActionPick}pick   {Normal}aaa      {Normal}c1",
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4"
 			);
 		},
 	);
@@ -2079,123 +5501,100 @@ fn visual_mode_swap_down_from_top_to_bottom_selection() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_down_from_bottom_to_top_selection() {
+fn cut_and_paste_after() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
+			Input::ToggleVisualMode,
 			Input::MoveCursorDown,
+			Input::Cut,
 			Input::MoveCursorDown,
 			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::SwapSelectedDown,
+			Input::PasteAfter,
+			Input::Undo,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_eq!(todo_hashes(&test_context), vec!["ccc", "ddd", "aaa", "bbb"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn cut_and_paste_before() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::MoveCursorEnd, Input::Cut, Input::MoveCursorHome, Input::PasteBefore],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c4"
-			);
+			assert_eq!(todo_hashes(&test_context), vec!["ddd", "aaa", "bbb", "ccc"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_up_from_top_to_bottom_selection() {
+fn cut_paste_twice_copies() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::SwapSelectedUp,
-		],
+		&[Input::Cut, Input::MoveCursorEnd, Input::PasteAfter, Input::PasteAfter],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			assert_eq!(todo_hashes(&test_context), vec!["bbb", "ccc", "ddd", "aaa", "aaa"]);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn cut_changed_lines() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::Cut, Input::ActionDrop, Input::PasteAfter],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::PasteAfter,
+				error = anyhow::anyhow!("The cut lines have changed, cut them again to move them")
 			);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_up_from_bottom_to_top_selection() {
+fn cut_while_filtered() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.push(Input::Cut);
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::SwapSelectedUp,
-		],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
+			test_context.handle_n_inputs(&mut module, 7);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::Cut,
+				error = anyhow::anyhow!("Lines can not be moved while the list is filtered")
 			);
 		},
 	);
@@ -2203,42 +5602,42 @@ fn visual_mode_swap_up_from_bottom_to_top_selection() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_down_to_limit_from_bottom_to_top_selection() {
+fn yank_and_paste_before() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
 			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::SwapSelectedDown,
-			Input::SwapSelectedDown,
+			Input::MoveCursorDown,
+			Input::Yank,
+			Input::MoveCursorEnd,
+			Input::PasteBefore,
+			Input::Undo,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c4"
+			test_context.handle_n_inputs(&mut module, 5);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "aaa", "bbb", "ddd"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn paste_empty_register() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::PasteAfter],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = Input::PasteAfter,
+				error = anyhow::anyhow!("Nothing in register \"")
 			);
 		},
 	);
@@ -2246,270 +5645,232 @@ fn visual_mode_swap_down_to_limit_from_bottom_to_top_selection() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_down_to_limit_from_top_to_bottom_selection() {
+fn named_register() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
+			Input::SelectRegister,
+			Input::Character('a'),
+			Input::Yank,
 			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::SwapSelectedDown,
-			Input::SwapSelectedDown,
+			Input::Yank,
+			Input::SelectRegister,
+			Input::Character('a'),
+			Input::PasteAfter,
+			Input::PasteAfter,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4"
-			);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "aaa", "bbb", "ccc", "ddd"]);
+		},
+	);
+}
+
+fn mouse(action: MouseAction, column: usize, row: usize) -> Input {
+	Input::Mouse(Mouse::new(action, column, row, false))
+}
+
+#[test]
+#[serial_test::serial]
+fn mouse_click_selects_line() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[mouse(MouseAction::Press, 5, 3), mouse(MouseAction::Release, 5, 3)],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			assert_eq!(module.state, ListState::Normal);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_up_to_limit_from_top_to_bottom_selection() {
+fn mouse_click_after_change_before_render() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::SwapSelectedUp,
-			Input::SwapSelectedUp,
+			Input::SwapSelectedDown,
+			mouse(MouseAction::Press, 5, 3),
+			mouse(MouseAction::Release, 5, 3),
+			mouse(MouseAction::Press, 5, 3),
+			mouse(MouseAction::Release, 5, 3),
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			let _ = test_context.build_view_data(&mut module);
+			let _ = test_context.handle_n_inputs(&mut module, 3);
+			assert_eq!(todo_hashes(&test_context), vec!["bbb", "aaa", "ccc", "ddd"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			let _ = test_context.build_view_data(&mut module);
+			let _ = test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_swap_up_to_limit_from_bottom_to_top_selection() {
+fn mouse_scroll_moves_cursor() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick aaa c2",
-			"pick aaa c3",
-			"pick aaa c4",
-			"pick aaa c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::SwapSelectedUp,
-			Input::SwapSelectedUp,
+			mouse(MouseAction::ScrollDown, 5, 1),
+			mouse(MouseAction::ScrollDown, 5, 1),
+			mouse(MouseAction::ScrollUp, 5, 1),
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
+			let _ = test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c2",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c3",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c5"
-			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_toggle_visual_mode() {
+fn mouse_click_outside_of_lines() {
 	process_module_test(
-		&["pick aaa c1"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::ToggleVisualMode],
+		&[Input::MoveCursorDown, mouse(MouseAction::Press, 5, 0), mouse(MouseAction::Press, 5, 20)],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::ToggleVisualMode);
-			assert_eq!(module.visual_index_start, None);
-			assert_eq!(module.state, ListState::Normal);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_open_external_editor() {
+fn mouse_shift_click_extends_selection() {
 	process_module_test(
-		&["pick aaa c1"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::OpenInEditor],
+		&[
+			Input::MoveCursorDown,
+			Input::Mouse(Mouse::new(MouseAction::Press, 5, 4, true)),
+			mouse(MouseAction::Release, 5, 4),
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_input(&mut module);
-			assert_process_result!(
-				test_context.handle_input(&mut module),
-				input = Input::OpenInEditor,
-				state = State::ExternalEditor
-			);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(module.visual_index_start, Some(1));
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_undo() {
+fn mouse_click_outside_of_selection_ends_visual_mode() {
 	process_module_test(
-		&["pick aaa c1", "pick bbb c2"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::ToggleVisualMode, Input::Down, Input::ActionDrop, Input::Undo],
+		&[Input::ToggleVisualMode, Input::MoveCursorDown, mouse(MouseAction::Press, 5, 4)],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_n_inputs(&mut module, 3);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
-			);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(module.visual_index_start, None);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_undo_normal_mode_change() {
+fn mouse_drag_moves_line() {
 	process_module_test(
-		&["pick aaa c1", "pick bbb c2"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::ActionDrop, Input::ToggleVisualMode, Input::Down, Input::Undo],
+		&[
+			mouse(MouseAction::Press, 5, 1),
+			mouse(MouseAction::Drag, 5, 2),
+			mouse(MouseAction::Drag, 5, 3),
+			mouse(MouseAction::Release, 5, 3),
+			mouse(MouseAction::Drag, 5, 4),
+			Input::Undo,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
 			test_context.handle_n_inputs(&mut module, 3);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Undo);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2"
-			);
-			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(todo_hashes(&test_context), vec!["bbb", "ccc", "aaa", "ddd"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			// the drag is a single change
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_redo() {
+fn mouse_drag_moves_selection() {
 	process_module_test(
-		&["drop aaa c1", "drop bbb c2"],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
 			Input::ToggleVisualMode,
-			Input::Down,
-			Input::ActionPick,
-			Input::Undo,
-			Input::Redo,
+			Input::MoveCursorDown,
+			mouse(MouseAction::Press, 5, 1),
+			mouse(MouseAction::Drag, 5, 3),
+			mouse(MouseAction::Release, 5, 3),
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected),Dimmed} > {ActionPick(selected)}pick   {Normal(selected)}aaa      \
-				 {Normal(selected)}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
-			);
-			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(todo_hashes(&test_context), vec!["ccc", "ddd", "aaa", "bbb"]);
+			assert_eq!(module.visual_index_start, Some(2));
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
+
 #[test]
 #[serial_test::serial]
-fn visual_mode_redo_normal_mode_change() {
+fn mouse_drag_while_filtered() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.extend(&[mouse(MouseAction::Press, 5, 1), mouse(MouseAction::Drag, 5, 2)]);
 	process_module_test(
-		&["drop aaa c1", "drop bbb c2"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::ActionPick,
-			Input::ToggleVisualMode,
-			Input::Down,
-			Input::Undo,
-			Input::Redo,
-		],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
-				"{Normal}   {ActionDrop}drop   {Normal}bbb      {Normal}c2"
+			test_context.handle_n_inputs(&mut module, 7);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
+			assert_process_result!(
+				test_context.handle_input(&mut module),
+				input = mouse(MouseAction::Drag, 5, 2),
+				error = anyhow::anyhow!("Lines can not be moved while the list is filtered")
 			);
-			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_remove_lines_start_index_first() {
+fn mouse_scroll_bar() {
 	process_module_test(
 		&[
 			"pick aaa c1",
@@ -2517,27 +5878,60 @@ fn visual_mode_remove_lines_start_index_first() {
 			"pick ccc c3",
 			"pick ddd c4",
 			"pick eee c5",
+			"pick fff c6",
+			"pick ggg c7",
+			"pick hhh c8",
 		],
-		ViewState::default(),
+		ViewState {
+			size: Size::new(30, 5),
+			..ViewState::default()
+		},
 		&[
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::Delete,
+			mouse(MouseAction::Press, 29, 4),
+			mouse(MouseAction::Drag, 10, 2),
+			mouse(MouseAction::Release, 10, 2),
 		],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 7);
+			let _ = test_context.build_view_data(&mut module);
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			test_context.handle_input(&mut module);
+			assert!(module.mouse_drag.is_none());
+		},
+	);
+}
+
+fn todo_actions(test_context: &TestContext<'_>) -> Vec<Action> {
+	test_context
+		.rebase_todo_file
+		.iter()
+		.map(|line| *line.get_action())
+		.collect()
+}
+
+#[test]
+#[serial_test::serial]
+fn count_render() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::Character('1'), Input::Character('2')],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
+				view_data,
+				"{TITLE}{STATUS:12}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4"
 			);
 		},
 	);
@@ -2545,37 +5939,25 @@ fn visual_mode_remove_lines_start_index_first() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_remove_lines_end_index_first() {
+fn count_render_with_filter() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.push(Input::Character('3'));
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
-			Input::MoveCursorUp,
-			Input::Delete,
-		],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
 			test_context.handle_all_inputs(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
 			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
+				view_data,
+				"{TITLE}{STATUS:Filtered by action: pick  3}{HELP}",
 				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}ddd      {Normal(selected)}c4",
-				"{Normal}   {ActionPick}pick   {Normal}eee      {Normal}c5"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
+				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaa      {Normal(selected)}c1",
+				"{Normal}   {ActionPick}pick   {Normal}bbb      {Normal}c2",
+				"{Normal}   {ActionPick}pick   {Normal}ccc      {Normal}c3",
+				"{Normal}   {ActionPick}pick   {Normal}ddd      {Normal}c4"
 			);
 		},
 	);
@@ -2583,366 +5965,267 @@ fn visual_mode_remove_lines_end_index_first() {
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_remove_lines_start_index_last() {
+fn count_move_cursor() {
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
 		&[
+			Input::Character('3'),
 			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorUp,
+			Input::Character('2'),
 			Input::MoveCursorUp,
-			Input::Delete,
 		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
-			);
-			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
-			);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			assert_eq!(module.count, None);
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn count_with_multiple_digits() {
+	process_module_test(
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::Character('1'), Input::Character('0'), Input::MoveCursorDown],
+		|mut test_context: TestContext<'_>| {
+			let mut module = List::new(test_context.config);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(module.count, Some(10));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_remove_lines_end_index_last() {
+fn count_very_large() {
+	// the digits saturate the count, which is then limited to the length of the list
+	let digits = vec![Input::Character('9'); 25];
+	let mut inputs = vec![Input::MoveCursorDown];
+	for input in &[
+		Input::MoveCursorDown,
+		Input::ActionDrop,
+		Input::MoveCursorUp,
+		Input::SwapSelectedDown,
+		Input::SwapSelectedUp,
+	] {
+		inputs.extend(&digits);
+		inputs.push(*input);
+	}
 	process_module_test(
-		&[
-			"pick aaa c1",
-			"pick bbb c2",
-			"pick ccc c3",
-			"pick ddd c4",
-			"pick eee c5",
-		],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::ToggleVisualMode,
-			Input::MoveCursorDown,
-			Input::MoveCursorDown,
-			Input::Delete,
-		],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.handle_all_inputs(&mut module);
-			assert_rendered_output!(
-				test_context.build_view_data(&mut module),
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal}   {ActionPick}pick   {Normal}aaa      {Normal}c1",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}bbb      {Normal(selected)}c2"
-			);
+			test_context.handle_n_inputs(&mut module, 26);
+			assert_eq!(module.count, Some(usize::MAX));
+			test_context.handle_input(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
+			test_context.handle_n_inputs(&mut module, 26);
 			assert_eq!(
-				module.visual_index_start.unwrap(),
-				test_context.rebase_todo_file.get_selected_line_index()
+				todo_actions(&test_context),
+				vec![Action::Pick, Action::Pick, Action::Pick, Action::Drop]
 			);
+			test_context.handle_n_inputs(&mut module, 26);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 0);
+			test_context.handle_n_inputs(&mut module, 26);
+			assert_eq!(todo_hashes(&test_context), vec!["bbb", "ccc", "ddd", "aaa"]);
+			test_context.handle_n_inputs(&mut module, 26);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_other_input() {
+fn count_very_large_filtered() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.push(Input::MoveCursorDown);
+	inputs.extend(vec![Input::Character('9'); 25]);
+	inputs.push(Input::MoveCursorDown);
 	process_module_test(
-		&["pick aaa c1"],
+		&["pick aaa c1", "drop bbb c2", "pick ccc c3", "pick ddd c4"],
 		ViewState::default(),
-		&[Input::Other],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			assert_process_result!(test_context.handle_input(&mut module), input = Input::Other);
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn edit_mode_render() {
+fn count_leading_zero() {
 	process_module_test(
-		&["exec foo"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::Edit],
+		&[Input::Character('0'), Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{LEADING}",
-				"{IndicatorColor}Modifying line: exec foo",
-				"",
-				"{BODY}",
-				"{Normal,Dimmed}exec {Normal}foo{Normal,Underline} ",
-				"{TRAILING}",
-				"{IndicatorColor}Enter to finish"
-			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn edit_mode_handle_input() {
+fn count_kept_on_resize() {
 	process_module_test(
-		&["exec foo"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::Edit, Input::Backspace, Input::Enter],
+		&[Input::Character('2'), Input::Resize, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			assert_eq!(test_context.rebase_todo_file.get_line(0).unwrap().get_content(), "fo");
-			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn scroll_right() {
+fn count_cleared_by_other_input() {
 	process_module_test(
-		&[
-			"pick aaaaaaaaaaaa this comment needs to be longer than the width of the view",
-			"pick bbbbbbbbbbbb this comment needs to be longer than the width of the view",
-			"pick cccccccccccc this comment needs to be longer than the width of the view",
-		],
-		ViewState {
-			size: Size::new(50, 4),
-			..ViewState::default()
-		},
-		&[Input::MoveCursorRight; 3],
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::Character('2'), Input::ToggleGraph, Input::MoveCursorDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa {Normal(selected)}s \
-				 comment needs to be longer th",
-				"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal}s comment needs to be longer th",
-				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}s comment needs to be longer th"
-			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn scroll_left() {
+fn count_swap_selected_down() {
 	process_module_test(
-		&[
-			"pick aaaaaaaaaaaa this comment needs to be longer than the width of the view",
-			"pick bbbbbbbbbbbb this comment needs to be longer than the width of the view",
-			"pick cccccccccccc this comment needs to be longer than the width of the view",
-		],
-		ViewState {
-			size: Size::new(50, 4),
-			..ViewState::default()
-		},
-		&[
-			Input::MoveCursorRight,
-			Input::MoveCursorRight,
-			Input::MoveCursorRight,
-			Input::MoveCursorLeft,
-		],
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::Character('2'), Input::SwapSelectedDown, Input::Undo],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.build_view_data(&mut module);
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {ActionPick(selected)}pick   {Normal(selected)}aaaaaaaa {Normal(selected)}is \
-				 comment needs to be longer t",
-				"{Normal}   {ActionPick}pick   {Normal}bbbbbbbb {Normal}is comment needs to be longer t",
-				"{Normal}   {ActionPick}pick   {Normal}cccccccc {Normal}is comment needs to be longer t"
-			);
+			test_context.handle_n_inputs(&mut module, 2);
+			assert_eq!(todo_hashes(&test_context), vec!["bbb", "ccc", "aaa", "ddd"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_help() {
+fn count_swap_selected_down_past_end() {
 	process_module_test(
-		&["pick aaa c1"],
-		ViewState {
-			size: Size::new(200, 100),
-			..ViewState::default()
-		},
-		&[Input::Help],
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::Character('9'), Input::SwapSelectedDown],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			module.state = ListState::Normal;
 			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}",
-				"{LEADING}",
-				"{Normal,Underline} Key      Action{Normal,Underline}{Pad  ,184}",
-				"{BODY}",
-				"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
-				"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
-				"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
-				"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
-				"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
-				"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
-				"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
-				"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
-				"{IndicatorColor} q       {Normal,Dimmed}|{Normal}Abort interactive rebase",
-				"{IndicatorColor} Q       {Normal,Dimmed}|{Normal}Immediately abort interactive rebase",
-				"{IndicatorColor} w       {Normal,Dimmed}|{Normal}Write interactive rebase file",
-				"{IndicatorColor} W       {Normal,Dimmed}|{Normal}Immediately write interactive rebase file",
-				"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Enter visual mode",
-				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
-				"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show commit information",
-				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commit down",
-				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commit up",
-				"{IndicatorColor} b       {Normal,Dimmed}|{Normal}Toggle break action",
-				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commit to be picked",
-				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commit to be reworded",
-				"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commit to be edited",
-				"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commit to be squashed",
-				"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commit to be fixed-up",
-				"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commit to be dropped",
-				"{IndicatorColor} E       {Normal,Dimmed}|{Normal}Edit an exec action's command",
-				"{IndicatorColor} I       {Normal,Dimmed}|{Normal}Insert a new line",
-				"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected line",
-				"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
-				"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
-				"{IndicatorColor} !       {Normal,Dimmed}|{Normal}Open the todo file in the default editor",
-				"{TRAILING}",
-				"{IndicatorColor}Press any key to close"
-			);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "ccc", "ddd", "bbb"]);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 3);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn normal_mode_help_input() {
+fn count_swap_selected_up_visual_mode() {
 	process_module_test(
-		&["pick aaa c1"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::Help, Input::SwapSelectedDown],
+		&[
+			Input::MoveCursorDown,
+			Input::MoveCursorDown,
+			Input::ToggleVisualMode,
+			Input::MoveCursorDown,
+			Input::Character('5'),
+			Input::SwapSelectedUp,
+			Input::Undo,
+		],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			module.state = ListState::Normal;
-			test_context.handle_all_inputs(&mut module);
-			assert!(!module.normal_mode_help.is_active());
+			test_context.handle_n_inputs(&mut module, 6);
+			assert_eq!(todo_hashes(&test_context), vec!["ccc", "ddd", "aaa", "bbb"]);
+			assert_eq!(module.visual_index_start, Some(0));
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_hashes(&test_context), vec!["aaa", "bbb", "ccc", "ddd"]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_help() {
+fn count_action() {
 	process_module_test(
-		&["pick aaa c1"],
-		ViewState {
-			size: Size::new(200, 100),
-			..ViewState::default()
-		},
-		&[Input::Help],
+		REGISTER_TODO,
+		ViewState::default(),
+		&[Input::MoveCursorDown, Input::Character('2'), Input::ActionFixup, Input::Undo],
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			module.state = ListState::Visual;
-			test_context.handle_all_inputs(&mut module);
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}",
-				"{LEADING}",
-				"{Normal,Underline} Key      Action{Normal,Underline}{Pad  ,184}",
-				"{BODY}",
-				"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Move selection up",
-				"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Move selection down",
-				"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Move selection up 5 lines",
-				"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Move selection down 5 lines",
-				"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Move selection to top of the list",
-				"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Move selection to end of the list",
-				"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll content to the left",
-				"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll content to the right",
-				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
-				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected commits down",
-				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected commits up",
-				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
-				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
-				"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commits to be edited",
-				"{IndicatorColor} s       {Normal,Dimmed}|{Normal}Set selected commits to be squashed",
-				"{IndicatorColor} f       {Normal,Dimmed}|{Normal}Set selected commits to be fixed-up",
-				"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Set selected commits to be dropped",
-				"{IndicatorColor} Delete  {Normal,Dimmed}|{Normal}Completely remove the selected lines",
-				"{IndicatorColor} Controlz{Normal,Dimmed}|{Normal}Undo the last change",
-				"{IndicatorColor} Controly{Normal,Dimmed}|{Normal}Redo the previous undone change",
-				"{IndicatorColor} v       {Normal,Dimmed}|{Normal}Exit visual mode",
-				"{TRAILING}",
-				"{IndicatorColor}Press any key to close"
+			test_context.handle_n_inputs(&mut module, 3);
+			assert_eq!(
+				todo_actions(&test_context),
+				vec![Action::Pick, Action::Fixup, Action::Fixup, Action::Pick]
 			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			test_context.handle_input(&mut module);
+			assert_eq!(todo_actions(&test_context), vec![Action::Pick; 4]);
 		},
 	);
 }
 
 #[test]
 #[serial_test::serial]
-fn visual_mode_help_input() {
+fn count_action_auto_select_next() {
 	process_module_test(
-		&["pick aaa c1"],
+		REGISTER_TODO,
 		ViewState::default(),
-		&[Input::Help, Input::SwapSelectedDown],
+		&[Input::Character('2'), Input::ActionDrop],
 		|mut test_context: TestContext<'_>| {
-			let mut module = List::new(test_context.config);
-			module.state = ListState::Visual;
+			let mut config = test_context.config.clone();
+			config.auto_select_next = true;
+			let mut module = List::new(&config);
 			test_context.handle_all_inputs(&mut module);
-			assert!(!module.visual_mode_help.is_active());
+			assert_eq!(
+				todo_actions(&test_context),
+				vec![Action::Drop, Action::Drop, Action::Pick, Action::Pick]
+			);
+			assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
 		},
 	);
 }
 
-// this can technically never happen, but it's worth testing, just in case of an invalid state
 #[test]
 #[serial_test::serial]
-fn render_noop_list() {
+fn count_action_filtered() {
+	let mut inputs = filter_inputs('1', "pick");
+	inputs.extend(&[Input::Character('2'), Input::ActionSquash]);
 	process_module_test(
-		&["break"],
+		&["pick aaa c1", "drop bbb c2", "pick ccc c3", "pick ddd c4"],
 		ViewState::default(),
-		&[],
+		&inputs,
 		|mut test_context: TestContext<'_>| {
 			let mut module = List::new(test_context.config);
-			test_context.rebase_todo_file.remove_lines(0, 0);
-			test_context.rebase_todo_file.add_line(0, Line::new("noop").unwrap());
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				view_data,
-				"{TITLE}{HELP}",
-				"{BODY}",
-				"{Normal(selected)} > {Normal(selected)}noop   "
+			test_context.handle_all_inputs(&mut module);
+			assert_eq!(
+				todo_actions(&test_context),
+				vec![Action::Squash, Action::Drop, Action::Squash, Action::Pick]
 			);
 		},
 	);
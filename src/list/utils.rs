@@ -4,6 +4,7 @@ use crate::{
 	config::key_bindings::KeyBindings,
 	constants::MINIMUM_FULL_WINDOW_WIDTH,
 	display::display_color::DisplayColor,
+	list::search::get_match_ranges,
 	todo_file::{action::Action, line::Line},
 	view::line_segment::LineSegment,
 };
@@ -91,6 +92,30 @@ pub(super) fn get_list_normal_mode_help_lines(key_bindings: &KeyBindings) -> Vec
 			key_bindings.action_drop.clone(),
 			String::from("Set selected commit to be dropped"),
 		),
+		(
+			key_bindings.cycle_fixup_message.clone(),
+			String::from("Cycle fixup message option (-C, -c)"),
+		),
+		(
+			key_bindings.autosquash.clone(),
+			String::from("Move fixup, squash and amend commits under their targets"),
+		),
+		(
+			key_bindings.absorb.clone(),
+			String::from("Fixup selected commit into the commit it changes"),
+		),
+		(
+			key_bindings.drop_redundant.clone(),
+			String::from("Drop all empty and already upstream commits"),
+		),
+		(
+			key_bindings.insert_exec_template.clone(),
+			String::from("Insert an exec template after each commit"),
+		),
+		(
+			key_bindings.remove_exec_template.clone(),
+			String::from("Remove the exec lines of an exec template"),
+		),
 		(key_bindings.edit.clone(), String::from("Edit an exec action's command")),
 		(key_bindings.insert_line.clone(), String::from("Insert a new line")),
 		(
@@ -102,6 +127,27 @@ pub(super) fn get_list_normal_mode_help_lines(key_bindings: &KeyBindings) -> Vec
 			key_bindings.redo.clone(),
 			String::from("Redo the previous undone change"),
 		),
+		(key_bindings.show_history.clone(), String::from("Show the undo history")),
+		(
+			key_bindings.toggle_graph.clone(),
+			String::from("Toggle the branch graph"),
+		),
+		(key_bindings.search.clone(), String::from("Search the todo list")),
+		(
+			key_bindings.search_backward.clone(),
+			String::from("Search the todo list backwards"),
+		),
+		(key_bindings.search_next.clone(), String::from("Move to the next match")),
+		(key_bindings.search_previous.clone(), String::from("Move to the previous match")),
+		(key_bindings.filter.clone(), String::from("Filter the todo list, or remove the filter")),
+		(key_bindings.cut.clone(), String::from("Cut the selected line, to move it with a paste")),
+		(key_bindings.yank.clone(), String::from("Copy the selected line")),
+		(key_bindings.paste_after.clone(), String::from("Paste below the selected line")),
+		(key_bindings.paste_before.clone(), String::from("Paste above the selected line")),
+		(
+			key_bindings.select_register.clone(),
+			String::from("Choose the register for the next cut, copy or paste"),
+		),
 		(
 			key_bindings.open_in_external_editor.clone(),
 			String::from("Open the todo file in the default editor"),
@@ -109,6 +155,19 @@ pub(super) fn get_list_normal_mode_help_lines(key_bindings: &KeyBindings) -> Vec
 	]
 }
 
+// templates past the available option keys can not be chosen
+pub(super) fn get_exec_template_options(templates: &[(String, String)]) -> Vec<(Option<usize>, char, String)> {
+	let mut keys = "123456789abcdefghijklmnopqrstuvwxyz".chars();
+	let mut options: Vec<(Option<usize>, char, String)> = templates
+		.iter()
+		.enumerate()
+		.zip(keys.by_ref())
+		.map(|((index, &(ref name, ref command)), key)| (Some(index), key, format!("{} ({})", name, command)))
+		.collect();
+	options.push((None, keys.next().unwrap_or('0'), String::from("Return to the list")));
+	options
+}
+
 pub(super) fn get_list_visual_mode_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec<String>, String)> {
 	vec![
 		(key_bindings.move_up.clone(), String::from("Move selection up")),
@@ -170,15 +229,47 @@ pub(super) fn get_list_visual_mode_help_lines(key_bindings: &KeyBindings) -> Vec
 			key_bindings.action_drop.clone(),
 			String::from("Set selected commits to be dropped"),
 		),
+		(
+			key_bindings.cycle_fixup_message.clone(),
+			String::from("Cycle fixup message option (-C, -c)"),
+		),
 		(
 			key_bindings.remove_line.clone(),
 			String::from("Completely remove the selected lines"),
 		),
+		(
+			key_bindings.sort_selection.clone(),
+			String::from("Sort the selected commits"),
+		),
+		(
+			key_bindings.absorb.clone(),
+			String::from("Fixup selected commits into the commits they change"),
+		),
+		(
+			key_bindings.insert_exec_template.clone(),
+			String::from("Insert an exec template after each selected commit"),
+		),
 		(key_bindings.undo.clone(), String::from("Undo the last change")),
 		(
 			key_bindings.redo.clone(),
 			String::from("Redo the previous undone change"),
 		),
+		(key_bindings.show_history.clone(), String::from("Show the undo history")),
+		(
+			key_bindings.toggle_graph.clone(),
+			String::from("Toggle the branch graph"),
+		),
+		(key_bindings.search_next.clone(), String::from("Move to the next match")),
+		(key_bindings.search_previous.clone(), String::from("Move to the previous match")),
+		(
+			key_bindings.cut.clone(),
+			String::from("Cut the selected lines, to move them with a paste"),
+		),
+		(key_bindings.yank.clone(), String::from("Copy the selected lines")),
+		(
+			key_bindings.select_register.clone(),
+			String::from("Choose the register for the next cut or copy"),
+		),
 		(
 			key_bindings.toggle_visual_mode.clone(),
 			String::from("Exit visual mode"),
@@ -199,70 +290,316 @@ const fn get_action_color(action: Action) -> DisplayColor {
 		Action::Label => DisplayColor::ActionLabel,
 		Action::Reset => DisplayColor::ActionReset,
 		Action::Merge => DisplayColor::ActionMerge,
+		Action::UpdateRef => DisplayColor::ActionUpdateRef,
 		// this is technically impossible, since noops should never be rendered
 		Action::Noop => DisplayColor::Normal,
 	}
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum LineMarker {
+	Problem,
+	Conflict,
+	// the dry run could not apply the line, so the lines after it were not checked
+	Unchecked,
+	Upstream,
+	Empty,
+	// the line is above a commit that it depends on
+	Dependency,
+	// the line was cut and is moved by the next paste
+	Cut,
+}
+
+impl LineMarker {
+	const fn to_indicator(self) -> &'static str {
+		match self {
+			Self::Problem => "!",
+			Self::Conflict => "x",
+			Self::Unchecked => "?",
+			Self::Upstream => "=",
+			Self::Empty => "_",
+			Self::Dependency => "^",
+			Self::Cut => "-",
+		}
+	}
+}
+
+// the matches of a search are underlined, so they stand out from the rest of the line
+fn push_search_segments(segments: &mut Vec<LineSegment>, text: &str, color: DisplayColor, search_term: Option<&str>) {
+	let mut start = 0;
+	for range in get_match_ranges(text, search_term.unwrap_or("")) {
+		if range.start > start {
+			segments.push(LineSegment::new_with_color(&text[start..range.start], color));
+		}
+		segments.push(LineSegment::new_with_color_and_style(
+			&text[range.clone()],
+			DisplayColor::IndicatorColor,
+			false,
+			true,
+			false,
+		));
+		start = range.end;
+	}
+	if start < text.len() || text.is_empty() {
+		segments.push(LineSegment::new_with_color(&text[start..], color));
+	}
+}
+
+// only the start of the hash is shown, so a hash that matches a search is underlined as a whole
+fn get_hash_segment(line: &Line, width: usize, search_term: Option<&str>) -> LineSegment {
+	let max_index = cmp::min(line.get_hash().len(), width);
+	let text = format!("{:width$} ", &line.get_hash()[0..max_index], width = width);
+	if search_term.map_or(false, |term| !get_match_ranges(line.get_hash(), term).is_empty()) {
+		LineSegment::new_with_color_and_style(text.as_str(), DisplayColor::IndicatorColor, false, true, false)
+	}
+	else {
+		LineSegment::new(text.as_str())
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct TodoLineOptions<'o> {
+	pub(super) graph_gutter: Option<&'o str>,
+	pub(super) is_cursor_line: bool,
+	pub(super) is_orphaned_fixup: bool,
+	pub(super) marker: Option<LineMarker>,
+	pub(super) search_term: Option<&'o str>,
+	pub(super) selected: bool,
+}
+
 pub(super) fn get_todo_line_segments(
 	line: &Line,
-	is_cursor_line: bool,
-	selected: bool,
+	options: &TodoLineOptions<'_>,
 	view_width: usize,
 ) -> Vec<LineSegment> {
+	let TodoLineOptions {
+		graph_gutter,
+		is_cursor_line,
+		is_orphaned_fixup,
+		marker,
+		search_term,
+		selected,
+	} = *options;
 	let mut segments: Vec<LineSegment> = vec![];
+	// a fixup without a target commit would be applied to whatever commit comes before it
+	let content_color = if is_orphaned_fixup {
+		DisplayColor::WarningColor
+	}
+	else {
+		DisplayColor::Normal
+	};
 
 	let action = line.get_action();
 
+	let indicator_color = if marker.is_some() {
+		DisplayColor::WarningColor
+	}
+	else {
+		DisplayColor::Normal
+	};
+
 	if view_width >= MINIMUM_FULL_WINDOW_WIDTH {
 		segments.push(LineSegment::new_with_color_and_style(
-			if is_cursor_line || selected { " > " } else { "   " },
-			DisplayColor::Normal,
+			format!(
+				"{}{} ",
+				marker.map_or(" ", LineMarker::to_indicator),
+				if is_cursor_line || selected { ">" } else { " " }
+			)
+			.as_str(),
+			indicator_color,
 			!is_cursor_line && selected,
 			false,
 			false,
 		));
 
+		if let Some(graph_gutter) = graph_gutter {
+			segments.push(LineSegment::new_with_color(graph_gutter, DisplayColor::IndicatorColor));
+		}
+
 		segments.push(LineSegment::new_with_color(
 			format!("{:6} ", action.as_string()).as_str(),
 			get_action_color(*action),
 		));
 
+		if let Some(option) = line.get_option() {
+			segments.push(LineSegment::new_with_color(
+				format!("{} ", option.as_string()).as_str(),
+				get_action_color(*action),
+			));
+		}
+
 		match *action {
 			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
-				let max_index = cmp::min(line.get_hash().len(), 8);
-				segments.push(LineSegment::new(
-					format!("{:8} ", line.get_hash()[0..max_index].to_string()).as_str(),
-				));
+				segments.push(get_hash_segment(line, 8, search_term));
+			},
+			Action::Merge if line.has_reference() => {
+				segments.push(get_hash_segment(line, 8, search_term));
 			},
-			Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::Break | Action::Noop => {},
+			Action::Exec
+			| Action::Label
+			| Action::Reset
+			| Action::Merge
+			| Action::Break
+			| Action::Noop
+			| Action::UpdateRef => {},
+		}
+		push_search_segments(&mut segments, line.get_content(), content_color, search_term);
+		if !line.get_comment().is_empty() {
+			segments.push(LineSegment::new_with_color_and_style(
+				format!(" # {}", line.get_comment()).as_str(),
+				DisplayColor::Normal,
+				true,
+				false,
+				false,
+			));
 		}
-		segments.push(LineSegment::new(line.get_content()));
 	}
 	else {
+		// there is only one column for the cursor and the marker, so the marker of a selected line takes the place of
+		// the space after the action
+		let is_selected = is_cursor_line || selected;
 		segments.push(LineSegment::new_with_color_and_style(
-			if is_cursor_line || selected { ">" } else { " " },
-			DisplayColor::Normal,
+			if is_selected {
+				">"
+			}
+			else {
+				marker.map_or(" ", LineMarker::to_indicator)
+			},
+			if is_selected { DisplayColor::Normal } else { indicator_color },
 			!is_cursor_line && selected,
 			false,
 			false,
 		));
 
-		segments.push(LineSegment::new_with_color(
-			format!("{:1} ", line.get_action().to_abbreviation()).as_str(),
-			get_action_color(*action),
-		));
+		if let Some(graph_gutter) = graph_gutter {
+			segments.push(LineSegment::new_with_color(graph_gutter, DisplayColor::IndicatorColor));
+		}
+
+		if let Some(marker) = marker.filter(|_| is_selected) {
+			segments.push(LineSegment::new_with_color(
+				format!("{:1}", line.get_action().to_abbreviation()).as_str(),
+				get_action_color(*action),
+			));
+			segments.push(LineSegment::new_with_color(marker.to_indicator(), DisplayColor::WarningColor));
+		}
+		else {
+			segments.push(LineSegment::new_with_color(
+				format!("{:1} ", line.get_action().to_abbreviation()).as_str(),
+				get_action_color(*action),
+			));
+		}
+
+		if let Some(option) = line.get_option() {
+			segments.push(LineSegment::new_with_color(
+				format!("{} ", option.as_string()).as_str(),
+				get_action_color(*action),
+			));
+		}
 
 		match *action {
 			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
-				let max_index = cmp::min(line.get_hash().len(), 3);
-				segments.push(LineSegment::new(
-					format!("{:3} ", line.get_hash()[0..max_index].to_string()).as_str(),
-				));
+				segments.push(get_hash_segment(line, 3, search_term));
 			},
-			Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::Break | Action::Noop => {},
+			Action::Merge if line.has_reference() => {
+				segments.push(get_hash_segment(line, 3, search_term));
+			},
+			Action::Exec
+			| Action::Label
+			| Action::Reset
+			| Action::Merge
+			| Action::Break
+			| Action::Noop
+			| Action::UpdateRef => {},
+		}
+		push_search_segments(&mut segments, line.get_content(), content_color, search_term);
+		if !line.get_comment().is_empty() {
+			segments.push(LineSegment::new_with_color_and_style(
+				format!(" # {}", line.get_comment()).as_str(),
+				DisplayColor::Normal,
+				true,
+				false,
+				false,
+			));
 		}
-		segments.push(LineSegment::new(line.get_content()));
 	}
 	segments
 }
+
+pub(super) fn get_done_line_segments(
+	line: &Line,
+	is_cursor_line: bool,
+	is_stopping_point: bool,
+	view_width: usize,
+) -> Vec<LineSegment> {
+	let is_full_width = view_width >= MINIMUM_FULL_WINDOW_WIDTH;
+	// applied lines are read only, so they are dimmed, except for the line where the rebase stopped
+	let color = if is_stopping_point {
+		DisplayColor::IndicatorColor
+	}
+	else {
+		DisplayColor::Normal
+	};
+	let dimmed = !is_stopping_point;
+	let mut segments = vec![LineSegment::new_with_color_and_style(
+		match (is_full_width, is_cursor_line) {
+			(true, true) => " > ",
+			(true, false) => "   ",
+			(false, true) => ">",
+			(false, false) => " ",
+		},
+		DisplayColor::Normal,
+		false,
+		false,
+		false,
+	)];
+
+	let mut text = if is_full_width {
+		format!("{:6} ", line.get_action().as_string())
+	}
+	else {
+		format!("{:1} ", line.get_action().to_abbreviation())
+	};
+	if let Some(option) = line.get_option() {
+		text.push_str(format!("{} ", option.as_string()).as_str());
+	}
+	if line.has_reference() {
+		let hash_width = if is_full_width { 8 } else { 3 };
+		let max_index = cmp::min(line.get_hash().len(), hash_width);
+		text.push_str(format!("{:width$} ", &line.get_hash()[0..max_index], width = hash_width).as_str());
+	}
+	segments.push(LineSegment::new_with_color_and_style(
+		text.as_str(),
+		color,
+		dimmed,
+		false,
+		false,
+	));
+	segments.push(LineSegment::new_with_color_and_style(
+		line.get_content(),
+		color,
+		dimmed,
+		false,
+		false,
+	));
+	if !line.get_comment().is_empty() {
+		segments.push(LineSegment::new_with_color_and_style(
+			format!(" # {}", line.get_comment()).as_str(),
+			color,
+			true,
+			false,
+			false,
+		));
+	}
+	if is_stopping_point {
+		segments.push(LineSegment::new_with_color(" (stopped here)", DisplayColor::IndicatorColor));
+	}
+	segments
+}
+
+// comments, blank lines and unknown lines are written back as they were read, so they are dimmed and never selected
+pub(super) fn get_kept_line_segments(kept_line: &str, view_width: usize) -> Vec<LineSegment> {
+	vec![
+		LineSegment::new(if view_width >= MINIMUM_FULL_WINDOW_WIDTH { "   " } else { " " }),
+		LineSegment::new_with_color_and_style(kept_line, DisplayColor::Normal, true, false, false),
+	]
+}
@@ -1,50 +1,19 @@
-// Make rustc's built-in lints more strict and set clippy into a whitelist-based configuration
-#![deny(
-	warnings,
-	nonstandard_style,
-	unused,
-	future_incompatible,
-	rust_2018_idioms,
-	unsafe_code
-)]
-#![deny(clippy::all, clippy::cargo, clippy::nursery, clippy::pedantic, clippy::restriction)]
-#![allow(clippy::blanket_clippy_restriction_lints)]
-#![allow(clippy::as_conversions)]
-#![allow(clippy::blocks_in_if_conditions)] // sometimes rustfmt makes blocks out of simple statements
-#![allow(clippy::cast_possible_truncation)]
-#![allow(clippy::cast_sign_loss)]
-#![allow(clippy::else_if_without_else)]
-#![allow(clippy::expect_used)]
-#![allow(clippy::float_arithmetic)]
-#![allow(clippy::implicit_return)]
-#![allow(clippy::indexing_slicing)]
-#![allow(clippy::integer_arithmetic)]
-#![allow(clippy::integer_division)]
-#![allow(clippy::missing_docs_in_private_items)]
-#![allow(clippy::module_name_repetitions)]
-#![allow(clippy::non_ascii_literal)]
-#![allow(clippy::panic)]
-#![allow(clippy::struct_excessive_bools)]
-#![allow(clippy::too_many_lines)]
-#![allow(clippy::unwrap_used)]
-#![allow(clippy::wildcard_enum_match_arm)]
-#![allow(clippy::similar_names)]
-#![allow(clippy::unreachable)]
-#![allow(clippy::missing_panics_doc)]
-
 mod components;
 mod config;
 mod confirm_abort;
 mod confirm_rebase;
 mod constants;
 mod display;
+mod dry_run;
 mod external_editor;
 mod input;
 mod insert;
 mod list;
 mod process;
+mod restore_autosave;
 mod show_commit;
 mod todo_file;
+mod undo_history;
 mod view;
 
 #[cfg(test)]
@@ -142,6 +111,7 @@ fn try_main(filepath: &str) -> Result<ExitStatus, Exit> {
 	})?;
 
 	let mut todo_file = TodoFile::new(filepath, config.undo_limit, config.git.comment_char.as_str());
+	todo_file.set_abbreviate_commands(config.git.abbreviate_commands);
 	todo_file.load_file().map_err(|err| {
 		Exit {
 			message: err.to_string(),
@@ -23,7 +23,7 @@ use crate::{
 		state::State,
 		window_size_error::WindowSizeError,
 	},
-	todo_file::TodoFile,
+	todo_file::{line::Line, TodoFile},
 	view::View,
 };
 
@@ -48,12 +48,17 @@ impl<'r> Process<'r> {
 		if self.view.start().is_err() {
 			return Ok(Some(ExitStatus::StateError));
 		}
+		if self.rebase_todo.has_autosave() {
+			self.state = State::RestoreAutosave;
+		}
+		let initial_state = self.state;
 		let view_width = self.view.get_view_size().width();
 		let view_height = self.view.get_view_size().height();
 		if WindowSizeError::is_window_too_small(view_width, view_height) {
 			self.handle_process_result(&mut modules, &ProcessResult::new().state(State::WindowSizeError));
 		}
-		self.activate(&mut modules, State::List);
+		self.activate(&mut modules, initial_state);
+		let mut autosaved_lines = self.rebase_todo.get_lines_owned();
 		while self.exit_status.is_none() {
 			if self
 				.view
@@ -65,6 +70,7 @@ impl<'r> Process<'r> {
 			}
 			let result = modules.handle_input(self.state, &mut self.view, &mut self.rebase_todo);
 			self.handle_process_result(&mut modules, &result);
+			self.autosave(&mut autosaved_lines);
 		}
 		if self.view.end().is_err() {
 			return Ok(Some(ExitStatus::StateError));
@@ -74,9 +80,20 @@ impl<'r> Process<'r> {
 				self.rebase_todo.write_file()?;
 			}
 		}
+		self.rebase_todo.remove_autosave()?;
 		Ok(self.exit_status)
 	}
 
+	// keep a copy of the edited lines next to the todo file, so they can be restored if the process is killed
+	fn autosave(&mut self, autosaved_lines: &mut Vec<Line>) {
+		if self.exit_status.is_some() || self.rebase_todo.iter().eq(autosaved_lines.iter()) {
+			return;
+		}
+		if self.rebase_todo.write_autosave().is_ok() {
+			*autosaved_lines = self.rebase_todo.get_lines_owned();
+		}
+	}
+
 	fn handle_process_result(&mut self, modules: &mut Modules<'_>, result: &ProcessResult) -> bool {
 		let previous_state = self.state;
 
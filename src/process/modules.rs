@@ -12,8 +12,10 @@ use crate::{
 		state::State,
 		window_size_error::WindowSizeError,
 	},
+	restore_autosave::RestoreAutosave,
 	show_commit::ShowCommit,
 	todo_file::TodoFile,
+	undo_history::UndoHistory,
 	view::{view_data::ViewData, View},
 };
 
@@ -24,7 +26,9 @@ pub struct Modules<'m> {
 	pub external_editor: ExternalEditor,
 	pub insert: Insert,
 	pub list: List<'m>,
+	pub restore_autosave: RestoreAutosave,
 	pub show_commit: ShowCommit<'m>,
+	pub undo_history: UndoHistory,
 	pub window_size_error: WindowSizeError,
 }
 
@@ -32,12 +36,18 @@ impl<'m> Modules<'m> {
 	pub fn new(config: &'m Config) -> Self {
 		Modules {
 			confirm_abort: ConfirmAbort::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
-			confirm_rebase: ConfirmRebase::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
+			confirm_rebase: ConfirmRebase::new(
+				&config.key_bindings.confirm_yes,
+				&config.key_bindings.confirm_no,
+				config.git.missing_commits_check,
+			),
 			error: Error::new(),
 			external_editor: ExternalEditor::new(config.git.editor.as_str()),
-			insert: Insert::new(),
+			insert: Insert::new(config.git.abbrev),
 			list: List::new(config),
+			restore_autosave: RestoreAutosave::new(),
 			show_commit: ShowCommit::new(config),
+			undo_history: UndoHistory::new(),
 			window_size_error: WindowSizeError::new(),
 		}
 	}
@@ -50,7 +60,9 @@ impl<'m> Modules<'m> {
 			State::ExternalEditor => &mut self.external_editor as &mut dyn ProcessModule,
 			State::Insert => &mut self.insert as &mut dyn ProcessModule,
 			State::List => &mut self.list as &mut dyn ProcessModule,
+			State::RestoreAutosave => &mut self.restore_autosave as &mut dyn ProcessModule,
 			State::ShowCommit => &mut self.show_commit as &mut dyn ProcessModule,
+			State::UndoHistory => &mut self.undo_history as &mut dyn ProcessModule,
 			State::WindowSizeError => &mut self.window_size_error as &mut dyn ProcessModule,
 		}
 	}
@@ -96,7 +108,9 @@ mod tests {
 		case::external_editor(State::ExternalEditor),
 		case::insert(State::Insert),
 		case::list(State::List),
+		case::restore_autosave(State::RestoreAutosave),
 		case::show_commit(State::ShowCommit),
+		case::undo_history(State::UndoHistory),
 		case::window_size_error(State::WindowSizeError)
 	)]
 	#[serial_test::serial]
@@ -6,6 +6,8 @@ pub enum State {
 	ExternalEditor,
 	List,
 	Insert,
+	RestoreAutosave,
 	ShowCommit,
+	UndoHistory,
 	WindowSizeError,
 }
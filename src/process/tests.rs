@@ -4,7 +4,7 @@ use super::*;
 use crate::{
 	display::size::Size,
 	process::testutil::{process_module_test, TestContext, ViewState},
-	todo_file::line::Line,
+	todo_file::{action::Action, edit_content::EditContext, line::Line},
 };
 
 #[test]
@@ -62,6 +62,51 @@ fn force_rebase() {
 	);
 }
 
+#[test]
+#[serial_test::serial]
+fn autosave_restore_on_start() {
+	process_module_test(
+		&["pick aaa comment", "pick bbb comment"],
+		ViewState::default(),
+		&[Input::Character('1'), Input::ForceRebase],
+		|mut test_context: TestContext<'_>| {
+			test_context.rebase_todo_file.remove_lines(0, 0);
+			test_context.rebase_todo_file.write_autosave().unwrap();
+			let autosave_path = test_context.rebase_todo_file.get_autosave_path();
+			test_context.rebase_todo_file.undo();
+			let mut process = Process::new(test_context.rebase_todo_file, test_context.view);
+			let modules = Modules::new(test_context.config);
+			assert_eq!(process.run(modules).unwrap().unwrap(), ExitStatus::Good);
+			process.rebase_todo.load_file().unwrap();
+			assert_eq!(process.rebase_todo.get_lines_owned(), vec![Line::new(
+				"pick bbb comment"
+			)
+			.unwrap()]);
+			assert!(!std::path::Path::new(autosave_path.as_str()).exists());
+		},
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn autosave_on_change() {
+	process_module_test(
+		&["pick aaa comment", "pick bbb comment"],
+		ViewState::default(),
+		&[],
+		|test_context: TestContext<'_>| {
+			let mut process = Process::new(test_context.rebase_todo_file, test_context.view);
+			let mut autosaved_lines = process.rebase_todo.get_lines_owned();
+			process.rebase_todo.update_range(0, 0, &EditContext::new().action(Action::Drop));
+			process.autosave(&mut autosaved_lines);
+			assert_eq!(autosaved_lines, process.rebase_todo.get_lines_owned());
+			let contents = std::fs::read_to_string(process.rebase_todo.get_autosave_path()).unwrap();
+			assert!(contents.ends_with("drop aaa comment\npick bbb comment\n"));
+			process.rebase_todo.remove_autosave().unwrap();
+		},
+	);
+}
+
 #[test]
 #[serial_test::serial]
 fn error_write_todo() {
@@ -1,4 +1,4 @@
-use std::{cell::Cell, env::set_var, path::Path};
+use std::{cell::Cell, env::set_var, fs::write, path::Path};
 
 use anyhow::Error;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -7,10 +7,19 @@ use tempfile::{Builder, NamedTempFile};
 use crate::{
 	config::{key_bindings::KeyBindings, Config},
 	create_key_event,
-	display::{size::Size, CrossTerm, Display, Event, KeyEvent},
-	input::{input_handler::InputHandler, Input},
+	display::{
+		size::Size,
+		testutil::_create_mouse_event,
+		CrossTerm,
+		Display,
+		Event,
+		KeyEvent,
+		MouseButton,
+		MouseEventKind,
+	},
+	input::{input_handler::InputHandler, Input, MouseAction},
 	process::{exit_status::ExitStatus, process_module::ProcessModule, process_result::ProcessResult, state::State},
-	todo_file::{line::Line, TodoFile},
+	todo_file::TodoFile,
 	view::{view_data::ViewData, View},
 };
 
@@ -98,7 +107,10 @@ impl Default for ViewState {
 fn map_str_to_event(input: &str) -> Event {
 	match input {
 		"Backspace" => create_key_event!(code KeyCode::Backspace),
+		"BackTab" => create_key_event!(code KeyCode::BackTab),
+		"Tab" => create_key_event!(code KeyCode::Tab),
 		"Enter" => create_key_event!(code KeyCode::Enter),
+		"Esc" => create_key_event!(code KeyCode::Esc),
 		"Delete" => create_key_event!(code KeyCode::Delete),
 		"End" => create_key_event!(code KeyCode::End),
 		"Home" => create_key_event!(code KeyCode::Home),
@@ -128,6 +140,7 @@ fn map_str_to_event(input: &str) -> Event {
 fn map_input_to_event(key_bindings: &KeyBindings, input: Input) -> Event {
 	match input {
 		Input::Abort => map_str_to_event(key_bindings.abort.first().unwrap().as_str()),
+		Input::Absorb => map_str_to_event(key_bindings.absorb.first().unwrap().as_str()),
 		Input::ActionBreak => map_str_to_event(key_bindings.action_break.first().unwrap().as_str()),
 		Input::ActionDrop => map_str_to_event(key_bindings.action_drop.first().unwrap().as_str()),
 		Input::ActionEdit => map_str_to_event(key_bindings.action_edit.first().unwrap().as_str()),
@@ -135,18 +148,40 @@ fn map_input_to_event(key_bindings: &KeyBindings, input: Input) -> Event {
 		Input::ActionPick => map_str_to_event(key_bindings.action_pick.first().unwrap().as_str()),
 		Input::ActionReword => map_str_to_event(key_bindings.action_reword.first().unwrap().as_str()),
 		Input::ActionSquash => map_str_to_event(key_bindings.action_squash.first().unwrap().as_str()),
+		Input::Autosquash => map_str_to_event(key_bindings.autosquash.first().unwrap().as_str()),
 		Input::Backspace => map_str_to_event("Backspace"),
+		Input::BackTab => map_str_to_event("BackTab"),
 		Input::Character(c) => map_str_to_event(String::from(c).as_str()),
+		Input::Mouse(mouse) => {
+			_create_mouse_event(
+				match mouse.get_action() {
+					MouseAction::Press => MouseEventKind::Down(MouseButton::Left),
+					MouseAction::Drag => MouseEventKind::Drag(MouseButton::Left),
+					MouseAction::Release => MouseEventKind::Up(MouseButton::Left),
+					MouseAction::ScrollDown => MouseEventKind::ScrollDown,
+					MouseAction::ScrollUp => MouseEventKind::ScrollUp,
+				},
+				mouse.get_column() as u16,
+				mouse.get_row() as u16,
+				&if mouse.is_shift() { vec![String::from("Shift")] } else { vec![] },
+			)
+		},
+		Input::Cut => map_str_to_event(key_bindings.cut.first().unwrap().as_str()),
+		Input::CycleFixupMessage => map_str_to_event(key_bindings.cycle_fixup_message.first().unwrap().as_str()),
 		Input::Delete => map_str_to_event("Delete"),
 		Input::Down | Input::ScrollDown => map_str_to_event("Down"),
+		Input::DropRedundant => map_str_to_event(key_bindings.drop_redundant.first().unwrap().as_str()),
 		Input::Edit => map_str_to_event(key_bindings.edit.first().unwrap().as_str()),
 		Input::End | Input::ScrollBottom => map_str_to_event("End"),
 		Input::Enter => map_str_to_event("Enter"),
+		Input::Escape => map_str_to_event("Esc"),
 		Input::Exit => map_str_to_event("Exit"),
+		Input::Filter => map_str_to_event(key_bindings.filter.first().unwrap().as_str()),
 		Input::ForceAbort => map_str_to_event(key_bindings.force_abort.first().unwrap().as_str()),
 		Input::ForceRebase => map_str_to_event(key_bindings.force_rebase.first().unwrap().as_str()),
 		Input::Help => map_str_to_event(key_bindings.help.first().unwrap().as_str()),
 		Input::Home | Input::ScrollTop => map_str_to_event("Home"),
+		Input::InsertExecTemplate => map_str_to_event(key_bindings.insert_exec_template.first().unwrap().as_str()),
 		Input::InsertLine => map_str_to_event(key_bindings.insert_line.first().unwrap().as_str()),
 		Input::Left | Input::ScrollLeft => map_str_to_event("Left"),
 		Input::MoveCursorDown => map_str_to_event(key_bindings.move_down.first().unwrap().as_str()),
@@ -162,17 +197,30 @@ fn map_input_to_event(key_bindings: &KeyBindings, input: Input) -> Event {
 		Input::Other => map_str_to_event("Other"),
 		Input::PageDown | Input::ScrollJumpDown => map_str_to_event("PageDown"),
 		Input::PageUp | Input::ScrollJumpUp => map_str_to_event("PageUp"),
+		Input::PasteAfter => map_str_to_event(key_bindings.paste_after.first().unwrap().as_str()),
+		Input::PasteBefore => map_str_to_event(key_bindings.paste_before.first().unwrap().as_str()),
 		Input::Rebase => map_str_to_event(key_bindings.rebase.first().unwrap().as_str()),
 		Input::Redo => map_str_to_event(key_bindings.redo.first().unwrap().as_str()),
+		Input::RemoveExecTemplate => map_str_to_event(key_bindings.remove_exec_template.first().unwrap().as_str()),
 		Input::Resize => map_str_to_event("Resize"),
 		Input::Right | Input::ScrollRight => map_str_to_event("Right"),
+		Input::Search => map_str_to_event(key_bindings.search.first().unwrap().as_str()),
+		Input::SearchBackward => map_str_to_event(key_bindings.search_backward.first().unwrap().as_str()),
+		Input::SearchNext => map_str_to_event(key_bindings.search_next.first().unwrap().as_str()),
+		Input::SearchPrevious => map_str_to_event(key_bindings.search_previous.first().unwrap().as_str()),
+		Input::SelectRegister => map_str_to_event(key_bindings.select_register.first().unwrap().as_str()),
 		Input::ShowCommit => map_str_to_event(key_bindings.show_commit.first().unwrap().as_str()),
 		Input::ShowDiff => map_str_to_event(key_bindings.show_diff.first().unwrap().as_str()),
+		Input::ShowHistory => map_str_to_event(key_bindings.show_history.first().unwrap().as_str()),
+		Input::SortSelection => map_str_to_event(key_bindings.sort_selection.first().unwrap().as_str()),
 		Input::SwapSelectedDown => map_str_to_event(key_bindings.move_selection_down.first().unwrap().as_str()),
 		Input::SwapSelectedUp => map_str_to_event(key_bindings.move_selection_up.first().unwrap().as_str()),
+		Input::Tab => map_str_to_event("Tab"),
+		Input::ToggleGraph => map_str_to_event(key_bindings.toggle_graph.first().unwrap().as_str()),
 		Input::ToggleVisualMode => map_str_to_event(key_bindings.toggle_visual_mode.first().unwrap().as_str()),
 		Input::Undo => map_str_to_event(key_bindings.undo.first().unwrap().as_str()),
 		Input::Up | Input::ScrollUp => map_str_to_event("Up"),
+		Input::Yank => map_str_to_event(key_bindings.yank.first().unwrap().as_str()),
 		Input::Yes => map_str_to_event(key_bindings.confirm_yes.first().unwrap().as_str()),
 		_ => {
 			panic!("Unsupported input: {:?}", input);
@@ -207,13 +255,16 @@ fn format_process_result(
 				State::ExternalEditor => "ExternalEditor",
 				State::Insert => "Insert",
 				State::List => "List",
+				State::RestoreAutosave => "RestoreAutosave",
 				State::ShowCommit => "ShowCommit",
+				State::UndoHistory => "UndoHistory",
 				State::WindowSizeError => "WindowSizeError",
 			}
 		}),
 		input.map_or(String::from("None"), |input| {
 			match input {
 				Input::Abort => String::from("Abort"),
+				Input::Absorb => String::from("Absorb"),
 				Input::ActionBreak => String::from("ActionBreak"),
 				Input::ActionDrop => String::from("ActionDrop"),
 				Input::ActionEdit => String::from("ActionEdit"),
@@ -221,22 +272,29 @@ fn format_process_result(
 				Input::ActionPick => String::from("ActionPick"),
 				Input::ActionReword => String::from("ActionReword"),
 				Input::ActionSquash => String::from("ActionSquash"),
+				Input::Autosquash => String::from("Autosquash"),
 				Input::Backspace => String::from("Backspace"),
 				Input::BackTab => String::from("BackTab"),
 				Input::Character(char) => String::from(char),
+				Input::Cut => String::from("Cut"),
+				Input::CycleFixupMessage => String::from("CycleFixupMessage"),
 				Input::Delete => String::from("Delete"),
 				Input::Down => String::from("Down"),
+				Input::DropRedundant => String::from("DropRedundant"),
 				Input::Edit => String::from("Edit"),
 				Input::End => String::from("End"),
 				Input::Enter => String::from("Enter"),
 				Input::Escape => String::from("Escape"),
 				Input::Exit => String::from("Exit"),
+				Input::Filter => String::from("Filter"),
 				Input::ForceAbort => String::from("ForceAbort"),
 				Input::ForceRebase => String::from("ForceRebase"),
 				Input::Help => String::from("Help"),
 				Input::Home => String::from("Home"),
 				Input::Ignore => String::from("Ignore"),
+				Input::Mouse(mouse) => format!("{:?}", mouse),
 				Input::Insert => String::from("Insert"),
+				Input::InsertExecTemplate => String::from("InsertExecTemplate"),
 				Input::InsertLine => String::from("InsertLine"),
 				Input::Kill => String::from("Kill"),
 				Input::Left => String::from("Left"),
@@ -253,8 +311,11 @@ fn format_process_result(
 				Input::Other => String::from("Other"),
 				Input::PageDown => String::from("PageDown"),
 				Input::PageUp => String::from("PageUp"),
+				Input::PasteAfter => String::from("PasteAfter"),
+				Input::PasteBefore => String::from("PasteBefore"),
 				Input::Rebase => String::from("Rebase"),
 				Input::Redo => String::from("Redo"),
+				Input::RemoveExecTemplate => String::from("RemoveExecTemplate"),
 				Input::Resize => String::from("Resize"),
 				Input::Right => String::from("Right"),
 				Input::ScrollBottom => String::from("ScrollBottom"),
@@ -265,14 +326,23 @@ fn format_process_result(
 				Input::ScrollRight => String::from("ScrollRight"),
 				Input::ScrollTop => String::from("ScrollTop"),
 				Input::ScrollUp => String::from("ScrollUp"),
+				Input::Search => String::from("Search"),
+				Input::SearchBackward => String::from("SearchBackward"),
+				Input::SearchNext => String::from("SearchNext"),
+				Input::SearchPrevious => String::from("SearchPrevious"),
+				Input::SelectRegister => String::from("SelectRegister"),
 				Input::ShowCommit => String::from("ShowCommit"),
 				Input::ShowDiff => String::from("ShowDiff"),
+				Input::ShowHistory => String::from("ShowHistory"),
+				Input::SortSelection => String::from("SortSelection"),
 				Input::SwapSelectedDown => String::from("SwapSelectedDown"),
 				Input::SwapSelectedUp => String::from("SwapSelectedUp"),
 				Input::Tab => String::from("Tab"),
+				Input::ToggleGraph => String::from("ToggleGraph"),
 				Input::ToggleVisualMode => String::from("ToggleVisualMode"),
 				Input::Undo => String::from("Undo"),
 				Input::Up => String::from("Up"),
+				Input::Yank => String::from("Yank"),
 				Input::Yes => String::from("Yes"),
 			}
 		}),
@@ -323,6 +393,9 @@ macro_rules! assert_process_result {
 	($actual:expr) => {
 		crate::process::testutil::_assert_process_result(&$actual, None, None, None, &None)
 	};
+	($actual:expr, error = $error:expr) => {
+		crate::process::testutil::_assert_process_result(&$actual, None, None, None, &Some($error))
+	};
 	($actual:expr, error = $error:expr, exit_status = $exit_status:expr) => {
 		crate::process::testutil::_assert_process_result(&$actual, None, None, Some($exit_status), &Some($error))
 	};
@@ -341,6 +414,12 @@ macro_rules! assert_process_result {
 	($actual:expr, input = $input:expr, exit_status = $exit_status:expr) => {
 		crate::process::testutil::_assert_process_result(&$actual, Some($input), None, Some($exit_status), &None)
 	};
+	($actual:expr, input = $input:expr, error = $error:expr) => {
+		crate::process::testutil::_assert_process_result(&$actual, Some($input), None, None, &Some($error))
+	};
+	($actual:expr, input = $input:expr, state = $state:expr, error = $error:expr) => {
+		crate::process::testutil::_assert_process_result(&$actual, Some($input), Some($state), None, &Some($error))
+	};
 }
 
 pub fn process_module_test<C>(lines: &[&str], view_state: ViewState, input: &[Input], callback: C)
@@ -356,6 +435,8 @@ where C: for<'p> FnOnce(TestContext<'p>) {
 	set_var("GIT_DIR", git_repo_dir.as_str());
 	let mut config = Config::new().unwrap();
 	config.git.editor = String::from("true");
+	// backwards search has no default binding
+	config.key_bindings.search_backward = vec![String::from("Z")];
 	let mut crossterm = CrossTerm::new();
 	crossterm.set_size(view_state.size);
 	CrossTerm::set_inputs(
@@ -374,7 +455,9 @@ where C: for<'p> FnOnce(TestContext<'p>) {
 		.unwrap();
 
 	let mut rebase_todo_file = TodoFile::new(todo_file.path().to_str().unwrap(), 1, "#");
-	rebase_todo_file.set_lines(lines.iter().map(|l| Line::new(l).unwrap()).collect());
+	// the lines are loaded from the file, so comments are kept with the line after them
+	write(todo_file.path(), lines.join("\n")).unwrap();
+	rebase_todo_file.load_file().unwrap();
 
 	callback(TestContext {
 		config: &config,
@@ -0,0 +1,5 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+	Discard,
+	Restore,
+}
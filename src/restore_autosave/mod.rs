@@ -0,0 +1,167 @@
+mod action;
+
+use crate::{
+	components::Choice,
+	display::display_color::DisplayColor,
+	input::input_handler::InputMode,
+	process::{process_module::ProcessModule, process_result::ProcessResult, state::State},
+	restore_autosave::action::Action,
+	todo_file::TodoFile,
+	view::{line_segment::LineSegment, view_data::ViewData, view_line::ViewLine, View},
+};
+
+pub struct RestoreAutosave {
+	choice: Choice<Action>,
+}
+
+impl ProcessModule for RestoreAutosave {
+	fn build_view_data(&mut self, view: &View<'_>, _: &TodoFile) -> &ViewData {
+		let view_size = view.get_view_size();
+		self.choice.get_view_data(view_size.width(), view_size.height())
+	}
+
+	fn handle_input(&mut self, view: &mut View<'_>, todo_file: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Default);
+		let mut result = ProcessResult::new().input(input);
+		if let Some(action) = self.choice.handle_input(input) {
+			let action_result = match *action {
+				Action::Restore => todo_file.restore_autosave(),
+				Action::Discard => todo_file.remove_autosave(),
+			};
+			result = match action_result {
+				Ok(_) => result.state(State::List),
+				Err(err) => result.error(err).state(State::List),
+			};
+		}
+		result
+	}
+}
+
+impl RestoreAutosave {
+	pub(crate) fn new() -> Self {
+		let mut choice = Choice::new(vec![
+			(Action::Restore, '1', String::from("Restore the unsaved changes")),
+			(Action::Discard, '2', String::from("Discard the unsaved changes")),
+		]);
+		choice.set_prompt(vec![
+			ViewLine::from(LineSegment::new_with_color(
+				"Unsaved changes from a previous session were found for this rebase.",
+				DisplayColor::IndicatorColor,
+			)),
+			ViewLine::from("The changes can be restored, or discarded to start from the original todo list."),
+		]);
+		Self { choice }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use anyhow::anyhow;
+
+	use super::*;
+	use crate::{
+		assert_process_result,
+		assert_rendered_output,
+		input::Input,
+		process::testutil::{process_module_test, TestContext, ViewState},
+		todo_file::line::Line,
+	};
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data() {
+		process_module_test(
+			&["pick aaa comment"],
+			ViewState::default(),
+			&[],
+			|test_context: TestContext<'_>| {
+				let mut module = RestoreAutosave::new();
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Unsaved changes from a previous session were found for this rebase.",
+					"{Normal}The changes can be restored, or discarded to start from the original todo list.",
+					"",
+					"{BODY}",
+					"{Normal}1) Restore the unsaved changes",
+					"{Normal}2) Discard the unsaved changes",
+					"",
+					"{IndicatorColor}Please choose an option."
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn restore() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment"],
+			ViewState::default(),
+			&[Input::Character('1')],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				test_context.rebase_todo_file.write_autosave().unwrap();
+				test_context
+					.rebase_todo_file
+					.set_lines(vec![Line::new("pick aaa comment").unwrap()]);
+				let mut module = RestoreAutosave::new();
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Character('1'),
+					state = State::List
+				);
+				assert_eq!(test_context.rebase_todo_file.get_lines_owned(), vec![Line::new(
+					"pick bbb comment"
+				)
+				.unwrap()]);
+				test_context.rebase_todo_file.remove_autosave().unwrap();
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn restore_error() {
+		process_module_test(
+			&["pick aaa comment"],
+			ViewState::default(),
+			&[Input::Character('1')],
+			|mut test_context: TestContext<'_>| {
+				let mut module = RestoreAutosave::new();
+				let todo_path = test_context.get_todo_file_path();
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Character('1'),
+					state = State::List,
+					error = anyhow!("No autosave found for: {}", todo_path)
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn discard() {
+		process_module_test(
+			&["pick aaa comment", "pick bbb comment"],
+			ViewState::default(),
+			&[Input::Character('2')],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(0, 0);
+				test_context.rebase_todo_file.write_autosave().unwrap();
+				let mut module = RestoreAutosave::new();
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Character('2'),
+					state = State::List
+				);
+				assert!(!Path::new(test_context.rebase_todo_file.get_autosave_path().as_str()).exists());
+			},
+		);
+	}
+}
@@ -0,0 +1,361 @@
+use std::{
+	cmp::Reverse,
+	collections::{HashMap, HashSet},
+	sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+	thread,
+};
+
+use git2::{Oid, Repository};
+
+use crate::show_commit::{
+	commit::{Commit, LoadCommitDiffOptions},
+	status::Status,
+};
+
+// the owner of each line of a file, as the index of the commit that last changed it, if it was changed by a commit
+type LineOwners = Vec<Option<usize>>;
+
+const DIFF_OPTIONS: LoadCommitDiffOptions = LoadCommitDiffOptions {
+	context_lines: 0,
+	copies: false,
+	ignore_whitespace: false,
+	ignore_whitespace_change: false,
+	interhunk_lines: 0,
+	rename_limit: 200,
+	renames: true,
+};
+
+// Commits are analysed from oldest to newest, following the parent of each commit, so that the line numbers of each
+// change match the lines left by the commits before it. Commits that do not continue the series are analysed in the
+// order of the hashes.
+fn get_history_order(repo: &Repository, hashes: &[&str]) -> Vec<usize> {
+	let commits: Vec<Option<(Oid, Option<Oid>)>> = hashes
+		.iter()
+		.map(|hash| {
+			let commit = repo.revparse_single(hash).ok()?.peel_to_commit().ok()?;
+			Some((commit.id(), commit.parent_id(0).ok()))
+		})
+		.collect();
+	let ids: HashMap<Oid, usize> = commits
+		.iter()
+		.enumerate()
+		.filter_map(|(index, commit)| commit.map(|(id, _)| (id, index)))
+		.collect();
+
+	let mut order = vec![];
+	let mut visited = HashSet::new();
+	for (index, commit) in commits.iter().enumerate() {
+		if let Some((_, parent_id)) = *commit {
+			if parent_id.map_or(true, |id| !ids.contains_key(&id)) {
+				let mut current = Some(index);
+				while let Some(current_index) = current {
+					if !visited.insert(current_index) {
+						break;
+					}
+					order.push(current_index);
+					let current_id = commits[current_index].map(|(id, _)| id);
+					current = commits
+						.iter()
+						.position(|commit| commit.map_or(false, |(_, parent)| parent == current_id));
+				}
+			}
+		}
+	}
+	for (index, commit) in commits.iter().enumerate() {
+		if commit.is_some() && visited.insert(index) {
+			order.push(index);
+		}
+	}
+	order
+}
+
+fn get_owners(owners: &mut LineOwners, start: usize, end: usize) -> HashSet<usize> {
+	if owners.len() < end {
+		owners.resize(end, None);
+	}
+	owners[start..end].iter().filter_map(|owner| *owner).collect()
+}
+
+fn apply_commit(index: usize, commit: &Commit, files: &mut HashMap<String, LineOwners>) -> HashSet<usize> {
+	let mut dependencies = HashSet::new();
+	for file_stat in commit.get_file_stats() {
+		let mut owners = files.remove(file_stat.get_from_name()).unwrap_or_default();
+		let mut deltas: Vec<_> = file_stat.deltas().iter().collect();
+		// later changes first, so the line numbers of the earlier changes stay valid
+		deltas.sort_by_key(|delta| Reverse(delta.old_start()));
+		for delta in deltas {
+			let old_start = delta.old_start() as usize;
+			let old_lines = delta.old_lines() as usize;
+			let new_lines = vec![Some(index); delta.new_lines() as usize];
+			if old_lines == 0 {
+				// an addition depends on the lines around it, it is inserted after the start line
+				dependencies.extend(get_owners(&mut owners, old_start.saturating_sub(1), old_start + 1));
+				let _removed: Vec<_> = owners.splice(old_start..old_start, new_lines).collect();
+			}
+			else {
+				dependencies.extend(get_owners(&mut owners, old_start - 1, old_start - 1 + old_lines));
+				let _removed: Vec<_> = owners
+					.splice((old_start - 1)..(old_start - 1 + old_lines), new_lines)
+					.collect();
+			}
+		}
+		if *file_stat.get_status() != Status::Deleted {
+			let _previous = files.insert(String::from(file_stat.get_to_name()), owners);
+		}
+	}
+	let _removed = dependencies.remove(&index);
+	dependencies
+}
+
+// Which commits each commit depends on, found by the commits that last changed the lines that it changes
+#[derive(Debug, Default)]
+pub(crate) struct Dependencies {
+	depends_on: HashMap<String, Vec<String>>,
+}
+
+impl Dependencies {
+	pub(crate) fn new() -> Self {
+		Self::default()
+	}
+
+	pub(crate) fn load(hashes: &[&str]) -> Self {
+		let mut depends_on = HashMap::new();
+		let repo = match Repository::open_from_env() {
+			Ok(repo) => repo,
+			Err(_) => return Self { depends_on },
+		};
+
+		let mut files = HashMap::new();
+		for index in get_history_order(&repo, hashes) {
+			// a commit that can not be loaded has no known dependencies
+			if let Ok(commit) = Commit::new_from_hash(hashes[index], DIFF_OPTIONS) {
+				let mut dependencies: Vec<usize> = apply_commit(index, &commit, &mut files).into_iter().collect();
+				dependencies.sort_unstable();
+				let _previous = depends_on.insert(
+					String::from(hashes[index]),
+					dependencies.into_iter().map(|dependency| String::from(hashes[dependency])).collect(),
+				);
+			}
+		}
+		Self { depends_on }
+	}
+
+	pub(crate) fn get_depends_on(&self, hash: &str) -> &[String] {
+		self.depends_on.get(hash).map_or(&[], Vec::as_slice)
+	}
+
+	pub(crate) fn get_depended_on_by(&self, hash: &str) -> Vec<&str> {
+		let mut dependents: Vec<&str> = self
+			.depends_on
+			.iter()
+			.filter(|&(_, dependencies)| dependencies.iter().any(|dependency| dependency == hash))
+			.map(|(dependent, _)| dependent.as_str())
+			.collect();
+		dependents.sort_unstable();
+		dependents
+	}
+}
+
+// Loads the dependencies in a background thread, since the diff of every commit is needed. Only the most recent hashes
+// are loaded, and a result for older hashes is ignored. The previous dependencies are kept until the new ones are
+// loaded.
+pub(crate) struct DependencyLoader {
+	dependencies: Dependencies,
+	generation: usize,
+	is_pending: bool,
+	loaded_hashes: Vec<String>,
+	receiver: Option<Receiver<(usize, Dependencies)>>,
+	sender: Option<Sender<(usize, Vec<String>)>>,
+}
+
+impl DependencyLoader {
+	pub(crate) fn new() -> Self {
+		Self {
+			dependencies: Dependencies::new(),
+			generation: 0,
+			is_pending: false,
+			loaded_hashes: vec![],
+			receiver: None,
+			sender: None,
+		}
+	}
+
+	fn start(&mut self) {
+		let (request_sender, request_receiver) = channel::<(usize, Vec<String>)>();
+		let (result_sender, result_receiver) = channel();
+		let _handle = thread::spawn(move || {
+			while let Ok(mut request) = request_receiver.recv() {
+				while let Ok(newer_request) = request_receiver.try_recv() {
+					request = newer_request;
+				}
+				let (generation, hashes) = request;
+				let hash_refs: Vec<&str> = hashes.iter().map(String::as_str).collect();
+				if result_sender.send((generation, Dependencies::load(&hash_refs))).is_err() {
+					break;
+				}
+			}
+		});
+		self.receiver = Some(result_receiver);
+		self.sender = Some(request_sender);
+	}
+
+	fn handle_result(&mut self, generation: usize, dependencies: Dependencies) {
+		if generation == self.generation {
+			self.dependencies = dependencies;
+			self.is_pending = false;
+		}
+	}
+
+	fn handle_disconnect(&mut self) {
+		self.is_pending = false;
+		self.receiver = None;
+		self.sender = None;
+	}
+
+	fn receive(&mut self) {
+		loop {
+			let result = match self.receiver {
+				Some(ref receiver) => receiver.try_recv(),
+				None => break,
+			};
+			match result {
+				Ok((generation, dependencies)) => self.handle_result(generation, dependencies),
+				Err(TryRecvError::Empty) => break,
+				Err(TryRecvError::Disconnected) => self.handle_disconnect(),
+			}
+		}
+	}
+
+	pub(crate) fn update(&mut self, hashes: &[String]) {
+		if self.sender.is_none() {
+			self.start();
+		}
+		self.receive();
+
+		if hashes == self.loaded_hashes.as_slice() {
+			return;
+		}
+		if let Some(ref sender) = self.sender {
+			self.generation += 1;
+			self.loaded_hashes = hashes.to_vec();
+			self.is_pending = sender.send((self.generation, self.loaded_hashes.clone())).is_ok();
+		}
+	}
+
+	#[cfg(test)]
+	pub(crate) fn wait(&mut self) {
+		while self.is_pending {
+			let result = match self.receiver {
+				Some(ref receiver) => receiver.recv(),
+				None => break,
+			};
+			match result {
+				Ok((generation, dependencies)) => self.handle_result(generation, dependencies),
+				Err(_) => self.handle_disconnect(),
+			}
+		}
+	}
+
+	pub(crate) const fn is_pending(&self) -> bool {
+		self.is_pending
+	}
+
+	pub(crate) const fn get_dependencies(&self) -> &Dependencies {
+		&self.dependencies
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use serial_test::serial;
+
+	use super::*;
+
+	const BASE: &str = "7f5eac44012ea33e5bdec0df72125c1bc2b2691d";
+	const MODIFIED: &str = "1cc0456637cb220155e957c641f483e60724c581";
+	const DELETED: &str = "d85479638307e4db37e1f1f2c3c807f7ff36a0ff";
+	const ADDED: &str = "c1ac7f2c32f9e00012f409572d223c9457ae497b";
+
+	fn load(hashes: &[&str]) -> Dependencies {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		Dependencies::load(hashes)
+	}
+
+	#[test]
+	#[serial]
+	fn depends_on() {
+		let dependencies = load(&[BASE, MODIFIED, DELETED, ADDED]);
+		assert!(dependencies.get_depends_on(BASE).is_empty());
+		assert_eq!(dependencies.get_depends_on(MODIFIED), &[String::from(BASE)]);
+		assert_eq!(dependencies.get_depends_on(DELETED), &[String::from(BASE)]);
+		assert!(dependencies.get_depends_on(ADDED).is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn depends_on_reordered() {
+		let dependencies = load(&[DELETED, MODIFIED, BASE]);
+		assert_eq!(dependencies.get_depends_on(MODIFIED), &[String::from(BASE)]);
+		assert_eq!(dependencies.get_depends_on(DELETED), &[String::from(BASE)]);
+	}
+
+	#[test]
+	#[serial]
+	fn depended_on_by() {
+		let dependencies = load(&[BASE, MODIFIED, DELETED, ADDED]);
+		let mut expected = vec![MODIFIED, DELETED];
+		expected.sort_unstable();
+		assert_eq!(dependencies.get_depended_on_by(BASE), expected);
+		assert!(dependencies.get_depended_on_by(ADDED).is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn without_earlier_commit() {
+		let dependencies = load(&[MODIFIED, DELETED]);
+		assert!(dependencies.get_depends_on(MODIFIED).is_empty());
+		assert!(dependencies.get_depends_on(DELETED).is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn invalid_commit() {
+		let dependencies = load(&["aaa", BASE]);
+		assert!(dependencies.get_depends_on("aaa").is_empty());
+		assert!(dependencies.get_depended_on_by(BASE).is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn loader_loads_in_background() {
+		let _ = load(&[]);
+		let mut loader = DependencyLoader::new();
+		loader.update(&[String::from(BASE), String::from(MODIFIED)]);
+		assert!(loader.is_pending());
+		loader.wait();
+		assert!(!loader.is_pending());
+		assert_eq!(loader.get_dependencies().get_depends_on(MODIFIED), &[String::from(BASE)]);
+	}
+
+	#[test]
+	#[serial]
+	fn loader_without_changes() {
+		let _ = load(&[]);
+		let mut loader = DependencyLoader::new();
+		loader.update(&[String::from(BASE), String::from(MODIFIED)]);
+		loader.wait();
+		loader.update(&[String::from(BASE), String::from(MODIFIED)]);
+		assert!(!loader.is_pending());
+		assert_eq!(loader.get_dependencies().get_depends_on(MODIFIED), &[String::from(BASE)]);
+	}
+}
@@ -1,9 +1,11 @@
 mod commit;
 mod delta;
+pub(crate) mod dependencies;
 mod diff_line;
 mod file_stat;
 mod file_stats_builder;
 mod origin;
+pub(crate) mod redundant;
 mod show_commit_state;
 mod status;
 mod user;
@@ -52,7 +54,11 @@ pub struct ShowCommit<'s> {
 
 impl<'s> ProcessModule for ShowCommit<'s> {
 	fn activate(&mut self, rebase_todo: &TodoFile, _: State) -> ProcessResult {
-		if let Some(selected_line) = rebase_todo.get_selected_line() {
+		// an applied line from an in progress rebase is selected instead of a todo line when it is set
+		if let Some(selected_line) = rebase_todo
+			.get_selected_done_line()
+			.or_else(|| rebase_todo.get_selected_line())
+		{
 			// skip loading commit data if the currently loaded commit has not changed, this retains
 			// position after returning to the list view or help
 			if let Some(ref commit) = self.commit {
@@ -0,0 +1,301 @@
+use std::{
+	collections::{HashMap, HashSet},
+	sync::mpsc::{channel, Receiver, Sender, TryRecvError},
+	thread,
+	time::{Duration, Instant},
+};
+
+use git2::{Commit, Error, Oid, Repository};
+
+// limits the number of upstream commits that are compared, since the onto commit may have a long unrelated history
+const MAXIMUM_UPSTREAM_COMMITS: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Redundancy {
+	// the commit does not change the tree of its parent
+	Empty,
+	// a commit with the same changes is already reachable from the onto commit, so git will skip it
+	Upstream,
+}
+
+// merge and root commits do not have a single diff to compare
+fn get_patch_id(repo: &Repository, commit: &Commit<'_>) -> Result<Option<Oid>, Error> {
+	if commit.parent_count() != 1 {
+		return Ok(None);
+	}
+	let diff = repo.diff_tree_to_tree(Some(&commit.parent(0)?.tree()?), Some(&commit.tree()?), None)?;
+	Ok(Some(diff.patchid(None)?))
+}
+
+fn load_upstream_patch_ids(repo: &Repository, onto: &str, commits: &[Commit<'_>]) -> Result<HashSet<Oid>, Error> {
+	let mut revwalk = repo.revwalk()?;
+	revwalk.push(repo.revparse_single(onto)?.peel_to_commit()?.id())?;
+	for commit in commits {
+		for parent_id in commit.parent_ids() {
+			revwalk.hide(parent_id)?;
+		}
+	}
+	let mut patch_ids = HashSet::new();
+	for oid in revwalk.take(MAXIMUM_UPSTREAM_COMMITS) {
+		if let Some(patch_id) = get_patch_id(repo, &repo.find_commit(oid?)?)? {
+			let _inserted = patch_ids.insert(patch_id);
+		}
+	}
+	Ok(patch_ids)
+}
+
+// Commits that can not be loaded are not redundant. Without an onto commit, only empty commits are found.
+pub(crate) fn load_redundant_commits(onto: Option<&str>, hashes: &[&str]) -> HashMap<String, Redundancy> {
+	let mut redundant_commits = HashMap::new();
+	let repo = match Repository::open_from_env() {
+		Ok(repo) => repo,
+		Err(_) => return redundant_commits,
+	};
+	let commits: Vec<(&str, Commit<'_>)> = hashes
+		.iter()
+		.filter_map(|&hash| Some((hash, repo.revparse_single(hash).ok()?.peel_to_commit().ok()?)))
+		.collect();
+
+	let upstream_patch_ids = onto
+		.and_then(|onto| {
+			let todo_commits: Vec<Commit<'_>> = commits.iter().map(|(_, commit)| commit.clone()).collect();
+			load_upstream_patch_ids(&repo, onto, &todo_commits).ok()
+		})
+		.unwrap_or_default();
+
+	for (hash, commit) in &commits {
+		let is_empty = commit.parent_count() == 1
+			&& commit
+				.parent(0)
+				.map_or(false, |parent| parent.tree_id() == commit.tree_id());
+		if is_empty {
+			let _previous = redundant_commits.insert(String::from(*hash), Redundancy::Empty);
+		}
+		else if let Ok(Some(patch_id)) = get_patch_id(&repo, commit) {
+			if upstream_patch_ids.contains(&patch_id) {
+				let _previous = redundant_commits.insert(String::from(*hash), Redundancy::Upstream);
+			}
+		}
+	}
+	redundant_commits
+}
+
+type RedundancyRequest = (usize, Option<String>, Vec<String>);
+
+// Finds the redundant commits in a background thread, since the patch of every commit, and of the upstream commits, is
+// needed. Only the most recent request is loaded, and a result for an older request is ignored. The previous redundant
+// commits are kept until the new ones are loaded.
+pub(crate) struct RedundancyLoader {
+	generation: usize,
+	is_pending: bool,
+	loaded_request: (Option<String>, Vec<String>),
+	receiver: Option<Receiver<(usize, HashMap<String, Redundancy>)>>,
+	redundant_commits: HashMap<String, Redundancy>,
+	requested_at: Instant,
+	sender: Option<Sender<RedundancyRequest>>,
+}
+
+impl RedundancyLoader {
+	pub(crate) fn new() -> Self {
+		Self {
+			generation: 0,
+			is_pending: false,
+			loaded_request: (None, vec![]),
+			receiver: None,
+			redundant_commits: HashMap::new(),
+			requested_at: Instant::now(),
+			sender: None,
+		}
+	}
+
+	fn start(&mut self) {
+		let (request_sender, request_receiver) = channel::<RedundancyRequest>();
+		let (result_sender, result_receiver) = channel();
+		let _handle = thread::spawn(move || {
+			while let Ok(mut request) = request_receiver.recv() {
+				while let Ok(newer_request) = request_receiver.try_recv() {
+					request = newer_request;
+				}
+				let (generation, onto, hashes) = request;
+				let hash_refs: Vec<&str> = hashes.iter().map(String::as_str).collect();
+				let redundant_commits = load_redundant_commits(onto.as_deref(), &hash_refs);
+				if result_sender.send((generation, redundant_commits)).is_err() {
+					break;
+				}
+			}
+		});
+		self.receiver = Some(result_receiver);
+		self.sender = Some(request_sender);
+	}
+
+	fn handle_result(&mut self, generation: usize, redundant_commits: HashMap<String, Redundancy>) {
+		if generation == self.generation {
+			self.redundant_commits = redundant_commits;
+			self.is_pending = false;
+		}
+	}
+
+	fn handle_disconnect(&mut self) {
+		self.is_pending = false;
+		self.receiver = None;
+		self.sender = None;
+	}
+
+	fn receive(&mut self) {
+		loop {
+			let result = match self.receiver {
+				Some(ref receiver) => receiver.try_recv(),
+				None => break,
+			};
+			match result {
+				Ok((generation, redundant_commits)) => self.handle_result(generation, redundant_commits),
+				Err(TryRecvError::Empty) => break,
+				Err(TryRecvError::Disconnected) => self.handle_disconnect(),
+			}
+		}
+	}
+
+	pub(crate) fn update(&mut self, onto: Option<&str>, hashes: &[String]) {
+		if self.sender.is_none() {
+			self.start();
+		}
+		self.receive();
+
+		if onto == self.loaded_request.0.as_deref() && hashes == self.loaded_request.1.as_slice() {
+			return;
+		}
+		if let Some(ref sender) = self.sender {
+			self.generation += 1;
+			self.loaded_request = (onto.map(String::from), hashes.to_vec());
+			self.requested_at = Instant::now();
+			self.is_pending = sender
+				.send((self.generation, self.loaded_request.0.clone(), self.loaded_request.1.clone()))
+				.is_ok();
+		}
+	}
+
+	#[cfg(test)]
+	pub(crate) fn wait(&mut self) {
+		while self.is_pending {
+			let result = match self.receiver {
+				Some(ref receiver) => receiver.recv(),
+				None => break,
+			};
+			match result {
+				Ok((generation, redundant_commits)) => self.handle_result(generation, redundant_commits),
+				Err(_) => self.handle_disconnect(),
+			}
+		}
+	}
+
+	pub(crate) const fn is_pending(&self) -> bool {
+		self.is_pending
+	}
+
+	// a load that finishes quickly is never shown as pending, so the list does not flicker on every change
+	pub(crate) fn is_pending_for(&self, duration: Duration) -> bool {
+		self.is_pending && self.requested_at.elapsed() >= duration
+	}
+
+	pub(crate) fn get_redundancy(&self, hash: &str) -> Option<Redundancy> {
+		self.redundant_commits.get(hash).copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env::set_var, path::Path};
+
+	use serial_test::serial;
+
+	use super::*;
+
+	fn load(onto: Option<&str>, hashes: &[&str]) -> HashMap<String, Redundancy> {
+		set_var(
+			"GIT_DIR",
+			Path::new(env!("CARGO_MANIFEST_DIR"))
+				.join("test")
+				.join("fixtures")
+				.join("simple")
+				.to_str()
+				.unwrap(),
+		);
+		load_redundant_commits(onto, hashes)
+	}
+
+	#[test]
+	#[serial]
+	fn empty_commit() {
+		let redundant_commits = load(None, &[
+			"18d82dcc4c36cade807d7cf79700b6bbad8080b9",
+			"7f5eac44012ea33e5bdec0df72125c1bc2b2691d",
+		]);
+		assert_eq!(redundant_commits.len(), 1);
+		assert_eq!(
+			redundant_commits.get("18d82dcc4c36cade807d7cf79700b6bbad8080b9"),
+			Some(&Redundancy::Empty)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn upstream_commit() {
+		let redundant_commits = load(Some("d85479638307e4db37e1f1f2c3c807f7ff36a0ff"), &[
+			"7f5eac44012ea33e5bdec0df72125c1bc2b2691d",
+			"1cc0456637cb220155e957c641f483e60724c581",
+		]);
+		assert_eq!(redundant_commits.len(), 1);
+		assert_eq!(
+			redundant_commits.get("1cc0456637cb220155e957c641f483e60724c581"),
+			Some(&Redundancy::Upstream)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn invalid_onto() {
+		let redundant_commits = load(Some("0123456789"), &["1cc0456637cb220155e957c641f483e60724c581"]);
+		assert!(redundant_commits.is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn invalid_commit() {
+		let redundant_commits = load(Some("d85479638307e4db37e1f1f2c3c807f7ff36a0ff"), &["0123456789"]);
+		assert!(redundant_commits.is_empty());
+	}
+
+	#[test]
+	#[serial]
+	fn loader_loads_in_background() {
+		let _ = load(None, &[]);
+		let mut loader = RedundancyLoader::new();
+		loader.update(Some("d85479638307e4db37e1f1f2c3c807f7ff36a0ff"), &[String::from(
+			"1cc0456637cb220155e957c641f483e60724c581",
+		)]);
+		assert!(loader.is_pending());
+		loader.wait();
+		assert!(!loader.is_pending());
+		assert_eq!(
+			loader.get_redundancy("1cc0456637cb220155e957c641f483e60724c581"),
+			Some(Redundancy::Upstream)
+		);
+	}
+
+	#[test]
+	#[serial]
+	fn loader_reloads_for_changed_onto() {
+		let _ = load(None, &[]);
+		let mut loader = RedundancyLoader::new();
+		let hashes = [String::from("1cc0456637cb220155e957c641f483e60724c581")];
+		loader.update(Some("d85479638307e4db37e1f1f2c3c807f7ff36a0ff"), &hashes);
+		loader.wait();
+		loader.update(Some("d85479638307e4db37e1f1f2c3c807f7ff36a0ff"), &hashes);
+		assert!(!loader.is_pending());
+		loader.update(None, &hashes);
+		assert!(loader.is_pending());
+		assert!(!loader.is_pending_for(Duration::from_secs(30)));
+		loader.wait();
+		assert_eq!(loader.get_redundancy("1cc0456637cb220155e957c641f483e60724c581"), None);
+	}
+}
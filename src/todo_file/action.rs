@@ -2,24 +2,40 @@ use std::convert::TryFrom;
 
 use anyhow::{anyhow, Error};
 
+/// An action of a line in a rebase todo file.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Action {
+	/// Stop the rebase at this point.
 	Break,
+	/// Remove the commit.
 	Drop,
+	/// Use the commit, but stop for amending.
 	Edit,
+	/// Run a shell command.
 	Exec,
+	/// Meld the commit into the previous commit, discarding its message.
 	Fixup,
+	/// Do nothing, used by git when there is nothing to rebase.
 	Noop,
+	/// Use the commit.
 	Pick,
+	/// Use the commit, but edit the commit message.
 	Reword,
+	/// Meld the commit into the previous commit.
 	Squash,
+	/// Label the current HEAD with a name.
 	Label,
+	/// Reset HEAD to a label.
 	Reset,
+	/// Create a merge commit.
 	Merge,
+	/// Update a reference to point at the current HEAD.
+	UpdateRef,
 }
 
 impl Action {
-	pub(crate) fn as_string(self) -> String {
+	/// The full name of the action, as written in the todo file.
+	pub fn as_string(self) -> String {
 		String::from(match self {
 			Self::Break => "break",
 			Self::Drop => "drop",
@@ -33,10 +49,12 @@ impl Action {
 			Self::Reset => "reset",
 			Self::Reword => "reword",
 			Self::Squash => "squash",
+			Self::UpdateRef => "update-ref",
 		})
 	}
 
-	pub(crate) fn to_abbreviation(self) -> String {
+	/// The single letter abbreviation of the action.
+	pub fn to_abbreviation(self) -> String {
 		String::from(match self {
 			Self::Break => "b",
 			Self::Drop => "d",
@@ -50,12 +68,20 @@ impl Action {
 			Self::Reset => "t",
 			Self::Reword => "r",
 			Self::Squash => "s",
+			Self::UpdateRef => "u",
 		})
 	}
 
+	/// Whether the action can not be changed to another action.
 	pub const fn is_static(self) -> bool {
 		match self {
-			Self::Break | Self::Exec | Self::Noop | Self::Reset | Self::Label | Self::Merge => true,
+			Self::Break
+			| Self::Exec
+			| Self::Noop
+			| Self::Reset
+			| Self::Label
+			| Self::Merge
+			| Self::UpdateRef => true,
 			Self::Drop | Self::Edit | Self::Fixup | Self::Pick | Self::Reword | Self::Squash => false,
 		}
 	}
@@ -78,6 +104,7 @@ impl TryFrom<&str> for Action {
 			"label" | "l" => Ok(Self::Label),
 			"reset" | "t" => Ok(Self::Reset),
 			"merge" | "m" => Ok(Self::Merge),
+			"update-ref" | "u" => Ok(Self::UpdateRef),
 			_ => Err(anyhow!("Invalid action: {}", s)),
 		}
 	}
@@ -134,6 +161,7 @@ mod tests {
 	test_action_to_string!(label, Action::Label, "label");
 	test_action_to_string!(reset, Action::Reset, "reset");
 	test_action_to_string!(merge, Action::Merge, "merge");
+	test_action_to_string!(update_ref, Action::UpdateRef, "update-ref");
 
 	test_action_try_from!(b, "b", Action::Break);
 	test_action_try_from!(break_str, "break", Action::Break);
@@ -159,6 +187,8 @@ mod tests {
 	test_action_try_from!(reset, "reset", Action::Reset);
 	test_action_try_from!(m, "m", Action::Merge);
 	test_action_try_from!(merge, "merge", Action::Merge);
+	test_action_try_from!(u, "u", Action::UpdateRef);
+	test_action_try_from!(update_ref, "update-ref", Action::UpdateRef);
 
 	#[test]
 	fn action_try_from_invalid() {
@@ -180,6 +210,7 @@ mod tests {
 	test_action_to_abbreviation!(l, Action::Label, "l");
 	test_action_to_abbreviation!(t, Action::Reset, "t");
 	test_action_to_abbreviation!(m, Action::Merge, "m");
+	test_action_to_abbreviation!(u, Action::UpdateRef, "u");
 
 	#[rstest(
 		action,
@@ -195,7 +226,8 @@ mod tests {
 		case::squash(Action::Squash, false),
 		case::squash(Action::Label, true),
 		case::squash(Action::Reset, true),
-		case::squash(Action::Merge, true)
+		case::squash(Action::Merge, true),
+		case::update_ref(Action::UpdateRef, true)
 	)]
 	fn module_lifecycle(action: Action, expected: bool) {
 		assert_eq!(action.is_static(), expected);
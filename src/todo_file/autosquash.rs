@@ -0,0 +1,244 @@
+use crate::todo_file::{action::Action, fixup_message::FixupMessage, line::Line};
+
+const FIXUP_PREFIXES: [(&str, Action, bool); 3] = [
+	("fixup! ", Action::Fixup, false),
+	("squash! ", Action::Squash, false),
+	("amend! ", Action::Fixup, true),
+];
+
+// only lines that are still picked, or already fixed up, are moved, other actions were chosen explicitly
+fn get_fixup_prefix(line: &Line) -> Option<(&str, Action, bool)> {
+	match *line.get_action() {
+		Action::Pick | Action::Fixup | Action::Squash => {
+			FIXUP_PREFIXES.iter().find_map(|&(prefix, action, is_amend)| {
+				line.get_content()
+					.strip_prefix(prefix)
+					.map(|subject| (subject.trim(), action, is_amend))
+			})
+		},
+		_ => None,
+	}
+}
+
+fn is_commit_line(line: &Line) -> bool {
+	line.has_reference() && !line.get_action().is_static() && *line.get_action() != Action::Drop
+}
+
+fn is_hash_match(hash: &str, subject: &str) -> bool {
+	subject.len() >= 4
+		&& subject.chars().all(|c| c.is_ascii_hexdigit())
+		&& (hash.starts_with(subject) || subject.starts_with(hash))
+}
+
+// like git, only an earlier commit can be the target, and an exact subject match wins over a hash match, which wins
+// over a subject prefix match
+fn find_target(lines: &[Line], index: usize, subject: &str) -> Option<usize> {
+	if subject.is_empty() {
+		return None;
+	}
+	let candidates = || lines[..index].iter().enumerate().filter(|&(_, line)| is_commit_line(line));
+	candidates()
+		.find(|&(_, line)| line.get_content() == subject)
+		.or_else(|| candidates().find(|&(_, line)| is_hash_match(line.get_hash(), subject)))
+		.or_else(|| candidates().find(|&(_, line)| line.get_content().starts_with(subject)))
+		.map(|(i, _)| i)
+}
+
+fn find_targets(lines: &[Line]) -> Vec<Option<usize>> {
+	lines
+		.iter()
+		.enumerate()
+		.map(|(index, line)| get_fixup_prefix(line).and_then(|(subject, ..)| find_target(lines, index, subject)))
+		.collect()
+}
+
+fn push_with_fixups(lines: &[Line], fixups: &[Vec<usize>], index: usize, is_fixup: bool, result: &mut Vec<Line>) {
+	let mut line = lines[index].clone();
+	// a line that is already a fixup or squash keeps the action and option that were set on it
+	if is_fixup && *line.get_action() == Action::Pick {
+		if let Some((_, action, is_amend)) = get_fixup_prefix(&line) {
+			line.set_action(action);
+			if is_amend {
+				line.set_option(Some(FixupMessage::Use));
+			}
+		}
+	}
+	result.push(line);
+	for &fixup_index in &fixups[index] {
+		push_with_fixups(lines, fixups, fixup_index, true, result);
+	}
+}
+
+pub(super) fn autosquash(lines: &[Line]) -> Vec<Line> {
+	let targets = find_targets(lines);
+	let mut fixups: Vec<Vec<usize>> = vec![vec![]; lines.len()];
+	for (index, target) in targets.iter().enumerate() {
+		if let Some(target) = *target {
+			fixups[target].push(index);
+		}
+	}
+
+	let mut result = Vec::with_capacity(lines.len());
+	for (index, target) in targets.iter().enumerate() {
+		if target.is_none() {
+			push_with_fixups(lines, &fixups, index, false, &mut result);
+		}
+	}
+	result
+}
+
+pub(super) fn find_orphaned_fixups(lines: &[Line]) -> Vec<usize> {
+	lines
+		.iter()
+		.enumerate()
+		.filter(|&(index, line)| {
+			get_fixup_prefix(line).map_or(false, |(subject, ..)| find_target(lines, index, subject).is_none())
+		})
+		.map(|(index, _)| index)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|l| Line::new(l).unwrap()).collect()
+	}
+
+	fn autosquash_text(lines: &[&str]) -> Vec<String> {
+		autosquash(&create_lines(lines)).iter().map(Line::to_text).collect()
+	}
+
+	#[rstest(
+		line,
+		expected,
+		case::fixup("pick ccc fixup! c1", "fixup ccc fixup! c1"),
+		case::squash("pick ccc squash! c1", "squash ccc squash! c1"),
+		case::amend("pick ccc amend! c1", "fixup -C ccc amend! c1")
+	)]
+	fn moves_under_target(line: &str, expected: &str) {
+		assert_eq!(autosquash_text(&["pick aaa c1", "pick bbb c2", line]), vec![
+			"pick aaa c1",
+			expected,
+			"pick bbb c2"
+		]);
+	}
+
+	#[test]
+	fn keeps_order_of_multiple_fixups() {
+		assert_eq!(
+			autosquash_text(&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1", "pick ddd squash! c1"]),
+			vec!["pick aaa c1", "fixup ccc fixup! c1", "squash ddd squash! c1", "pick bbb c2"]
+		);
+	}
+
+	#[test]
+	fn fixup_of_fixup() {
+		assert_eq!(
+			autosquash_text(&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1", "pick ddd fixup! fixup! c1"]),
+			vec!["pick aaa c1", "fixup ccc fixup! c1", "fixup ddd fixup! fixup! c1", "pick bbb c2"]
+		);
+	}
+
+	#[test]
+	fn target_by_hash() {
+		assert_eq!(
+			autosquash_text(&["pick aaaa1111 c1", "pick bbb c2", "pick ccc fixup! aaaa"]),
+			vec!["pick aaaa1111 c1", "fixup ccc fixup! aaaa", "pick bbb c2"]
+		);
+	}
+
+	#[test]
+	fn target_by_subject_prefix() {
+		assert_eq!(
+			autosquash_text(&["pick aaa a long subject", "pick bbb c2", "pick ccc fixup! a long"]),
+			vec!["pick aaa a long subject", "fixup ccc fixup! a long", "pick bbb c2"]
+		);
+	}
+
+	#[test]
+	fn exact_subject_preferred_over_prefix() {
+		assert_eq!(
+			autosquash_text(&["pick aaa c1 more", "pick bbb c1", "pick ccc fixup! c1"]),
+			vec!["pick aaa c1 more", "pick bbb c1", "fixup ccc fixup! c1"]
+		);
+	}
+
+	#[test]
+	fn target_after_fixup_not_used() {
+		assert_eq!(autosquash_text(&["pick ccc fixup! c1", "pick aaa c1"]), vec![
+			"pick ccc fixup! c1",
+			"pick aaa c1"
+		]);
+	}
+
+	#[test]
+	fn dropped_target_not_used() {
+		assert_eq!(
+			autosquash_text(&["drop aaa c1", "pick bbb c2", "pick ccc fixup! c1"]),
+			vec!["drop aaa c1", "pick bbb c2", "pick ccc fixup! c1"]
+		);
+	}
+
+	#[rstest(
+		line,
+		case::squash("squash ccc fixup! c1"),
+		case::fixup_with_option("fixup -c ccc amend! c1")
+	)]
+	fn explicit_fixup_action_kept(line: &str) {
+		assert_eq!(autosquash_text(&["pick aaa c1", "pick bbb c2", line]), vec![
+			"pick aaa c1",
+			line,
+			"pick bbb c2"
+		]);
+	}
+
+	#[rstest(
+		line,
+		case::drop("drop ccc fixup! c1"),
+		case::edit("edit ccc fixup! c1"),
+		case::reword("reword ccc fixup! c1")
+	)]
+	fn explicit_action_not_moved(line: &str) {
+		assert_eq!(autosquash_text(&["pick aaa c1", "pick bbb c2", line]), vec![
+			"pick aaa c1",
+			"pick bbb c2",
+			line
+		]);
+	}
+
+	#[test]
+	fn orphaned_fixup_not_moved() {
+		assert_eq!(autosquash_text(&["pick aaa c1", "pick ccc fixup! c9", "pick bbb c2"]), vec![
+			"pick aaa c1",
+			"pick ccc fixup! c9",
+			"pick bbb c2"
+		]);
+	}
+
+	#[test]
+	fn fixups_targeting_each_other() {
+		assert_eq!(autosquash_text(&["pick aaaa fixup! bbbb", "pick bbbb fixup! aaaa"]), vec![
+			"pick aaaa fixup! bbbb",
+			"fixup bbbb fixup! aaaa"
+		]);
+	}
+
+	#[test]
+	fn orphaned_fixups() {
+		let lines = create_lines(&[
+			"pick aaa c1",
+			"pick bbb fixup! c1",
+			"pick ccc fixup! c9",
+			"exec make",
+			"drop ddd squash! c9",
+			"pick eee squash! c8",
+			"pick fff fixup! c7",
+			"pick ggg c7",
+		]);
+		assert_eq!(find_orphaned_fixups(&lines), vec![2, 5, 6]);
+	}
+}
@@ -1,35 +1,54 @@
-use crate::todo_file::action::Action;
+use crate::todo_file::{action::Action, fixup_message::FixupMessage};
 
+/// The changes to make to a range of lines, built with the action, content and option methods.
 pub struct EditContext {
 	action: Option<Action>,
 	content: Option<String>,
+	option: Option<Option<FixupMessage>>,
 }
 
 impl EditContext {
+	/// Create a new context that changes nothing.
 	pub const fn new() -> Self {
 		Self {
 			action: None,
 			content: None,
+			option: None,
 		}
 	}
 
+	/// Set the action of the lines.
 	pub const fn action(mut self, action: Action) -> Self {
 		self.action = Some(action);
 		self
 	}
 
+	/// Set the content of the lines.
 	pub fn content(mut self, content: &str) -> Self {
 		self.content = Some(content.to_owned());
 		self
 	}
 
+	/// Set the option of the lines, or clear it with `None`.
+	pub const fn option(mut self, option: Option<FixupMessage>) -> Self {
+		self.option = Some(option);
+		self
+	}
+
+	/// Get the action to set.
 	pub const fn get_action(&self) -> &Option<Action> {
 		&self.action
 	}
 
+	/// Get the content to set.
 	pub const fn get_content(&self) -> &Option<String> {
 		&self.content
 	}
+
+	/// Get the option to set.
+	pub const fn get_option(&self) -> &Option<Option<FixupMessage>> {
+		&self.option
+	}
 }
 
 #[cfg(test)]
@@ -41,6 +60,7 @@ mod tests {
 		let edit_context = EditContext::new();
 		assert_eq!(edit_context.get_action(), &None);
 		assert_eq!(edit_context.get_content(), &None);
+		assert_eq!(edit_context.get_option(), &None);
 	}
 
 	#[test]
@@ -63,4 +83,18 @@ mod tests {
 		assert_eq!(edit_context.get_action(), &Some(Action::Edit));
 		assert_eq!(edit_context.get_content(), &Some(String::from("test content")));
 	}
+
+	#[test]
+	fn with_option() {
+		let edit_context = EditContext::new().option(Some(FixupMessage::Use));
+		assert_eq!(edit_context.get_action(), &None);
+		assert_eq!(edit_context.get_content(), &None);
+		assert_eq!(edit_context.get_option(), &Some(Some(FixupMessage::Use)));
+	}
+
+	#[test]
+	fn with_cleared_option() {
+		let edit_context = EditContext::new().option(None);
+		assert_eq!(edit_context.get_option(), &Some(None));
+	}
 }
@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use anyhow::{anyhow, Error};
+
+/// How the message of the commit of a `fixup` or `merge` line is used.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FixupMessage {
+	/// Use the message of the commit, the `-C` option.
+	Use,
+	/// Use the message of the commit and edit it, the `-c` option.
+	Edit,
+}
+
+impl FixupMessage {
+	/// The option, as written in the todo file.
+	pub fn as_string(self) -> String {
+		String::from(match self {
+			Self::Use => "-C",
+			Self::Edit => "-c",
+		})
+	}
+}
+
+impl TryFrom<&str> for FixupMessage {
+	type Error = Error;
+
+	fn try_from(s: &str) -> Result<Self, Self::Error> {
+		match s {
+			"-C" => Ok(Self::Use),
+			"-c" => Ok(Self::Edit),
+			_ => Err(anyhow!("Invalid option: {}", s)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest(
+		fixup_message,
+		expected,
+		case::use_message(FixupMessage::Use, "-C"),
+		case::edit_message(FixupMessage::Edit, "-c")
+	)]
+	fn as_string(fixup_message: FixupMessage, expected: &str) {
+		assert_eq!(fixup_message.as_string(), expected);
+		assert_eq!(FixupMessage::try_from(expected).unwrap(), fixup_message);
+	}
+
+	#[test]
+	fn try_from_invalid() {
+		assert_eq!(
+			FixupMessage::try_from("-x").unwrap_err().to_string(),
+			"Invalid option: -x"
+		);
+	}
+}
@@ -0,0 +1,34 @@
+use crate::todo_file::line::Line;
+
+/// A human readable description of a change in the history.
+#[derive(Debug, PartialEq)]
+pub struct HistoryEntry {
+	description: String,
+	lines: Vec<Line>,
+	start_index: usize,
+}
+
+impl HistoryEntry {
+	pub(crate) const fn new(description: String, lines: Vec<Line>, start_index: usize) -> Self {
+		Self {
+			description,
+			lines,
+			start_index,
+		}
+	}
+
+	/// A description of the change, for example "Moved 3 lines up".
+	pub fn get_description(&self) -> &str {
+		self.description.as_str()
+	}
+
+	/// The affected lines, as they are after the change.
+	pub const fn get_lines(&self) -> &Vec<Line> {
+		&self.lines
+	}
+
+	/// The index of the first affected line, after the change.
+	pub const fn get_start_index(&self) -> usize {
+		self.start_index
+	}
+}
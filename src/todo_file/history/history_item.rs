@@ -1,56 +1,95 @@
 use crate::todo_file::{history::operation::Operation, line::Line};
 
+/// A change recorded in the history, holding what is needed to revert it.
 #[derive(Debug, PartialEq)]
 pub struct HistoryItem {
+	/// The first index of the changed range.
 	pub start_index: usize,
+	/// The last index of the changed range, which may be before the start index.
 	pub end_index: usize,
+	/// The type of change.
 	pub operation: Operation,
+	/// The lines needed to revert the change, for modify, remove and replace changes.
 	pub lines: Vec<Line>,
+	/// The index the first line of the range was moved to, for move changes.
+	pub destination_index: usize,
 }
 
 impl HistoryItem {
+	/// Lines in the range were changed, `lines` holds the lines before the change.
 	pub fn new_modify(start_index: usize, end_index: usize, lines: Vec<Line>) -> Self {
 		Self {
 			operation: Operation::Modify,
 			start_index,
 			end_index,
 			lines,
+			destination_index: 0,
 		}
 	}
 
+	/// Lines were added in the range.
 	pub const fn new_add(start_index: usize, end_index: usize) -> Self {
 		Self {
 			operation: Operation::Add,
 			start_index,
 			end_index,
 			lines: vec![],
+			destination_index: 0,
 		}
 	}
 
+	/// Lines in the range were removed, `lines` holds the removed lines.
 	pub fn new_remove(start_index: usize, end_index: usize, lines: Vec<Line>) -> Self {
 		Self {
 			operation: Operation::Remove,
 			start_index,
 			end_index,
 			lines,
+			destination_index: 0,
 		}
 	}
 
+	/// Lines were replaced with the lines in the range, `lines` holds the replaced lines.
+	pub fn new_replace(start_index: usize, end_index: usize, lines: Vec<Line>) -> Self {
+		Self {
+			operation: Operation::Replace,
+			start_index,
+			end_index,
+			lines,
+			destination_index: 0,
+		}
+	}
+
+	/// Lines in the range were moved up by one.
 	pub const fn new_swap_up(start_index: usize, end_index: usize) -> Self {
 		Self {
 			operation: Operation::SwapUp,
 			start_index,
 			end_index,
 			lines: vec![],
+			destination_index: 0,
 		}
 	}
 
+	/// Lines in the range were moved down by one.
 	pub const fn new_swap_down(start_index: usize, end_index: usize) -> Self {
 		Self {
 			operation: Operation::SwapDown,
 			start_index,
 			end_index,
 			lines: vec![],
+			destination_index: 0,
+		}
+	}
+
+	/// Lines in the range were moved, so the first line of the range is now at the destination index.
+	pub const fn new_move(start_index: usize, end_index: usize, destination_index: usize) -> Self {
+		Self {
+			operation: Operation::Move,
+			start_index,
+			end_index,
+			lines: vec![],
+			destination_index,
 		}
 	}
 }
@@ -1,17 +1,25 @@
+/// Descriptions of the changes in the history.
+pub mod history_entry;
+/// The changes recorded in the history.
 pub mod history_item;
-mod operation;
+/// The types of changes recorded in the history.
+pub mod operation;
 
 #[cfg(test)]
 mod tests;
 
-use std::{cmp::min, collections::VecDeque};
+use std::{
+	cmp::{max, min},
+	collections::VecDeque,
+};
 
 use crate::todo_file::{
-	history::{history_item::HistoryItem, operation::Operation},
+	history::{history_entry::HistoryEntry, history_item::HistoryItem, operation::Operation},
 	line::Line,
-	utils::{add_range, remove_range, swap_range_down, swap_range_up},
+	utils::{add_range, move_range, remove_range, swap_range_down, swap_range_up},
 };
 
+/// An undo and redo history of changes made to a list of lines, limited to a number of changes.
 pub struct History {
 	redo_history: VecDeque<HistoryItem>,
 	undo_history: VecDeque<HistoryItem>,
@@ -19,6 +27,7 @@ pub struct History {
 }
 
 impl History {
+	/// Create an empty history that keeps at most `limit` changes.
 	pub fn new(limit: u32) -> Self {
 		Self {
 			redo_history: VecDeque::new(),
@@ -27,6 +36,7 @@ impl History {
 		}
 	}
 
+	/// Apply a history item to the lines, returning the item that reverts the change.
 	pub fn apply_operation(lines: &mut Vec<Line>, operation: &HistoryItem) -> HistoryItem {
 		match operation.operation {
 			Operation::Modify => {
@@ -52,6 +62,13 @@ impl History {
 				add_range(lines, &operation.lines, operation.start_index, operation.end_index);
 				HistoryItem::new_add(operation.start_index, operation.end_index)
 			},
+			Operation::Replace => {
+				let removed_lines = remove_range(lines, operation.start_index, operation.end_index);
+				let start_index = min(operation.start_index, operation.end_index);
+				let end_index = start_index + operation.lines.len() - 1;
+				add_range(lines, &operation.lines, start_index, end_index);
+				HistoryItem::new_replace(start_index, end_index, removed_lines)
+			},
 			Operation::SwapUp => {
 				swap_range_down(lines, operation.start_index - 1, operation.end_index - 1);
 				HistoryItem::new_swap_down(operation.start_index - 1, operation.end_index - 1)
@@ -60,9 +77,25 @@ impl History {
 				swap_range_up(lines, operation.start_index + 1, operation.end_index + 1);
 				HistoryItem::new_swap_up(operation.start_index + 1, operation.end_index + 1)
 			},
+			Operation::Move => {
+				let start_index = min(operation.start_index, operation.end_index);
+				let count = max(operation.start_index, operation.end_index) - start_index;
+				move_range(
+					lines,
+					operation.destination_index,
+					operation.destination_index + count,
+					start_index,
+				);
+				HistoryItem::new_move(
+					operation.destination_index,
+					operation.destination_index + count,
+					start_index,
+				)
+			},
 		}
 	}
 
+	/// Record a change that can be undone, clearing the redo history.
 	pub fn record(&mut self, operations: HistoryItem) {
 		self.redo_history.clear();
 		// delete old entries on limit reached
@@ -74,7 +107,9 @@ impl History {
 
 	fn get_last_index_range(history_item: &HistoryItem, list_length: usize) -> (usize, usize) {
 		match history_item.operation {
-			Operation::Add | Operation::Modify => (history_item.start_index, history_item.end_index),
+			Operation::Add | Operation::Modify | Operation::Replace => {
+				(history_item.start_index, history_item.end_index)
+			},
 			Operation::Remove => {
 				let index = min(history_item.start_index, history_item.end_index);
 				if index == 0 || list_length == 0 {
@@ -103,9 +138,15 @@ impl History {
 				(start_index, end_index)
 			},
 			Operation::SwapDown => (history_item.start_index + 1, history_item.end_index + 1),
+			Operation::Move => {
+				let count = max(history_item.start_index, history_item.end_index)
+					- min(history_item.start_index, history_item.end_index);
+				(history_item.destination_index, history_item.destination_index + count)
+			},
 		}
 	}
 
+	/// Undo the last change, returning the range of lines that changed.
 	pub fn undo(&mut self, current: &mut Vec<Line>) -> Option<(usize, usize)> {
 		self.undo_history.pop_back().map(|operation| {
 			let history = Self::apply_operation(current, &operation);
@@ -115,6 +156,7 @@ impl History {
 		})
 	}
 
+	/// Redo the last undone change, returning the range of lines that changed.
 	pub fn redo(&mut self, current: &mut Vec<Line>) -> Option<(usize, usize)> {
 		self.redo_history.pop_back().map(|operation| {
 			let history = Self::apply_operation(current, &operation);
@@ -124,6 +166,103 @@ impl History {
 		})
 	}
 
+	fn format_line_count(count: usize) -> String {
+		if count == 1 {
+			String::from("1 line")
+		}
+		else {
+			format!("{} lines", count)
+		}
+	}
+
+	// describe an undo history item, using the lines as they are after the change was made
+	fn describe(history_item: &HistoryItem, lines: &[Line]) -> HistoryEntry {
+		let start_index = min(history_item.start_index, history_item.end_index);
+		let end_index = max(history_item.start_index, history_item.end_index);
+		let count = end_index - start_index + 1;
+		match history_item.operation {
+			Operation::Modify => {
+				let changed_lines = lines[start_index..=end_index].to_vec();
+				let action = changed_lines[0].get_action();
+				let description = if changed_lines.iter().all(|l| l.get_action() == action)
+					&& history_item.lines.iter().any(|l| l.get_action() != action)
+				{
+					format!("Changed {} to {}", Self::format_line_count(count), action.as_string())
+				}
+				else {
+					format!("Edited {}", Self::format_line_count(count))
+				};
+				HistoryEntry::new(description, changed_lines, start_index)
+			},
+			Operation::Add => {
+				HistoryEntry::new(
+					format!("Added {}", Self::format_line_count(count)),
+					lines[start_index..=end_index].to_vec(),
+					start_index,
+				)
+			},
+			Operation::Remove => {
+				HistoryEntry::new(
+					format!("Removed {}", Self::format_line_count(count)),
+					history_item.lines.clone(),
+					start_index,
+				)
+			},
+			Operation::SwapUp => {
+				HistoryEntry::new(
+					format!("Moved {} up", Self::format_line_count(count)),
+					lines[(start_index - 1)..end_index].to_vec(),
+					start_index - 1,
+				)
+			},
+			Operation::SwapDown => {
+				HistoryEntry::new(
+					format!("Moved {} down", Self::format_line_count(count)),
+					lines[(start_index + 1)..=(end_index + 1)].to_vec(),
+					start_index + 1,
+				)
+			},
+			Operation::Move => {
+				HistoryEntry::new(
+					format!("Moved {}", Self::format_line_count(count)),
+					lines[history_item.destination_index..(history_item.destination_index + count)].to_vec(),
+					history_item.destination_index,
+				)
+			},
+			Operation::Replace => {
+				HistoryEntry::new(
+					format!(
+						"Replaced {} with {}",
+						Self::format_line_count(history_item.lines.len()),
+						Self::format_line_count(count)
+					),
+					lines[start_index..=end_index].to_vec(),
+					start_index,
+				)
+			},
+		}
+	}
+
+	/// Describe the undo entries, oldest first, and the redo entries, next redo first.
+	pub fn get_entries(&self, current: &[Line]) -> (Vec<HistoryEntry>, Vec<HistoryEntry>) {
+		let mut lines = current.to_vec();
+		let mut undo_entries = vec![];
+		for history_item in self.undo_history.iter().rev() {
+			undo_entries.push(Self::describe(history_item, &lines));
+			Self::apply_operation(&mut lines, history_item);
+		}
+		undo_entries.reverse();
+
+		let mut lines = current.to_vec();
+		let mut redo_entries = vec![];
+		for history_item in self.redo_history.iter().rev() {
+			let undo_item = Self::apply_operation(&mut lines, history_item);
+			redo_entries.push(Self::describe(&undo_item, &lines));
+		}
+		(undo_entries, redo_entries)
+	}
+
+	/// Clear the undo and redo history.
 	pub fn reset(&mut self) {
 		self.undo_history.clear();
 		self.redo_history.clear();
@@ -1,8 +1,18 @@
+/// The type of change recorded in a history item.
 #[derive(Debug, PartialEq)]
 pub enum Operation {
+	/// Lines were changed in place.
 	Modify,
+	/// Lines were moved up by one.
 	SwapUp,
+	/// Lines were moved down by one.
 	SwapDown,
+	/// Lines were added.
 	Add,
+	/// Lines were removed.
 	Remove,
+	/// A range of lines was replaced with a different number of lines.
+	Replace,
+	/// Lines were moved to a different position.
+	Move,
 }
@@ -7,6 +7,12 @@ fn history_item_to_string(item: &HistoryItem) -> String {
 	else {
 		format!("{}-{}", item.start_index, item.end_index)
 	};
+	let range = if item.operation == Operation::Move {
+		format!("{}>{}", range, item.destination_index)
+	}
+	else {
+		range
+	};
 	format!(
 		"{:?}[{}] {}",
 		item.operation,
@@ -909,6 +915,125 @@ fn undo_redo_modify_range_up_index_end() {
 	);
 }
 
+#[test]
+fn undo_redo_replace_fewer_lines() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_replace(1, 2, vec![Line::new("drop xxx cx").unwrap()]));
+	let mut lines = create_lines();
+	assert_eq!(history.undo(&mut lines), Some((1, 1)));
+	assert_todo_lines!(lines, "pick aaa c1", "drop xxx cx", "pick ddd c4", "pick eee c5");
+	assert_eq!(history.redo(&mut lines), Some((1, 2)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"pick eee c5"
+	);
+}
+
+#[test]
+fn undo_redo_replace_more_lines() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_replace(4, 4, vec![
+		Line::new("drop xxx cx").unwrap(),
+		Line::new("drop yyy cy").unwrap(),
+	]));
+	let mut lines = create_lines();
+	assert_eq!(history.undo(&mut lines), Some((4, 5)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"drop xxx cx",
+		"drop yyy cy"
+	);
+	assert_eq!(history.redo(&mut lines), Some((4, 4)));
+	assert_todo_lines!(
+		lines,
+		"pick aaa c1",
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick ddd c4",
+		"pick eee c5"
+	);
+}
+
+#[test]
+fn undo_redo_move_down() {
+	let mut history = History::new(10);
+	let mut lines = create_lines();
+	move_range(&mut lines, 0, 1, 2);
+	history.record(HistoryItem::new_move(0, 1, 2));
+	assert_eq!(history.undo(&mut lines), Some((0, 1)));
+	assert_todo_lines!(lines, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+	assert_eq!(history.redo(&mut lines), Some((2, 3)));
+	assert_history_items!(history.undo_history, HistoryItem::new_move(0, 1, 2));
+	assert_todo_lines!(lines, "pick ccc c3", "pick ddd c4", "pick aaa c1", "pick bbb c2", "pick eee c5");
+}
+
+#[test]
+fn undo_redo_move_up() {
+	let mut history = History::new(10);
+	let mut lines = create_lines();
+	move_range(&mut lines, 4, 3, 1);
+	history.record(HistoryItem::new_move(3, 4, 1));
+	assert_eq!(history.undo(&mut lines), Some((3, 4)));
+	assert_todo_lines!(lines, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+}
+
+#[test]
+fn get_entries_descriptions() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_replace(1, 2, vec![Line::new("drop xxx cx").unwrap()]));
+	history.record(HistoryItem::new_modify(0, 0, vec![Line::new("pick aaa c0").unwrap()]));
+	history.record(HistoryItem::new_swap_up(2, 3));
+	let (undo_entries, redo_entries) = history.get_entries(&create_lines());
+	assert_eq!(
+		undo_entries
+			.iter()
+			.map(HistoryEntry::get_description)
+			.collect::<Vec<&str>>(),
+		vec!["Replaced 1 line with 2 lines", "Edited 1 line", "Moved 2 lines up"]
+	);
+	assert_eq!(undo_entries[2].get_start_index(), 1);
+	assert_todo_lines!(undo_entries[2].get_lines(), "pick bbb c2", "pick ccc c3");
+	assert!(redo_entries.is_empty());
+}
+
+#[test]
+fn get_entries_move() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_move(3, 4, 0));
+	let (undo_entries, _) = history.get_entries(&create_lines());
+	assert_eq!(undo_entries[0].get_description(), "Moved 2 lines");
+	assert_eq!(undo_entries[0].get_start_index(), 0);
+	assert_todo_lines!(undo_entries[0].get_lines(), "pick aaa c1", "pick bbb c2");
+}
+
+#[test]
+fn get_entries_redo() {
+	let mut history = History::new(10);
+	history.record(HistoryItem::new_swap_down(0, 0));
+	history.record(HistoryItem::new_remove(1, 1, vec![Line::new("drop xxx cx").unwrap()]));
+	let mut lines = create_lines();
+	history.undo(&mut lines);
+	history.undo(&mut lines);
+	let (undo_entries, redo_entries) = history.get_entries(&lines);
+	assert!(undo_entries.is_empty());
+	assert_eq!(
+		redo_entries
+			.iter()
+			.map(HistoryEntry::get_description)
+			.collect::<Vec<&str>>(),
+		vec!["Moved 1 line down", "Removed 1 line"]
+	);
+	assert_todo_lines!(redo_entries[1].get_lines(), "drop xxx cx");
+}
+
 #[test]
 fn reset() {
 	let mut history = History::new(3);
@@ -2,81 +2,131 @@ use std::convert::TryFrom;
 
 use anyhow::{anyhow, Result};
 
-use crate::todo_file::action::Action;
+use crate::todo_file::{action::Action, fixup_message::FixupMessage};
 
+/// A single line of a rebase todo file.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Line {
 	action: Action,
+	comment: String,
 	content: String,
 	hash: String,
+	kept_lines: Vec<String>,
 	mutated: bool,
+	option: Option<FixupMessage>,
 }
 
 impl Line {
 	fn new_noop() -> Self {
 		Self {
 			action: Action::Noop,
+			comment: String::from(""),
 			content: String::from(""),
 			hash: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new_pick(hash: &str) -> Self {
+	/// Create a `pick` line for a commit.
+	pub fn new_pick(hash: &str, content: &str) -> Self {
 		Self {
 			action: Action::Pick,
-			content: String::from(""),
+			comment: String::from(""),
+			content: String::from(content),
 			hash: String::from(hash),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new_break() -> Self {
+	/// Create a `break` line.
+	pub fn new_break() -> Self {
 		Self {
 			action: Action::Break,
+			comment: String::from(""),
 			content: String::from(""),
 			hash: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new_exec(command: &str) -> Self {
+	/// Create an `exec` line that runs a command.
+	pub fn new_exec(command: &str) -> Self {
 		Self {
 			action: Action::Exec,
+			comment: String::from(""),
 			content: String::from(command),
 			hash: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new_merge(command: &str) -> Self {
+	/// Create a `merge` line from everything after the action, for example `-C abc123 feature # Merge`.
+	pub fn new_merge(command: &str) -> Self {
+		let (option, hash, labels, comment) = Self::split_merge(command);
 		Self {
 			action: Action::Merge,
-			content: String::from(command),
-			hash: String::from(""),
+			comment,
+			content: labels,
+			hash,
+			kept_lines: vec![],
 			mutated: false,
+			option,
 		}
 	}
 
-	pub(crate) fn new_label(label: &str) -> Self {
+	/// Create a `label` line.
+	pub fn new_label(label: &str) -> Self {
 		Self {
 			action: Action::Label,
+			comment: String::from(""),
 			content: String::from(label),
 			hash: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new_reset(label: &str) -> Self {
+	/// Create a `reset` line.
+	pub fn new_reset(label: &str) -> Self {
 		Self {
 			action: Action::Reset,
+			comment: String::from(""),
 			content: String::from(label),
 			hash: String::from(""),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
+		}
+	}
+
+	/// Create an `update-ref` line.
+	pub fn new_update_ref(reference: &str) -> Self {
+		Self {
+			action: Action::UpdateRef,
+			comment: String::from(""),
+			content: String::from(reference),
+			hash: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}
 	}
 
-	pub(crate) fn new(input_line: &str) -> Result<Self> {
+	/// Parse a line of a todo file.
+	///
+	/// # Errors
+	/// Returns an error if the line does not start with a known action or is missing the parts the action
+	/// requires.
+	pub fn new(input_line: &str) -> Result<Self> {
 		if input_line.starts_with("noop") {
 			return Ok(Self::new_noop());
 		}
@@ -91,30 +141,45 @@ impl Line {
 			|| input_line.starts_with('l')
 			|| input_line.starts_with("reset")
 			|| input_line.starts_with('t')
+			|| input_line.starts_with("update-ref")
+			|| input_line.starts_with('u')
 		{
 			let input: Vec<&str> = input_line.splitn(2, ' ').collect();
 			if input.len() == 2 {
+				let action = Action::try_from(input[0])?;
+				if action == Action::Merge {
+					return Ok(Self::new_merge(input[1]));
+				}
 				return Ok(Self {
-					action: Action::try_from(input[0])?,
+					action,
+					comment: String::from(""),
 					hash: String::from(""),
 					content: String::from(input[1]),
+					kept_lines: vec![],
 					mutated: false,
+					option: None,
 				});
 			}
 		}
 		else {
-			let input: Vec<&str> = input_line.splitn(3, ' ').collect();
-			if input.len() >= 2 {
+			let input: Vec<&str> = input_line.splitn(2, ' ').collect();
+			if input.len() == 2 {
+				let action = Action::try_from(input[0])?;
+				let (option, remainder) = if action == Action::Fixup {
+					Self::split_option(input[1])
+				}
+				else {
+					(None, input[1])
+				};
+				let reference: Vec<&str> = remainder.splitn(2, ' ').collect();
 				return Ok(Self {
-					action: Action::try_from(input[0])?,
-					hash: String::from(input[1]),
-					content: if input.len() == 3 {
-						String::from(input[2])
-					}
-					else {
-						String::from("")
-					},
+					action,
+					comment: String::from(""),
+					hash: String::from(reference[0]),
+					content: String::from(*reference.get(1).unwrap_or(&"")),
+					kept_lines: vec![],
 					mutated: false,
+					option,
 				});
 			}
 		}
@@ -122,38 +187,109 @@ impl Line {
 		Err(anyhow!("Invalid line: {}", input_line))
 	}
 
-	pub(crate) fn set_action(&mut self, action: Action) {
+	fn split_option(input: &str) -> (Option<FixupMessage>, &str) {
+		let parts: Vec<&str> = input.splitn(2, ' ').collect();
+		match (FixupMessage::try_from(parts[0]), parts.get(1)) {
+			(Ok(option), Some(remainder)) => (Some(option), remainder),
+			_ => (None, input),
+		}
+	}
+
+	// merge [-C <commit> | -c <commit>] <label> [# <oneline>]
+	fn split_merge(input: &str) -> (Option<FixupMessage>, String, String, String) {
+		let (option, remainder) = Self::split_option(input);
+		let (hash, remainder) = if option.is_some() {
+			let reference: Vec<&str> = remainder.splitn(2, ' ').collect();
+			(String::from(reference[0]), *reference.get(1).unwrap_or(&""))
+		}
+		else {
+			(String::from(""), remainder)
+		};
+		let parts: Vec<&str> = remainder.splitn(2, '#').collect();
+		(
+			option,
+			hash,
+			String::from(parts[0].trim()),
+			String::from(parts.get(1).map_or("", |c| c.trim())),
+		)
+	}
+
+	/// Change the action of the line. Lines with a static action, such as `exec`, can not be changed.
+	pub fn set_action(&mut self, action: Action) {
 		if !self.action.is_static() && self.action != action {
 			self.mutated = true;
 			self.action = action;
+			if action != Action::Fixup {
+				self.option = None;
+			}
+		}
+	}
+
+	/// Set or clear the message option of a `fixup` line.
+	pub fn set_option(&mut self, option: Option<FixupMessage>) {
+		if self.action == Action::Fixup && self.option != option {
+			self.mutated = true;
+			self.option = option;
 		}
 	}
 
-	pub(crate) fn edit_content(&mut self, content: &str) {
-		if self.is_editable() {
+	/// Change the content of an editable line. For a `merge` line this is everything after the action.
+	pub fn edit_content(&mut self, content: &str) {
+		if self.action == Action::Merge {
+			let (option, hash, labels, comment) = Self::split_merge(content);
+			self.option = option;
+			self.hash = hash;
+			self.content = labels;
+			self.comment = comment;
+		}
+		else if self.is_editable() {
 			self.content = String::from(content)
 		}
 	}
 
-	pub(crate) const fn get_action(&self) -> &Action {
+	/// The action of the line.
+	pub const fn get_action(&self) -> &Action {
 		&self.action
 	}
 
-	pub(crate) fn get_content(&self) -> &str {
+	/// The content of the line, the commit summary for commit lines or the argument for other actions.
+	pub fn get_content(&self) -> &str {
 		self.content.as_str()
 	}
 
-	pub(crate) fn get_hash(&self) -> &str {
+	/// The comment after a `merge` line's labels, without the `#`.
+	pub fn get_comment(&self) -> &str {
+		self.comment.as_str()
+	}
+
+	/// The commit reference of the line, empty if the line has no commit.
+	pub fn get_hash(&self) -> &str {
 		self.hash.as_str()
 	}
 
-	pub(crate) fn has_reference(&self) -> bool {
+	/// The message option of a `fixup` or `merge` line.
+	pub const fn get_option(&self) -> Option<FixupMessage> {
+		self.option
+	}
+
+	/// The comments, blank lines and lines that could not be parsed before the line in the todo file.
+	pub fn get_kept_lines(&self) -> &[String] {
+		self.kept_lines.as_slice()
+	}
+
+	pub(crate) fn set_kept_lines(&mut self, kept_lines: Vec<String>) {
+		self.kept_lines = kept_lines;
+	}
+
+	/// Whether the line references a commit.
+	pub fn has_reference(&self) -> bool {
 		!self.hash.is_empty()
 	}
 
-	pub(crate) const fn is_editable(&self) -> bool {
+	/// Whether the content of the line can be edited.
+	pub const fn is_editable(&self) -> bool {
 		match self.action {
-			Action::Exec | Action::Label | Action::Reset | Action::Merge => true,
+			Action::Exec | Action::Label | Action::Reset | Action::Merge | Action::UpdateRef => true,
 			Action::Break
 			| Action::Drop
 			| Action::Edit
@@ -165,15 +301,42 @@ impl Line {
 		}
 	}
 
-	pub(crate) fn to_text(&self) -> String {
+	/// The line as written in a todo file.
+	pub fn to_text(&self) -> String {
+		self.format_text(self.action.as_string())
+	}
+
+	/// The line as written in a todo file, with the action abbreviated.
+	pub fn to_abbreviated_text(&self) -> String {
+		self.format_text(self.action.to_abbreviation())
+	}
+
+	fn format_text(&self, action: String) -> String {
 		match self.action {
 			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
-				format!("{} {} {}", self.action.as_string(), self.hash, self.content)
+				if let Some(option) = self.option {
+					format!("{} {} {} {}", action, option.as_string(), self.hash, self.content)
+				}
+				else {
+					format!("{} {} {}", action, self.hash, self.content)
+				}
 			},
-			Action::Exec | Action::Label | Action::Reset | Action::Merge => {
-				format!("{} {}", self.action.as_string(), self.content)
+			Action::Exec | Action::Label | Action::Reset | Action::UpdateRef => {
+				format!("{} {}", action, self.content)
 			},
-			Action::Noop | Action::Break => self.action.as_string(),
+			Action::Merge => {
+				let mut text = action;
+				if let Some(option) = self.option {
+					text.push_str(format!(" {} {}", option.as_string(), self.hash).as_str());
+				}
+				text.push(' ');
+				text.push_str(self.content.as_str());
+				if !self.comment.is_empty() {
+					text.push_str(format!(" # {}", self.comment).as_str());
+				}
+				text
+			},
+			Action::Noop | Action::Break => action,
 		}
 	}
 }
@@ -189,81 +352,201 @@ mod tests {
 		expected,
 		case::pick_action("pick aaa comment", &Line {
 			action: Action::Pick,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::reword_action("reword aaa comment", &Line {
 			action: Action::Reword,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::edit_action("edit aaa comment", &Line {
 			action: Action::Edit,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::squash_action("squash aaa comment", &Line {
 			action: Action::Squash,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::fixup_action("fixup aaa comment", &Line {
 			action: Action::Fixup,
+			comment: String::from(""),
+			hash: String::from("aaa"),
+			content: String::from("comment"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
+		}),
+		case::fixup_with_upper_c_option("fixup -C aaa comment", &Line {
+			action: Action::Fixup,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: Some(FixupMessage::Use),
+		}),
+		case::fixup_with_lower_c_option("fixup -c aaa comment", &Line {
+			action: Action::Fixup,
+			comment: String::from(""),
+			hash: String::from("aaa"),
+			content: String::from("comment"),
+			kept_lines: vec![],
+			mutated: false,
+			option: Some(FixupMessage::Edit),
+		}),
+		case::fixup_abbreviated_with_option("f -C aaa comment", &Line {
+			action: Action::Fixup,
+			comment: String::from(""),
+			hash: String::from("aaa"),
+			content: String::from("comment"),
+			kept_lines: vec![],
+			mutated: false,
+			option: Some(FixupMessage::Use),
 		}),
 		case::drop_action("drop aaa comment", &Line {
 			action: Action::Drop,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::action_without_comment("pick aaa", &Line {
 			action: Action::Pick,
+			comment: String::from(""),
 			hash: String::from("aaa"),
 			content: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::exec_action("exec command", &Line {
 			action: Action::Exec,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("command"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::label_action("label ref", &Line {
 			action: Action::Label,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("ref"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::reset_action("reset ref", &Line {
 			action: Action::Reset,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("ref"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::reset_action("merge command", &Line {
 			action: Action::Merge,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("command"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
+		}),
+		case::merge_with_upper_c_option("merge -C aaa label # Merge branch 'feature'", &Line {
+			action: Action::Merge,
+			comment: String::from("Merge branch 'feature'"),
+			hash: String::from("aaa"),
+			content: String::from("label"),
+			kept_lines: vec![],
+			mutated: false,
+			option: Some(FixupMessage::Use),
+		}),
+		case::merge_with_lower_c_option("merge -c aaa label", &Line {
+			action: Action::Merge,
+			comment: String::from(""),
+			hash: String::from("aaa"),
+			content: String::from("label"),
+			kept_lines: vec![],
 			mutated: false,
+			option: Some(FixupMessage::Edit),
+		}),
+		case::merge_abbreviated_octopus("m -C aaa one two # Merge branches", &Line {
+			action: Action::Merge,
+			comment: String::from("Merge branches"),
+			hash: String::from("aaa"),
+			content: String::from("one two"),
+			kept_lines: vec![],
+			mutated: false,
+			option: Some(FixupMessage::Use),
+		}),
+		case::merge_without_commit("merge label # Merge #1", &Line {
+			action: Action::Merge,
+			comment: String::from("Merge #1"),
+			hash: String::from(""),
+			content: String::from("label"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
+		}),
+		case::update_ref_action("update-ref refs/heads/main", &Line {
+			action: Action::UpdateRef,
+			comment: String::from(""),
+			hash: String::from(""),
+			content: String::from("refs/heads/main"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
+		}),
+		case::update_ref_abbreviated_action("u refs/heads/main", &Line {
+			action: Action::UpdateRef,
+			comment: String::from(""),
+			hash: String::from(""),
+			content: String::from("refs/heads/main"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
 		}),
 		case::break_action("break", &Line {
 			action: Action::Break,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 		case::nnop( "noop", &Line {
 			action: Action::Noop,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		}),
 	)]
 	fn new(line: &str, expected: &Line) {
@@ -272,11 +555,14 @@ mod tests {
 
 	#[test]
 	fn line_new_pick() {
-		assert_eq!(Line::new_pick("abc123"), Line {
+		assert_eq!(Line::new_pick("abc123", "comment"), Line {
 			action: Action::Pick,
+			comment: String::from(""),
 			hash: String::from("abc123"),
-			content: String::from(""),
+			content: String::from("comment"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		});
 	}
 
@@ -284,9 +570,12 @@ mod tests {
 	fn line_new_break() {
 		assert_eq!(Line::new_break(), Line {
 			action: Action::Break,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from(""),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		});
 	}
 
@@ -294,9 +583,12 @@ mod tests {
 	fn line_new_exec() {
 		assert_eq!(Line::new_exec("command"), Line {
 			action: Action::Exec,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("command"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		});
 	}
 
@@ -304,9 +596,12 @@ mod tests {
 	fn line_new_merge() {
 		assert_eq!(Line::new_merge("command"), Line {
 			action: Action::Merge,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("command"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		});
 	}
 
@@ -314,9 +609,12 @@ mod tests {
 	fn line_new_label() {
 		assert_eq!(Line::new_label("label"), Line {
 			action: Action::Label,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("label"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
 		});
 	}
 
@@ -324,9 +622,25 @@ mod tests {
 	fn line_new_reset() {
 		assert_eq!(Line::new_reset("label"), Line {
 			action: Action::Reset,
+			comment: String::from(""),
 			hash: String::from(""),
 			content: String::from("label"),
+			kept_lines: vec![],
 			mutated: false,
+			option: None,
+		});
+	}
+
+	#[test]
+	fn line_new_update_ref() {
+		assert_eq!(Line::new_update_ref("refs/heads/main"), Line {
+			action: Action::UpdateRef,
+			comment: String::from(""),
+			hash: String::from(""),
+			content: String::from("refs/heads/main"),
+			kept_lines: vec![],
+			mutated: false,
+			option: None,
 		});
 	}
 
@@ -344,7 +658,8 @@ mod tests {
 		case::drop_line_only("drop", "Invalid line: drop"),
 		case::label_line_only("label", "Invalid line: label"),
 		case::reset_line_only("reset", "Invalid line: reset"),
-		case::merge_line_only("merge", "Invalid line: merge")
+		case::merge_line_only("merge", "Invalid line: merge"),
+		case::update_ref_line_only("update-ref", "Invalid line: update-ref")
 	)]
 	fn new_err(line: &str, expected_err: &str) {
 		assert_eq!(Line::new(line).unwrap_err().to_string(), expected_err);
@@ -374,6 +689,7 @@ mod tests {
 		case::label_action(Action::Label, Action::Fixup),
 		case::reset_action(Action::Reset, Action::Fixup),
 		case::merge_action(Action::Merge, Action::Fixup),
+		case::update_ref_action(Action::UpdateRef, Action::Fixup),
 		case::exec(Action::Exec, Action::Fixup),
 		case::noop(Action::Noop, Action::Fixup)
 	)]
@@ -392,6 +708,32 @@ mod tests {
 		assert_eq!(line.mutated, true);
 	}
 
+	#[test]
+	fn set_action_from_fixup_clears_option() {
+		let mut line = Line::new("fixup -C aaa comment").unwrap();
+		line.set_action(Action::Pick);
+		assert_eq!(line.get_option(), None);
+	}
+
+	#[rstest(
+		line,
+		option,
+		expected,
+		mutated,
+		case::add_upper_c("fixup aaa comment", Some(FixupMessage::Use), Some(FixupMessage::Use), true),
+		case::add_lower_c("fixup aaa comment", Some(FixupMessage::Edit), Some(FixupMessage::Edit), true),
+		case::replace("fixup -C aaa comment", Some(FixupMessage::Edit), Some(FixupMessage::Edit), true),
+		case::remove("fixup -c aaa comment", None, None, true),
+		case::unchanged("fixup -c aaa comment", Some(FixupMessage::Edit), Some(FixupMessage::Edit), false),
+		case::not_fixup("pick aaa comment", Some(FixupMessage::Use), None, false)
+	)]
+	fn set_option(line: &str, option: Option<FixupMessage>, expected: Option<FixupMessage>, mutated: bool) {
+		let mut line = Line::new(line).unwrap();
+		line.set_option(option);
+		assert_eq!(line.get_option(), expected);
+		assert_eq!(line.mutated, mutated);
+	}
+
 	#[test]
 	fn set_to_new_action_with_unchanged_action() {
 		let mut line = Line::new("pick aaa comment").unwrap();
@@ -413,7 +755,8 @@ mod tests {
 		case::squash("squash aaa comment", "comment"),
 		case::label("label ref", "new"),
 		case::reset("reset ref", "new"),
-		case::merge("merge command", "new")
+		case::merge("merge command", "new"),
+		case::update_ref("update-ref refs/heads/main", "new")
 	)]
 	fn edit_content(line: &str, expected: &str) {
 		let mut line = Line::new(line).unwrap();
@@ -421,6 +764,26 @@ mod tests {
 		assert_eq!(line.get_content(), expected);
 	}
 
+	#[test]
+	fn edit_content_merge() {
+		let mut line = Line::new("merge -C aaa label # comment").unwrap();
+		line.edit_content("-c bbb other # new comment");
+		assert_eq!(line.get_option(), Some(FixupMessage::Edit));
+		assert_eq!(line.get_hash(), "bbb");
+		assert_eq!(line.get_content(), "other");
+		assert_eq!(line.get_comment(), "new comment");
+	}
+
+	#[test]
+	fn edit_content_merge_remove_commit() {
+		let mut line = Line::new("merge -C aaa label # comment").unwrap();
+		line.edit_content("label");
+		assert_eq!(line.get_option(), None);
+		assert!(!line.has_reference());
+		assert_eq!(line.get_content(), "label");
+		assert_eq!(line.get_comment(), "");
+	}
+
 	#[rstest(
 		line,
 		expected,
@@ -482,7 +845,9 @@ mod tests {
 		case::squash("squash aaa comment", true),
 		case::label("label ref", false),
 		case::reset("reset ref", false),
-		case::merge("merge command", false)
+		case::merge("merge command", false),
+		case::merge_with_commit("merge -C aaa label", true),
+		case::update_ref("update-ref refs/heads/main", false)
 	)]
 	fn has_reference(line: &str, expected: bool) {
 		assert_eq!(Line::new(line).unwrap().has_reference(), expected);
@@ -502,7 +867,8 @@ mod tests {
 		case::squash(Action::Exec, true),
 		case::squash(Action::Label, true),
 		case::squash(Action::Reset, true),
-		case::squash(Action::Merge, true)
+		case::squash(Action::Merge, true),
+		case::update_ref(Action::UpdateRef, true)
 	)]
 	fn is_editable(from: Action, editable: bool) {
 		let line = Line::new(format!("{} aaa bbb", from.as_string()).as_str()).unwrap();
@@ -516,11 +882,39 @@ mod tests {
 		case::edit("edit aaa comment"),
 		case::exec("exec git commit --amend 'foo'"),
 		case::fixup("fixup aaa comment"),
+		case::fixup_with_upper_c_option("fixup -C aaa comment"),
+		case::fixup_with_lower_c_option("fixup -c aaa comment"),
 		case::pick("pick aaa comment"),
 		case::reword("reword aaa comment"),
-		case::squash("squash aaa comment")
+		case::squash("squash aaa comment"),
+		case::merge("merge label"),
+		case::merge_with_comment("merge label # Merge branch 'feature'"),
+		case::merge_with_upper_c_option("merge -C aaa label # Merge branch 'feature'"),
+		case::merge_with_lower_c_option("merge -c aaa one two"),
+		case::update_ref("update-ref refs/heads/main")
 	)]
 	fn to_text(line: &str) {
 		assert_eq!(Line::new(line).unwrap().to_text(), line);
 	}
+
+	#[rstest(
+		line,
+		expected,
+		case::break_action("break", "b"),
+		case::drop("drop aaa comment", "d aaa comment"),
+		case::edit("edit aaa comment", "e aaa comment"),
+		case::exec("exec git commit --amend 'foo'", "x git commit --amend 'foo'"),
+		case::fixup("fixup aaa comment", "f aaa comment"),
+		case::fixup_with_option("fixup -C aaa comment", "f -C aaa comment"),
+		case::pick("pick aaa comment", "p aaa comment"),
+		case::reword("reword aaa comment", "r aaa comment"),
+		case::squash("squash aaa comment", "s aaa comment"),
+		case::label("label ref", "l ref"),
+		case::reset("reset ref", "t ref"),
+		case::merge("merge -C aaa ref # comment", "m -C aaa ref # comment"),
+		case::update_ref("update-ref refs/heads/main", "u refs/heads/main")
+	)]
+	fn to_abbreviated_text(line: &str, expected: &str) {
+		assert_eq!(Line::new(line).unwrap().to_abbreviated_text(), expected);
+	}
 }
@@ -1,6 +1,8 @@
 use std::{
-	fs::{read_to_string, File},
-	io::Write,
+	cmp::{max, min},
+	collections::{HashMap, HashSet},
+	fs::{read_to_string, remove_file, rename, File},
+	io::{ErrorKind, Write},
 	path::Path,
 	slice::Iter,
 };
@@ -10,40 +12,77 @@ use anyhow::{anyhow, Result};
 use line::Line;
 
 use crate::todo_file::{
+	autosquash::{autosquash, find_orphaned_fixups},
 	edit_content::EditContext,
-	history::{history_item::HistoryItem, History},
-	utils::{remove_range, swap_range_down, swap_range_up},
+	history::{history_entry::HistoryEntry, history_item::HistoryItem, History},
+	utils::{add_range, move_range, remove_range, stable_hash, swap_range_down, swap_range_up},
+	validator::{validate, Problem},
 };
 
+/// The actions of todo file lines.
 pub mod action;
+mod autosquash;
+/// Changes to apply to a range of lines.
 pub mod edit_content;
-mod history;
+/// The message options of `fixup` and `merge` lines.
+pub mod fixup_message;
+/// The undo and redo history.
+pub mod history;
+/// Parsing and formatting of todo file lines.
 pub mod line;
 mod utils;
+/// Problems in the todo file that git would reject.
+pub mod validator;
 
+/// A rebase todo file, with the selected line, edit operations and an undo history.
 pub struct TodoFile {
+	abbreviate_commands: bool,
+	autosave_key: Option<String>,
 	comment_char: String,
+	done_lines: Vec<Line>,
 	filepath: String,
 	history: History,
 	is_noop: bool,
 	lines: Vec<Line>,
+	original_commits: Option<Vec<Line>>,
+	selected_done_line_index: Option<usize>,
 	selected_line_index: usize,
+	// the kept lines after the last line, such as the help that git writes, which are not before any line
+	trailing_lines: Vec<String>,
 }
 
 impl TodoFile {
-	pub(crate) fn new(path: &str, undo_limit: u32, comment_char: &str) -> Self {
+	/// Create a todo file for the path, keeping up to `undo_limit` changes, where lines starting with
+	/// `comment_char` are comments.
+	pub fn new(path: &str, undo_limit: u32, comment_char: &str) -> Self {
 		Self {
+			abbreviate_commands: false,
+			autosave_key: None,
 			comment_char: String::from(comment_char),
+			done_lines: vec![],
 			filepath: path.to_owned(),
 			history: History::new(undo_limit),
 			lines: vec![],
 			is_noop: false,
+			original_commits: None,
+			selected_done_line_index: None,
 			selected_line_index: 0,
+			trailing_lines: vec![],
 		}
 	}
 
-	pub(crate) fn set_lines(&mut self, lines: Vec<Line>) {
-		self.is_noop = !lines.is_empty() && lines[0].get_action() == &Action::Noop;
+	/// Write the actions abbreviated, as `rebase.abbreviateCommands` does.
+	pub fn set_abbreviate_commands(&mut self, abbreviate_commands: bool) {
+		self.abbreviate_commands = abbreviate_commands;
+	}
+
+	fn is_noop_lines(lines: &[Line]) -> bool {
+		!lines.is_empty() && lines[0].get_action() == &Action::Noop
+	}
+
+	/// Replace the lines, resetting the undo history. The first lines set are remembered as the original commits.
+	pub fn set_lines(&mut self, lines: Vec<Line>) {
+		self.is_noop = Self::is_noop_lines(&lines);
 		self.lines = if self.is_noop {
 			vec![]
 		}
@@ -53,53 +92,265 @@ impl TodoFile {
 		if self.selected_line_index >= self.lines.len() {
 			self.selected_line_index = if self.lines.is_empty() { 0 } else { self.lines.len() - 1 };
 		}
+		// only the first set of lines is what git originally wrote
+		if self.original_commits.is_none() {
+			self.original_commits = Some(
+				self.lines
+					.iter()
+					.filter(|l| l.has_reference() && !l.get_action().is_static())
+					.cloned()
+					.collect(),
+			);
+			self.autosave_key = Some(format!("{:016x}", stable_hash(&self.lines)));
+		}
 		self.history.reset();
 	}
 
-	pub(crate) fn load_file(&mut self) -> Result<()> {
-		let lines = read_to_string(Path::new(&self.filepath))
-			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?
-			.lines()
-			.filter_map(|l| {
-				if l.starts_with(self.comment_char.as_str()) || l.is_empty() {
-					None
-				}
-				else {
-					Some(Line::new(l).map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err)))
+	// comments, blank lines and lines that could not be parsed are kept with the line after them, so they move with
+	// that line, and the kept lines after the last line are returned separately
+	fn parse_lines(&self, contents: &str) -> (Vec<Line>, Vec<String>) {
+		let mut lines = vec![];
+		let mut kept_lines = vec![];
+		for l in contents.lines() {
+			let line = if l.starts_with(self.comment_char.as_str()) {
+				None
+			}
+			else {
+				match Line::new(l) {
+					Ok(line) => Some(line),
+					// an exec without a command is kept as a line, so it is reported instead of hidden
+					Err(_) if matches!(l.trim(), "exec" | "x") => Some(Line::new_exec("")),
+					Err(_) => None,
 				}
-			})
-			.collect::<Result<Vec<Line>>>()?;
+			};
+			if let Some(mut line) = line {
+				line.set_kept_lines(kept_lines);
+				kept_lines = vec![];
+				lines.push(line);
+			}
+			else {
+				kept_lines.push(String::from(l));
+			}
+		}
+		(lines, kept_lines)
+	}
+
+	fn lines_to_text(lines: &[Line], abbreviate_commands: bool) -> Vec<String> {
+		let mut file_lines = vec![];
+		for l in lines {
+			file_lines.extend(l.get_kept_lines().iter().cloned());
+			file_lines.push(if abbreviate_commands {
+				l.to_abbreviated_text()
+			}
+			else {
+				l.to_text()
+			});
+		}
+		file_lines
+	}
+
+	/// Read and parse the todo file, resetting the undo history. When the file is the `rebase-merge/git-rebase-todo`
+	/// file of a rebase that is already in progress, the lines that have already been applied are read from the `done`
+	/// file next to it.
+	///
+	/// # Errors
+	/// Returns an error if the file can not be read.
+	pub fn load_file(&mut self) -> Result<()> {
+		let contents = read_to_string(Path::new(&self.filepath))
+			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?;
+		let (lines, trailing_lines) = self.parse_lines(contents.as_str());
 		self.set_lines(lines);
+		self.trailing_lines = trailing_lines;
+		self.load_done_file();
 		Ok(())
 	}
 
-	pub(crate) fn write_file(&self) -> Result<()> {
+	// the done lines are only informational, so a done file that can not be read is the same as no done lines
+	fn load_done_file(&mut self) {
+		let todo_path = Path::new(&self.filepath);
+		let is_rebase_todo = todo_path.file_name().map_or(false, |name| name == "git-rebase-todo")
+			&& todo_path
+				.parent()
+				.and_then(Path::file_name)
+				.map_or(false, |name| name == "rebase-merge");
+		let done_lines = if is_rebase_todo {
+			read_to_string(todo_path.with_file_name("done"))
+				.map(|contents| self.parse_lines(contents.as_str()).0)
+				.unwrap_or_default()
+		}
+		else {
+			vec![]
+		};
+		self.set_done_lines(done_lines);
+	}
+
+	/// Read the todo file after it was changed outside of this model, recording the changes so they can be undone.
+	///
+	/// # Errors
+	/// Returns an error if the file can not be read.
+	pub fn reload_file(&mut self) -> Result<()> {
+		let contents = read_to_string(Path::new(&self.filepath))
+			.map_err(|err| anyhow!("Error reading file: {}", self.filepath).context(err))?;
+		let (lines, trailing_lines) = self.parse_lines(contents.as_str());
+		self.trailing_lines = trailing_lines;
+		if Self::is_noop_lines(&lines) {
+			self.set_lines(lines);
+		}
+		else {
+			self.replace_lines(lines);
+		}
+		Ok(())
+	}
+
+	fn is_same_text(a: &Line, b: &Line) -> bool {
+		a.to_text() == b.to_text() && a.get_kept_lines() == b.get_kept_lines()
+	}
+
+	/// Replace the lines, recording the changed range as a single change in the undo history.
+	pub fn replace_lines(&mut self, lines: Vec<Line>) {
+		let lines: Vec<Line> = lines.into_iter().filter(|l| l.get_action() != &Action::Noop).collect();
+		self.is_noop = false;
+		let prefix = self
+			.lines
+			.iter()
+			.zip(lines.iter())
+			.take_while(|&(a, b)| Self::is_same_text(a, b))
+			.count();
+		let suffix = self.lines[prefix..]
+			.iter()
+			.rev()
+			.zip(lines[prefix..].iter().rev())
+			.take_while(|&(a, b)| Self::is_same_text(a, b))
+			.count();
+		let old_length = self.lines.len() - prefix - suffix;
+		let new_length = lines.len() - prefix - suffix;
+
+		let removed_lines: Vec<Line> = self
+			.lines
+			.splice(prefix..(prefix + old_length), lines[prefix..(prefix + new_length)].iter().cloned())
+			.collect();
+		if new_length == 0 && old_length > 0 {
+			self.history
+				.record(HistoryItem::new_remove(prefix, prefix + old_length - 1, removed_lines));
+		}
+		else if old_length == 0 && new_length > 0 {
+			self.history.record(HistoryItem::new_add(prefix, prefix + new_length - 1));
+		}
+		else if old_length > 0 {
+			self.history
+				.record(HistoryItem::new_replace(prefix, prefix + new_length - 1, removed_lines));
+		}
+		self.set_selected_line_index(self.selected_line_index);
+	}
+
+	/// Write the lines to the todo file.
+	///
+	/// # Errors
+	/// Returns an error if the file can not be written.
+	pub fn write_file(&self) -> Result<()> {
 		let mut file = File::create(&self.filepath)
 			.map_err(|err| anyhow!(err).context(anyhow!("Error opening file: {}", self.filepath)))?;
 		let file_contents = if self.is_noop {
 			String::from("noop")
 		}
 		else {
-			self.lines.iter().map(Line::to_text).collect::<Vec<String>>().join("\n")
+			let mut file_lines = Self::lines_to_text(&self.lines, self.abbreviate_commands);
+			file_lines.extend(self.trailing_lines.iter().cloned());
+			file_lines.join("\n")
 		};
 		writeln!(file, "{}", file_contents)
 			.map_err(|err| anyhow!(err).context(anyhow!("Error writing file: {}", self.filepath)))?;
 		Ok(())
 	}
 
-	pub(crate) fn set_selected_line_index(&mut self, selected_line_index: usize) {
-		self.selected_line_index = if self.lines.is_empty() {
-			0
+	/// The path of the autosave file, next to the todo file.
+	pub fn get_autosave_path(&self) -> String {
+		format!("{}.autosave", self.filepath)
+	}
+
+	// the autosaved lines, only if written for the same original todo file and different from the current lines
+	fn read_autosave(&self) -> Option<Vec<Line>> {
+		let contents = read_to_string(self.get_autosave_path()).ok()?;
+		let (key, body) = contents.split_at(contents.find('\n').unwrap_or_else(|| contents.len()));
+		if self.autosave_key.as_deref() != Some(key) {
+			return None;
 		}
-		else if selected_line_index >= self.lines.len() {
-			self.lines.len() - 1
+		let (lines, _) = self.parse_lines(body.trim_start_matches('\n'));
+		if lines == self.lines {
+			return None;
 		}
-		else {
-			selected_line_index
+		Some(lines)
+	}
+
+	/// Whether an autosave of the same original todo file exists, with lines that differ from the current lines.
+	pub fn has_autosave(&self) -> bool {
+		self.read_autosave().is_some()
+	}
+
+	/// Write the current lines to the autosave file.
+	///
+	/// The lines are written to a temporary file first, so an existing autosave is only replaced once the write has
+	/// finished.
+	///
+	/// # Errors
+	/// Returns an error if the file can not be written.
+	pub fn write_autosave(&self) -> Result<()> {
+		let autosave_path = self.get_autosave_path();
+		let temporary_path = format!("{}.tmp", autosave_path);
+		let mut file = File::create(&temporary_path)
+			.map_err(|err| anyhow!(err).context(anyhow!("Error opening file: {}", temporary_path)))?;
+		let file_contents = Self::lines_to_text(&self.lines, false).join("\n");
+		writeln!(
+			file,
+			"{}\n{}",
+			self.autosave_key.as_deref().unwrap_or(""),
+			file_contents
+		)
+		.map_err(|err| anyhow!(err).context(anyhow!("Error writing file: {}", temporary_path)))?;
+		rename(&temporary_path, &autosave_path)
+			.map_err(|err| anyhow!(err).context(anyhow!("Error writing file: {}", autosave_path)))?;
+		Ok(())
+	}
+
+	/// Replace the lines with the autosaved lines.
+	///
+	/// # Errors
+	/// Returns an error if there is no matching autosave.
+	pub fn restore_autosave(&mut self) -> Result<()> {
+		let lines = self
+			.read_autosave()
+			.ok_or_else(|| anyhow!("No autosave found for: {}", self.filepath))?;
+		self.set_lines(lines);
+		Ok(())
+	}
+
+	/// Remove the autosave file, if it exists.
+	///
+	/// # Errors
+	/// Returns an error if the file exists and can not be removed.
+	pub fn remove_autosave(&self) -> Result<()> {
+		let autosave_path = self.get_autosave_path();
+		match remove_file(&autosave_path) {
+			Err(err) if err.kind() != ErrorKind::NotFound => {
+				Err(anyhow!(err).context(anyhow!("Error removing file: {}", autosave_path)))
+			},
+			_ => Ok(()),
 		}
 	}
 
-	pub(crate) fn swap_range_up(&mut self, start_index: usize, end_index: usize) -> bool {
+	/// Replace the lines that have already been applied by an in progress rebase.
+	pub fn set_done_lines(&mut self, lines: Vec<Line>) {
+		self.done_lines = lines;
+		self.selected_done_line_index = None;
+	}
+
+	/// The lines that have already been applied by an in progress rebase, the last being where the rebase stopped.
+	pub fn get_done_lines(&self) -> &[Line] {
+		self.done_lines.as_slice()
+	}
+
+	/// Move a range of lines up by one, returning false if the range is already at the top.
+	pub fn swap_range_up(&mut self, start_index: usize, end_index: usize) -> bool {
 		if end_index == 0 || start_index == 0 || self.lines.is_empty() {
 			return false;
 		}
@@ -118,7 +369,8 @@ impl TodoFile {
 		true
 	}
 
-	pub(crate) fn swap_range_down(&mut self, start_index: usize, end_index: usize) -> bool {
+	/// Move a range of lines down by one, returning false if the range is already at the bottom.
+	pub fn swap_range_down(&mut self, start_index: usize, end_index: usize) -> bool {
 		let len = self.lines.len();
 		let max_index = if len == 0 { 0 } else { len - 1 };
 
@@ -131,7 +383,86 @@ impl TodoFile {
 		true
 	}
 
-	pub(crate) fn add_line(&mut self, index: usize, line: Line) {
+	/// Stably reorder a range of lines by a key for each line in the range, as a single change in the undo history.
+	/// Lines without a key, such as an `exec` after a `pick`, stay with the line above them. A range with a `label`,
+	/// `reset` or `merge` line is not sorted, since the commits around them belong to different branches. Returns false
+	/// if the order did not change.
+	pub fn sort_range<K: Ord>(&mut self, start_index: usize, end_index: usize, keys: &[Option<K>]) -> bool {
+		let first_index = min(start_index, end_index);
+		let last_index = max(start_index, end_index);
+		if last_index >= self.lines.len()
+			|| keys.len() != last_index - first_index + 1
+			|| self.lines[first_index..=last_index]
+				.iter()
+				.any(|line| matches!(*line.get_action(), Action::Label | Action::Merge | Action::Reset))
+		{
+			return false;
+		}
+
+		let mut groups: Vec<(Option<&K>, Vec<Line>)> = vec![];
+		for (line, key) in self.lines[first_index..=last_index].iter().zip(keys.iter()) {
+			match (key, groups.last_mut()) {
+				(None, Some(group)) => group.1.push(line.clone()),
+				_ => groups.push((key.as_ref(), vec![line.clone()])),
+			}
+		}
+		groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+		let mut lines = self.lines[..first_index].to_vec();
+		lines.extend(groups.into_iter().flat_map(|(_, group)| group));
+		lines.extend_from_slice(&self.lines[(last_index + 1)..]);
+		if lines.iter().map(Line::to_text).eq(self.lines.iter().map(Line::to_text)) {
+			return false;
+		}
+		self.replace_lines(lines);
+		true
+	}
+
+	/// Move a range of lines so they are before the line at an index, as a single change in the undo history. An
+	/// index past the last line moves the lines to the end. Returns false if the lines would not move.
+	pub fn move_range(&mut self, start_index: usize, end_index: usize, index: usize) -> bool {
+		let first_index = min(start_index, end_index);
+		let last_index = max(start_index, end_index);
+		if last_index >= self.lines.len() || (index >= first_index && index <= last_index + 1) {
+			return false;
+		}
+
+		let index = min(index, self.lines.len());
+		// the index of the line the range is moved before changes once the range is removed
+		let destination_index = if index > last_index {
+			index - (last_index - first_index + 1)
+		}
+		else {
+			index
+		};
+		move_range(&mut self.lines, first_index, last_index, destination_index);
+		self.history
+			.record(HistoryItem::new_move(first_index, last_index, destination_index));
+		true
+	}
+
+	/// Insert lines at an index, as a single change in the undo history. The kept lines of the inserted lines are not
+	/// inserted, since they belong to the lines the inserted lines were copied from.
+	pub fn add_lines(&mut self, index: usize, lines: &[Line]) {
+		if lines.is_empty() {
+			return;
+		}
+		let lines: Vec<Line> = lines
+			.iter()
+			.cloned()
+			.map(|mut line| {
+				line.set_kept_lines(vec![]);
+				line
+			})
+			.collect();
+		let start = min(index, self.lines.len());
+		let end = start + lines.len() - 1;
+		add_range(&mut self.lines, &lines, start, end);
+		self.history.record(HistoryItem::new_add(start, end));
+	}
+
+	/// Insert a line at an index.
+	pub fn add_line(&mut self, index: usize, line: Line) {
 		let i = if index > self.lines.len() {
 			self.lines.len()
 		}
@@ -142,7 +473,8 @@ impl TodoFile {
 		self.history.record(HistoryItem::new_add(i, i));
 	}
 
-	pub(crate) fn remove_lines(&mut self, start_index: usize, end_index: usize) {
+	/// Remove a range of lines.
+	pub fn remove_lines(&mut self, start_index: usize, end_index: usize) {
 		if self.lines.is_empty() {
 			return;
 		}
@@ -160,7 +492,8 @@ impl TodoFile {
 		self.history.record(HistoryItem::new_remove(start, end, removed_lines));
 	}
 
-	pub(crate) fn update_range(&mut self, start_index: usize, end_index: usize, edit_context: &EditContext) {
+	/// Apply the changes in an edit context to a range of lines.
+	pub fn update_range(&mut self, start_index: usize, end_index: usize, edit_context: &EditContext) {
 		if self.lines.is_empty() {
 			return;
 		}
@@ -187,66 +520,343 @@ impl TodoFile {
 			if let Some(content) = edit_context.get_content().as_ref() {
 				line.edit_content(content);
 			}
+
+			if let Some(option) = edit_context.get_option().as_ref() {
+				line.set_option(*option);
+			}
 		}
 		self.history.record(HistoryItem::new_modify(start, end, lines));
 	}
 
-	pub(crate) fn undo(&mut self) -> Option<(usize, usize)> {
+	/// Undo the last change, returning the range of lines that changed.
+	pub fn undo(&mut self) -> Option<(usize, usize)> {
 		self.history.undo(&mut self.lines)
 	}
 
-	pub(crate) fn redo(&mut self) -> Option<(usize, usize)> {
+	/// Redo the last undone change, returning the range of lines that changed.
+	pub fn redo(&mut self) -> Option<(usize, usize)> {
 		self.history.redo(&mut self.lines)
 	}
 
-	pub(crate) fn get_selected_line(&self) -> Option<&Line> {
-		self.lines.get(self.selected_line_index)
+	/// The path of the todo file.
+	pub fn get_filepath(&self) -> &str {
+		self.filepath.as_str()
 	}
 
-	pub(crate) fn get_max_selected_line_index(&self) -> usize {
-		let len = self.lines.len();
-		if len == 0 {
-			0
+	/// The line at an index.
+	pub fn get_line(&self, index: usize) -> Option<&Line> {
+		self.lines.get(index)
+	}
+
+	/// A copy of all the lines.
+	pub fn get_lines_owned(&self) -> Vec<Line> {
+		self.lines.to_owned()
+	}
+
+	/// Whether the todo file was a `noop` rebase.
+	pub const fn is_noop(&self) -> bool {
+		self.is_noop
+	}
+
+	/// Iterate over the lines.
+	pub fn iter(&self) -> Iter<'_, Line> {
+		self.lines.iter()
+	}
+
+	/// Whether there are no lines.
+	pub fn is_empty(&self) -> bool {
+		self.lines.is_empty()
+	}
+
+	/// Describe the changes that can be undone, oldest first, and redone, next redo first.
+	pub fn get_history_entries(&self) -> (Vec<HistoryEntry>, Vec<HistoryEntry>) {
+		self.history.get_entries(&self.lines)
+	}
+
+	/// Move each `fixup!`, `squash!` and `amend!` commit directly under the earlier commit it targets, setting the
+	/// matching action on picked commits, as a single change in the undo history. Returns false if nothing needed to
+	/// move.
+	pub fn autosquash(&mut self) -> bool {
+		let lines = autosquash(&self.lines);
+		if lines.iter().map(Line::to_text).eq(self.lines.iter().map(Line::to_text)) {
+			return false;
 		}
-		else {
-			len - 1
+		self.replace_lines(lines);
+		true
+	}
+
+	/// Move lines below a target line above them and mark them as fixups, as a single change in the undo history.
+	/// Each pair is the index of a line and the index of its target. The moved lines are placed after any fixups
+	/// already below the target. Returns false if no line could be moved.
+	pub fn fixup_into(&mut self, fixups: &[(usize, usize)]) -> bool {
+		let mut targets: HashMap<usize, Vec<usize>> = HashMap::new();
+		for &(index, target_index) in fixups {
+			if index < self.lines.len() && target_index < index {
+				targets.entry(target_index).or_default().push(index);
+			}
+		}
+		let moved: HashSet<usize> = targets.values().flatten().copied().collect();
+		if moved.is_empty() || targets.keys().any(|target_index| moved.contains(target_index)) {
+			return false;
+		}
+
+		let mut lines = vec![];
+		let mut pending: Vec<Line> = vec![];
+		for (index, line) in self.lines.iter().enumerate() {
+			if moved.contains(&index) {
+				continue;
+			}
+			if !matches!(*line.get_action(), Action::Fixup | Action::Squash) {
+				lines.append(&mut pending);
+			}
+			lines.push(line.clone());
+			if let Some(group) = targets.get(&index) {
+				pending.extend(group.iter().map(|&moved_index| {
+					let mut moved_line = self.lines[moved_index].clone();
+					moved_line.set_action(Action::Fixup);
+					moved_line
+				}));
+			}
 		}
+		lines.append(&mut pending);
+		self.replace_lines(lines);
+		true
 	}
 
-	pub(crate) const fn get_selected_line_index(&self) -> usize {
-		self.selected_line_index
+	/// Mark lines as dropped, as a single change in the undo history. Returns false if no line changed.
+	pub fn drop_lines(&mut self, indexes: &[usize]) -> bool {
+		let mut lines = self.lines.clone();
+		for &index in indexes {
+			if let Some(line) = lines.get_mut(index) {
+				line.set_action(Action::Drop);
+			}
+		}
+		if lines.iter().map(Line::to_text).eq(self.lines.iter().map(Line::to_text)) {
+			return false;
+		}
+		self.replace_lines(lines);
+		true
 	}
 
-	pub(crate) fn get_filepath(&self) -> &str {
-		self.filepath.as_str()
+	/// Apply the changes in an edit context to a set of lines, that do not need to be next to each other, as a single
+	/// change in the undo history. Returns false if no line changed.
+	pub fn update_lines(&mut self, indexes: &[usize], edit_context: &EditContext) -> bool {
+		let mut lines = self.lines.clone();
+		for &index in indexes {
+			if let Some(line) = lines.get_mut(index) {
+				if let Some(action) = edit_context.get_action().as_ref() {
+					line.set_action(*action);
+				}
+				if let Some(content) = edit_context.get_content().as_ref() {
+					line.edit_content(content);
+				}
+				if let Some(option) = edit_context.get_option().as_ref() {
+					line.set_option(*option);
+				}
+			}
+		}
+		if lines.iter().map(Line::to_text).eq(self.lines.iter().map(Line::to_text)) {
+			return false;
+		}
+		self.replace_lines(lines);
+		true
 	}
 
-	pub(crate) fn get_line(&self, index: usize) -> Option<&Line> {
-		self.lines.get(index)
+	/// Remove a set of lines, that do not need to be next to each other, as a single change in the undo history.
+	/// Returns false if no line was removed.
+	pub fn remove_lines_at(&mut self, indexes: &[usize]) -> bool {
+		let lines: Vec<Line> = self
+			.lines
+			.iter()
+			.enumerate()
+			.filter(|&(index, _)| !indexes.contains(&index))
+			.map(|(_, line)| line.clone())
+			.collect();
+		if lines.len() == self.lines.len() {
+			return false;
+		}
+		self.replace_lines(lines);
+		true
 	}
 
-	pub(crate) fn get_lines_owned(&self) -> Vec<Line> {
-		self.lines.to_owned()
+	/// Insert an `exec` line after each commit in a range of lines, as a single change in the undo history, similar to
+	/// `git rebase --exec`. The line is placed after any `fixup` or `squash` lines of the commit, and is not added
+	/// again where the same command already follows the commit. Returns false if no line was added.
+	pub fn add_exec_after_commits(&mut self, start_index: usize, end_index: usize, command: &str) -> bool {
+		let first_index = min(start_index, end_index);
+		let last_index = min(max(start_index, end_index), self.lines.len().saturating_sub(1));
+		let indexes: Vec<usize> = (first_index..=last_index).collect();
+		self.add_exec_after_lines(&indexes, command)
 	}
 
-	pub(crate) const fn is_noop(&self) -> bool {
-		self.is_noop
+	/// Insert an `exec` line after each commit in a set of lines, in the same way as `add_exec_after_commits`.
+	pub fn add_exec_after_lines(&mut self, indexes: &[usize], command: &str) -> bool {
+		let is_commit = |line: &Line| {
+			matches!(
+				*line.get_action(),
+				Action::Edit | Action::Fixup | Action::Merge | Action::Pick | Action::Reword | Action::Squash
+			)
+		};
+
+		let mut insert_after = HashSet::new();
+		for &index in indexes {
+			if !self.lines.get(index).map_or(false, is_commit) {
+				continue;
+			}
+			let mut group_end = index;
+			while self
+				.lines
+				.get(group_end + 1)
+				.map_or(false, |line| matches!(*line.get_action(), Action::Fixup | Action::Squash))
+			{
+				group_end += 1;
+			}
+			let has_exec = self.lines[(group_end + 1)..]
+				.iter()
+				.take_while(|line| *line.get_action() == Action::Exec)
+				.any(|line| line.get_content() == command);
+			if !has_exec {
+				let _inserted = insert_after.insert(group_end);
+			}
+		}
+		if insert_after.is_empty() {
+			return false;
+		}
+
+		let mut lines = vec![];
+		for (index, line) in self.lines.iter().enumerate() {
+			lines.push(line.clone());
+			if insert_after.contains(&index) {
+				lines.push(Line::new_exec(command));
+			}
+		}
+		self.replace_lines(lines);
+		true
 	}
 
-	pub fn iter(&self) -> Iter<'_, Line> {
-		self.lines.iter()
+	/// Remove all `exec` lines with a command, as a single change in the undo history. Returns false if no line was
+	/// removed.
+	pub fn remove_exec_lines(&mut self, command: &str) -> bool {
+		let lines: Vec<Line> = self
+			.lines
+			.iter()
+			.filter(|line| *line.get_action() != Action::Exec || line.get_content() != command)
+			.cloned()
+			.collect();
+		if lines.len() == self.lines.len() {
+			return false;
+		}
+		self.replace_lines(lines);
+		true
 	}
 
-	pub(crate) fn is_empty(&self) -> bool {
-		self.lines.is_empty()
+	/// The indexes of `fixup!`, `squash!` and `amend!` commits that have no target commit in the todo file.
+	pub fn get_orphaned_fixups(&self) -> Vec<usize> {
+		find_orphaned_fixups(&self.lines)
+	}
+
+	/// Find the lines that git would reject during the rebase.
+	pub fn get_problems(&self) -> Vec<Problem> {
+		validate(&self.lines)
+	}
+
+	/// Commits from the original lines that have been removed, rather than being marked as dropped.
+	pub fn get_missing_commits(&self) -> Vec<Line> {
+		self.original_commits.as_ref().map_or_else(Vec::new, |original_commits| {
+			original_commits
+				.iter()
+				.filter(|original| {
+					!self.lines.iter().any(|l| {
+						l.has_reference()
+							&& (l.get_hash().starts_with(original.get_hash())
+								|| original.get_hash().starts_with(l.get_hash()))
+					})
+				})
+				.cloned()
+				.collect()
+		})
+	}
+
+	/// Add `drop` lines for the missing commits.
+	pub fn drop_missing_commits(&mut self) {
+		let missing_commits = self.get_missing_commits();
+		if missing_commits.is_empty() {
+			return;
+		}
+		let start = self.lines.len();
+		for mut line in missing_commits {
+			line.set_action(Action::Drop);
+			self.lines.push(line);
+		}
+		self.history.record(HistoryItem::new_add(start, self.lines.len() - 1));
+	}
+}
+
+// the selected line is the cursor of the list, which is not part of the library, so the library does not use it
+#[allow(dead_code)]
+impl TodoFile {
+	/// Select one of the applied lines instead of a todo line, limited to the applied lines. `None` returns the
+	/// selection to the todo lines.
+	pub(crate) fn set_selected_done_line_index(&mut self, index: Option<usize>) {
+		self.selected_done_line_index = if self.done_lines.is_empty() {
+			None
+		}
+		else {
+			index.map(|i| min(i, self.done_lines.len() - 1))
+		};
+	}
+
+	/// The index of the selected applied line, if one is selected.
+	pub(crate) const fn get_selected_done_line_index(&self) -> Option<usize> {
+		self.selected_done_line_index
+	}
+
+	/// The selected applied line, if one is selected.
+	pub(crate) fn get_selected_done_line(&self) -> Option<&Line> {
+		self.selected_done_line_index.and_then(|i| self.done_lines.get(i))
+	}
+
+	/// Set the selected line, limited to the lines in the file.
+	pub(crate) fn set_selected_line_index(&mut self, selected_line_index: usize) {
+		self.selected_line_index = if self.lines.is_empty() {
+			0
+		}
+		else if selected_line_index >= self.lines.len() {
+			self.lines.len() - 1
+		}
+		else {
+			selected_line_index
+		}
+	}
+
+	/// The selected line, if there are any lines.
+	pub(crate) fn get_selected_line(&self) -> Option<&Line> {
+		self.lines.get(self.selected_line_index)
+	}
+
+	/// The index of the last line that can be selected.
+	pub(crate) fn get_max_selected_line_index(&self) -> usize {
+		let len = self.lines.len();
+		if len == 0 {
+			0
+		}
+		else {
+			len - 1
+		}
+	}
+
+	/// The index of the selected line.
+	pub(crate) const fn get_selected_line_index(&self) -> usize {
+		self.selected_line_index
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use tempfile::{Builder, NamedTempFile};
+	use tempfile::{tempdir, Builder, NamedTempFile, TempDir};
 
 	use super::*;
+	use crate::todo_file::fixup_message::FixupMessage;
 
 	fn create_and_load_todo_file(file_contents: &[&str]) -> (TodoFile, NamedTempFile) {
 		let todo_file_path = Builder::new()
@@ -286,6 +896,373 @@ mod tests {
 		assert_todo_lines!(todo_file, "pick aaa foobar");
 	}
 
+	fn create_and_load_in_progress_todo_file(done_contents: &[&str]) -> (TodoFile, TempDir) {
+		let git_dir = tempdir().unwrap();
+		let rebase_dir = git_dir.path().join("rebase-merge");
+		std::fs::create_dir(&rebase_dir).unwrap();
+		let todo_file_path = rebase_dir.join("git-rebase-todo");
+		std::fs::write(&todo_file_path, "pick ccc c3\n").unwrap();
+		std::fs::write(rebase_dir.join("done"), done_contents.join("\n")).unwrap();
+		let mut todo_file = TodoFile::new(todo_file_path.to_str().unwrap(), 1, "#");
+		todo_file.load_file().unwrap();
+		(todo_file, git_dir)
+	}
+
+	#[test]
+	fn load_file_without_done_file() {
+		let (todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa foobar"]);
+		assert!(todo_file.get_done_lines().is_empty());
+	}
+
+	#[test]
+	fn load_file_with_done_file() {
+		let (todo_file, _rebase_dir) =
+			create_and_load_in_progress_todo_file(&["pick aaa c1", "# comment", "", "edit bbb c2"]);
+		assert_eq!(
+			todo_file
+				.get_done_lines()
+				.iter()
+				.map(Line::to_text)
+				.collect::<Vec<String>>(),
+			vec!["pick aaa c1", "edit bbb c2"]
+		);
+		assert_todo_lines!(todo_file, "pick ccc c3");
+	}
+
+	#[test]
+	fn load_file_ignores_done_file_outside_rebase() {
+		let dir = tempdir().unwrap();
+		let todo_file_path = dir.path().join("git-rebase-todo");
+		std::fs::write(&todo_file_path, "pick ccc c3\n").unwrap();
+		std::fs::write(dir.path().join("done"), "pick aaa c1\n").unwrap();
+		let mut todo_file = TodoFile::new(todo_file_path.to_str().unwrap(), 1, "#");
+		todo_file.load_file().unwrap();
+		assert!(todo_file.get_done_lines().is_empty());
+	}
+
+	#[test]
+	fn load_file_with_unreadable_done_file() {
+		let dir = tempdir().unwrap();
+		let rebase_dir = dir.path().join("rebase-merge");
+		std::fs::create_dir_all(rebase_dir.join("done")).unwrap();
+		let todo_file_path = rebase_dir.join("git-rebase-todo");
+		std::fs::write(&todo_file_path, "pick ccc c3\n").unwrap();
+		let mut todo_file = TodoFile::new(todo_file_path.to_str().unwrap(), 1, "#");
+		todo_file.load_file().unwrap();
+		assert!(todo_file.get_done_lines().is_empty());
+		assert_todo_lines!(todo_file, "pick ccc c3");
+	}
+
+	#[test]
+	fn selected_done_line() {
+		let (mut todo_file, _rebase_dir) = create_and_load_in_progress_todo_file(&["pick aaa c1", "edit bbb c2"]);
+		assert!(todo_file.get_selected_done_line().is_none());
+		todo_file.set_selected_done_line_index(Some(5));
+		assert_eq!(todo_file.get_selected_done_line_index(), Some(1));
+		assert_eq!(todo_file.get_selected_done_line().unwrap().get_hash(), "bbb");
+		todo_file.set_selected_done_line_index(None);
+		assert!(todo_file.get_selected_done_line().is_none());
+	}
+
+	#[test]
+	fn selected_done_line_without_done_lines() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa foobar"]);
+		todo_file.set_selected_done_line_index(Some(0));
+		assert_eq!(todo_file.get_selected_done_line_index(), None);
+	}
+
+	#[test]
+	fn autosquash() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc fixup! c1"]);
+		assert!(todo_file.autosquash());
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup ccc fixup! c1", "pick bbb c2");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc fixup! c1");
+	}
+
+	#[test]
+	fn autosquash_without_changes() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "fixup ccc fixup! c1"]);
+		assert!(!todo_file.autosquash());
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn fixup_into() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "fixup bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.fixup_into(&[(3, 0), (4, 2)]));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"fixup bbb c2",
+			"fixup ddd c4",
+			"pick ccc c3",
+			"fixup eee c5"
+		);
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+	}
+
+	#[test]
+	fn fixup_into_same_target() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(todo_file.fixup_into(&[(1, 0), (2, 0)]));
+		assert_todo_lines!(todo_file, "pick aaa c1", "fixup bbb c2", "fixup ccc c3");
+	}
+
+	#[test]
+	fn fixup_into_target_below() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.fixup_into(&[(0, 1)]));
+		assert!(!todo_file.fixup_into(&[(2, 0)]));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn fixup_into_moved_target() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.fixup_into(&[(1, 0), (2, 1)]));
+	}
+
+	#[test]
+	fn drop_lines() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3"]);
+		assert!(todo_file.drop_lines(&[0, 1, 3, 4]));
+		assert_todo_lines!(todo_file, "drop aaa c1", "exec make", "pick bbb c2", "drop ccc c3");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "exec make", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn drop_lines_without_changes() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["drop aaa c1", "exec make"]);
+		assert!(!todo_file.drop_lines(&[0, 1]));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn update_lines() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.update_lines(&[0, 2, 4], &EditContext::new().action(Action::Fixup)));
+		assert_todo_lines!(todo_file, "fixup aaa c1", "pick bbb c2", "fixup ccc c3", "pick ddd c4");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn update_lines_without_changes() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.update_lines(&[1], &EditContext::new().action(Action::Pick)));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn remove_lines_at() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.remove_lines_at(&[1, 3, 4]));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick ccc c3");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn remove_lines_at_without_lines() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert!(!todo_file.remove_lines_at(&[1]));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn add_exec_after_commits() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"break",
+			"pick ccc c3",
+			"exec make",
+			"pick ddd c4",
+		]);
+		assert!(todo_file.add_exec_after_commits(0, 5, "make"));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"fixup bbb c2",
+			"exec make",
+			"break",
+			"pick ccc c3",
+			"exec make",
+			"pick ddd c4",
+			"exec make"
+		);
+		todo_file.undo();
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"fixup bbb c2",
+			"break",
+			"pick ccc c3",
+			"exec make",
+			"pick ddd c4"
+		);
+	}
+
+	#[test]
+	fn add_exec_after_commits_range() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(todo_file.add_exec_after_commits(2, 1, "make"));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "exec make", "pick ccc c3", "exec make");
+	}
+
+	#[test]
+	fn add_exec_after_commits_already_added() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "exec make", "drop bbb c2", "exec other"]);
+		assert!(!todo_file.add_exec_after_commits(0, 3, "make"));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn add_exec_after_lines() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "exec make", "pick ccc c3"]);
+		assert!(todo_file.add_exec_after_lines(&[0, 2, 3], "make"));
+		assert_todo_lines!(
+			todo_file,
+			"pick aaa c1",
+			"exec make",
+			"pick bbb c2",
+			"exec make",
+			"pick ccc c3",
+			"exec make"
+		);
+	}
+
+	#[test]
+	fn remove_exec_lines() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "exec make", "pick bbb c2", "exec other", "exec make"]);
+		assert!(todo_file.remove_exec_lines("make"));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "exec other");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "exec make", "pick bbb c2", "exec other", "exec make");
+	}
+
+	#[test]
+	fn remove_exec_lines_not_found() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "exec other"]);
+		assert!(!todo_file.remove_exec_lines("make"));
+	}
+
+	#[test]
+	fn get_orphaned_fixups() {
+		let (todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick ccc fixup! c9"]);
+		assert_eq!(todo_file.get_orphaned_fixups(), vec![1]);
+	}
+
+	#[test]
+	fn sort_range() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "exec make", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.sort_range(3, 1, &[Some(2), None, Some(1)]));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick ccc c3", "pick bbb c2", "exec make", "pick ddd c4");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "exec make", "pick ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn sort_range_stable() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["exec make", "pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(todo_file.sort_range(0, 3, &[None, Some(2), Some(1), Some(2)]));
+		assert_todo_lines!(todo_file, "exec make", "pick bbb c2", "pick aaa c1", "pick ccc c3");
+	}
+
+	#[test]
+	fn sort_range_already_sorted() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.sort_range(0, 1, &[Some(1), Some(2)]));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn sort_range_with_branch_lines() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&[
+			"pick aaa c1",
+			"label feature",
+			"reset onto",
+			"pick bbb c2",
+			"merge -C ccc feature",
+		]);
+		assert!(!todo_file.sort_range(0, 3, &[Some(2), None, None, Some(1)]));
+		assert!(!todo_file.sort_range(3, 4, &[Some(2), Some(1)]));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn sort_range_invalid_range() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.sort_range(0, 2, &[Some(2), Some(1), Some(0)]));
+		assert!(!todo_file.sort_range(0, 1, &[Some(2)]));
+	}
+
+	#[test]
+	fn move_range_down() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5"]);
+		assert!(todo_file.move_range(1, 0, 4));
+		assert_todo_lines!(todo_file, "pick ccc c3", "pick ddd c4", "pick aaa c1", "pick bbb c2", "pick eee c5");
+		assert_eq!(todo_file.undo(), Some((0, 1)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4", "pick eee c5");
+		assert_eq!(todo_file.redo(), Some((2, 3)));
+		assert_todo_lines!(todo_file, "pick ccc c3", "pick ddd c4", "pick aaa c1", "pick bbb c2", "pick eee c5");
+	}
+
+	#[test]
+	fn move_range_up() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		assert!(todo_file.move_range(2, 3, 0));
+		assert_todo_lines!(todo_file, "pick ccc c3", "pick ddd c4", "pick aaa c1", "pick bbb c2");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4");
+	}
+
+	#[test]
+	fn move_range_to_end() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(todo_file.move_range(0, 0, 10));
+		assert_todo_lines!(todo_file, "pick bbb c2", "pick ccc c3", "pick aaa c1");
+	}
+
+	#[test]
+	fn move_range_without_move() {
+		let (mut todo_file, _todo_file_path) =
+			create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		assert!(!todo_file.move_range(0, 1, 0));
+		assert!(!todo_file.move_range(0, 1, 1));
+		assert!(!todo_file.move_range(0, 1, 2));
+		assert!(!todo_file.move_range(0, 3, 0));
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn add_lines() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.add_lines(1, &[Line::new("exec make").unwrap(), Line::new("break").unwrap()]);
+		assert_todo_lines!(todo_file, "pick aaa c1", "exec make", "break", "pick bbb c2");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2");
+	}
+
 	#[test]
 	fn load_noop_file() {
 		let (todo_file, _) = create_and_load_todo_file(&["noop"]);
@@ -293,6 +1270,13 @@ mod tests {
 		assert!(todo_file.is_noop());
 	}
 
+	#[test]
+	fn load_noop_file_with_comments() {
+		let (todo_file, _) = create_and_load_todo_file(&["# comment", "noop", "", "# comment"]);
+		assert!(todo_file.is_empty());
+		assert!(todo_file.is_noop());
+	}
+
 	#[test]
 	fn load_ignore_comments() {
 		let (todo_file, _) = create_and_load_todo_file(&["# pick aaa comment", "pick aaa foo", "# pick aaa comment"]);
@@ -305,6 +1289,289 @@ mod tests {
 		assert_todo_lines!(todo_file, "pick aaa foobar");
 	}
 
+	#[test]
+	fn load_ignore_unknown_lines() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa foobar", "frobnicate bbb foobar", "pick"]);
+		assert_todo_lines!(todo_file, "pick aaa foobar");
+	}
+
+	#[test]
+	fn load_empty_exec() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa foobar", "exec", "x"]);
+		assert_todo_lines!(todo_file, "pick aaa foobar", "exec ", "exec ");
+		assert_eq!(todo_file.get_problems().len(), 2);
+	}
+
+	#[test]
+	fn load_only_comments_is_empty() {
+		let (todo_file, _) = create_and_load_todo_file(&["# comment", "", "# comment"]);
+		assert!(todo_file.is_empty());
+	}
+
+	#[test]
+	fn missing_commits_none() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		assert!(todo_file.get_missing_commits().is_empty());
+	}
+
+	#[test]
+	fn missing_commits_removed_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "exec make"]);
+		todo_file.remove_lines(0, 0);
+		todo_file.remove_lines(1, 1);
+		assert_eq!(todo_file.get_missing_commits(), vec![Line::new("pick aaa c1").unwrap()]);
+	}
+
+	#[test]
+	fn missing_commits_ignores_later_loads() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		std::fs::write(todo_file_path.path(), "pick bbb c2\npick ccc c3").unwrap();
+		todo_file.load_file().unwrap();
+		assert_eq!(todo_file.get_missing_commits(), vec![Line::new("pick aaa c1").unwrap()]);
+	}
+
+	#[test]
+	fn drop_missing_commits() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "fixup bbb c2", "pick ccc c3"]);
+		todo_file.remove_lines(0, 1);
+		todo_file.drop_missing_commits();
+		assert_todo_lines!(todo_file, "pick ccc c3", "drop aaa c1", "drop bbb c2");
+		assert!(todo_file.get_missing_commits().is_empty());
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick ccc c3");
+	}
+
+	#[test]
+	fn drop_missing_commits_none_missing() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		todo_file.drop_missing_commits();
+		assert_todo_lines!(todo_file, "pick aaa c1");
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn replace_lines_unchanged() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.replace_lines(vec![Line::new("pick aaa c1").unwrap(), Line::new("pick bbb c2").unwrap()]);
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn replace_lines_added() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.replace_lines(vec![
+			Line::new("pick aaa c1").unwrap(),
+			Line::new("exec make").unwrap(),
+			Line::new("pick bbb c2").unwrap(),
+		]);
+		assert_todo_lines!(todo_file, "pick aaa c1", "exec make", "pick bbb c2");
+		assert_eq!(todo_file.undo(), Some((1, 1)));
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2");
+	}
+
+	#[test]
+	fn replace_lines_removed() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		todo_file.replace_lines(vec![Line::new("pick aaa c1").unwrap()]);
+		assert_todo_lines!(todo_file, "pick aaa c1");
+		assert_eq!(todo_file.get_selected_line_index(), 0);
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+	}
+
+	#[test]
+	fn replace_lines_changed() {
+		let (_, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let mut todo_file = TodoFile::new(todo_file_path.path().to_str().unwrap(), 10, "#");
+		todo_file.load_file().unwrap();
+		todo_file.update_range(0, 0, &EditContext::new().action(Action::Drop));
+		todo_file.replace_lines(vec![
+			Line::new("drop aaa c1").unwrap(),
+			Line::new("squash ccc c3").unwrap(),
+			Line::new("fixup bbb c2").unwrap(),
+			Line::new("pick ddd c4").unwrap(),
+		]);
+		assert_todo_lines!(todo_file, "drop aaa c1", "squash ccc c3", "fixup bbb c2", "pick ddd c4");
+		assert_eq!(todo_file.undo(), Some((1, 2)));
+		assert_todo_lines!(todo_file, "drop aaa c1", "pick bbb c2", "pick ccc c3");
+		todo_file.undo();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2", "pick ccc c3");
+		todo_file.redo();
+		todo_file.redo();
+		assert_todo_lines!(todo_file, "drop aaa c1", "squash ccc c3", "fixup bbb c2", "pick ddd c4");
+	}
+
+	#[test]
+	fn reload_file_noop() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1"]);
+		std::fs::write(todo_file_path.path(), "noop\n").unwrap();
+		todo_file.reload_file().unwrap();
+		assert!(todo_file.is_noop());
+		assert!(todo_file.undo().is_none());
+	}
+
+	#[test]
+	fn history_entries() {
+		let (_, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let mut todo_file = TodoFile::new(todo_file_path.path().to_str().unwrap(), 10, "#");
+		todo_file.load_file().unwrap();
+		todo_file.update_range(1, 2, &EditContext::new().action(Action::Fixup));
+		todo_file.swap_range_down(0, 0);
+		todo_file.add_line(3, Line::new("exec make").unwrap());
+		todo_file.remove_lines(0, 0);
+		todo_file.undo();
+		let (undo_entries, redo_entries) = todo_file.get_history_entries();
+		assert_eq!(
+			undo_entries
+				.iter()
+				.map(HistoryEntry::get_description)
+				.collect::<Vec<&str>>(),
+			vec!["Changed 2 lines to fixup", "Moved 1 line down", "Added 1 line"]
+		);
+		assert_eq!(
+			redo_entries
+				.iter()
+				.map(HistoryEntry::get_description)
+				.collect::<Vec<&str>>(),
+			vec!["Removed 1 line"]
+		);
+		assert_eq!(undo_entries[1].get_start_index(), 1);
+		assert_eq!(undo_entries[1].get_lines()[0].to_text(), "pick aaa c1");
+		assert_eq!(redo_entries[0].get_lines()[0].to_text(), "fixup bbb c2");
+	}
+
+	#[test]
+	fn autosave_restore() {
+		let (mut todo_file, _todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.remove_lines(0, 0);
+		todo_file.write_autosave().unwrap();
+		todo_file.load_file().unwrap();
+		assert!(todo_file.has_autosave());
+		todo_file.restore_autosave().unwrap();
+		assert_todo_lines!(todo_file, "pick bbb c2");
+		todo_file.remove_autosave().unwrap();
+		assert!(!todo_file.has_autosave());
+	}
+
+	#[test]
+	fn autosave_unchanged_lines() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		todo_file.write_autosave().unwrap();
+		assert!(!todo_file.has_autosave());
+		todo_file.remove_autosave().unwrap();
+	}
+
+	#[test]
+	fn autosave_different_todo_file() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.remove_lines(0, 0);
+		todo_file.write_autosave().unwrap();
+		std::fs::write(todo_file_path.path(), "pick ccc c3\n").unwrap();
+		let mut new_todo_file = TodoFile::new(todo_file_path.path().to_str().unwrap(), 1, "#");
+		new_todo_file.load_file().unwrap();
+		assert!(!new_todo_file.has_autosave());
+		assert_eq!(
+			new_todo_file.restore_autosave().unwrap_err().to_string(),
+			format!("No autosave found for: {}", todo_file.get_filepath())
+		);
+		todo_file.remove_autosave().unwrap();
+	}
+
+	#[test]
+	fn autosave_key_is_stable() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		todo_file.remove_lines(0, 0);
+		todo_file.write_autosave().unwrap();
+		let contents = std::fs::read_to_string(todo_file.get_autosave_path()).unwrap();
+		assert_eq!(contents.lines().next().unwrap(), "f1bc5e38f27da807");
+		assert!(!Path::new(format!("{}.tmp", todo_file.get_autosave_path()).as_str()).exists());
+		todo_file.remove_autosave().unwrap();
+	}
+
+	#[test]
+	fn remove_autosave_not_found() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1"]);
+		assert!(todo_file.remove_autosave().is_ok());
+	}
+
+	#[test]
+	fn write_file_abbreviate_commands() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa foobar", "exec make", "# a comment"]);
+		todo_file.set_abbreviate_commands(true);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "p aaa foobar", "x make", "# a comment");
+	}
+
+	#[test]
+	fn write_file_preserves_kept_lines() {
+		let (todo_file, _) = create_and_load_todo_file(&[
+			"# a note",
+			"pick aaa foobar",
+			"",
+			"frobnicate bbb",
+			"pick ccc foobar",
+			"# a comment",
+			"#",
+		]);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"# a note",
+			"pick aaa foobar",
+			"",
+			"frobnicate bbb",
+			"pick ccc foobar",
+			"# a comment",
+			"#"
+		);
+	}
+
+	#[test]
+	fn write_file_kept_lines_move_with_line() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa foobar", "# a note", "pick bbb foobar", "# a comment"]);
+		todo_file.swap_range_down(0, 0);
+		todo_file.add_line(2, Line::new("pick ccc foobar").unwrap());
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(
+			todo_file.get_filepath(),
+			"# a note",
+			"pick bbb foobar",
+			"pick aaa foobar",
+			"pick ccc foobar",
+			"# a comment"
+		);
+	}
+
+	#[test]
+	fn write_file_kept_lines_of_removed_lines() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa foobar", "# a note", "pick bbb foobar", "# a comment"]);
+		todo_file.remove_lines(1, 1);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "pick aaa foobar", "# a comment");
+	}
+
+	#[test]
+	fn write_file_kept_lines_of_added_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["# a note", "pick aaa foobar"]);
+		let lines = vec![todo_file.get_line(0).unwrap().clone()];
+		todo_file.add_lines(1, &lines);
+		todo_file.write_file().unwrap();
+		assert_read_todo_file!(todo_file.get_filepath(), "# a note", "pick aaa foobar", "pick aaa foobar");
+	}
+
+	#[test]
+	fn reload_file_changed_kept_lines() {
+		let (mut todo_file, todo_file_path) = create_and_load_todo_file(&["# a note", "pick aaa foobar"]);
+		std::fs::write(todo_file_path.path(), "# another note\npick aaa foobar").unwrap();
+		todo_file.reload_file().unwrap();
+		assert_eq!(todo_file.get_line(0).unwrap().get_kept_lines(), &[String::from("# another note")]);
+		assert!(todo_file.undo().is_some());
+		assert_eq!(todo_file.get_line(0).unwrap().get_kept_lines(), &[String::from("# a note")]);
+	}
+
 	#[test]
 	fn set_lines() {
 		let (mut todo_file, _) = create_and_load_todo_file(&[]);
@@ -454,6 +1721,13 @@ mod tests {
 		assert_todo_lines!(todo_file, "exec echo", "exec echo", "exec echo");
 	}
 
+	#[test]
+	fn update_range_set_option() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["fixup aaa comment", "pick bbb comment"]);
+		todo_file.update_range(0, 1, &EditContext::new().option(Some(FixupMessage::Use)));
+		assert_todo_lines!(todo_file, "fixup -C aaa comment", "pick bbb comment");
+	}
+
 	#[test]
 	fn update_range_edit_action() {
 		let (mut todo_file, _) =
@@ -1,3 +1,5 @@
+use std::iter::once;
+
 use crate::todo_file::line::Line;
 
 pub fn swap_range_up(lines: &mut Vec<Line>, start_index: usize, end_index: usize) {
@@ -41,6 +43,11 @@ pub fn remove_range(lines: &mut Vec<Line>, start_index: usize, end_index: usize)
 	removed_lines
 }
 
+pub fn move_range(lines: &mut Vec<Line>, start_index: usize, end_index: usize, destination_index: usize) {
+	let moved_lines = remove_range(lines, start_index, end_index);
+	add_range(lines, &moved_lines, destination_index, destination_index + moved_lines.len() - 1);
+}
+
 pub fn add_range(lines: &mut Vec<Line>, new_lines: &[Line], start_index: usize, end_index: usize) {
 	let range = if end_index <= start_index {
 		end_index..=start_index
@@ -53,3 +60,15 @@ pub fn add_range(lines: &mut Vec<Line>, new_lines: &[Line], start_index: usize,
 		lines.insert(index, new_lines[add_index].clone());
 	}
 }
+
+// FNV-1a, the result must not change between builds, since it is stored in the autosave file
+pub fn stable_hash(lines: &[Line]) -> u64 {
+	let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+	for line in lines {
+		for byte in line.to_text().bytes().chain(once(b'\n')) {
+			hash ^= u64::from(byte);
+			hash = hash.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+	hash
+}
@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+
+use crate::todo_file::{action::Action, line::Line};
+
+/// A line in the todo file that git would reject during the rebase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Problem {
+	index: usize,
+	message: String,
+}
+
+impl Problem {
+	fn new(index: usize, message: String) -> Self {
+		Self { index, message }
+	}
+
+	/// The index of the line with the problem.
+	pub const fn get_index(&self) -> usize {
+		self.index
+	}
+
+	/// A description of the problem.
+	pub fn get_message(&self) -> &str {
+		self.message.as_str()
+	}
+}
+
+// a reference that is not a label may still be a commit, which git will resolve itself
+fn is_commit_reference(reference: &str) -> bool {
+	reference.len() >= 4 && reference.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+const fn is_commit_action(action: &Action) -> bool {
+	matches!(
+		*action,
+		Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash
+	)
+}
+
+fn is_same_commit(a: &str, b: &str) -> bool {
+	a.starts_with(b) || b.starts_with(a)
+}
+
+pub(super) fn validate(lines: &[Line]) -> Vec<Problem> {
+	let mut problems = vec![];
+	let mut labels: HashSet<&str> = HashSet::new();
+	let mut picked: Vec<&str> = vec![];
+	let mut seen_commit = false;
+
+	for (index, line) in lines.iter().enumerate() {
+		match *line.get_action() {
+			Action::Fixup | Action::Squash if !seen_commit => {
+				problems.push(Problem::new(
+					index,
+					format!("Cannot {} without a previous commit", line.get_action().as_string()),
+				));
+			},
+			Action::Exec if line.get_content().trim().is_empty() => {
+				problems.push(Problem::new(index, String::from("Exec command is empty")));
+			},
+			Action::Label => {
+				let label = line.get_content().split_whitespace().next().unwrap_or("");
+				if !labels.insert(label) {
+					problems.push(Problem::new(index, format!("Label '{}' is already defined", label)));
+				}
+			},
+			Action::Reset => {
+				let reference = line.get_content().split_whitespace().next().unwrap_or("");
+				if reference != "[new" && !labels.contains(reference) && !is_commit_reference(reference) {
+					problems.push(Problem::new(index, format!("Label '{}' is not defined", reference)));
+				}
+			},
+			Action::Merge => {
+				for reference in line.get_content().split_whitespace() {
+					if !labels.contains(reference) && !is_commit_reference(reference) {
+						problems.push(Problem::new(index, format!("Label '{}' is not defined", reference)));
+					}
+				}
+			},
+			_ => {},
+		}
+
+		match *line.get_action() {
+			action if is_commit_action(&action) => {
+				let hash = line.get_hash();
+				if picked.iter().any(|h| is_same_commit(h, hash)) {
+					problems.push(Problem::new(index, format!("Commit {} is picked more than once", hash)));
+				}
+				picked.push(hash);
+				// a fixup or squash needs a commit before it, which an earlier fixup or squash does not give it
+				seen_commit = seen_commit || !matches!(action, Action::Fixup | Action::Squash);
+			},
+			Action::Merge => seen_commit = true,
+			_ => {},
+		}
+	}
+	problems
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|l| Line::new(l).unwrap()).collect()
+	}
+
+	#[test]
+	fn valid_todo() {
+		let lines = create_lines(&[
+			"label onto",
+			"pick aaa c1",
+			"fixup bbb c2",
+			"label feature",
+			"reset onto",
+			"merge -C ccc feature # Merge",
+			"exec make test",
+		]);
+		assert!(validate(&lines).is_empty());
+	}
+
+	#[rstest(
+		action,
+		case::fixup("fixup"),
+		case::squash("squash")
+	)]
+	fn first_commit_fixup_or_squash(action: &str) {
+		let lines = create_lines(&[
+			"exec make",
+			format!("{} aaaa c1", action).as_str(),
+			"pick bbbb c2",
+		]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			format!("Cannot {} without a previous commit", action)
+		)]);
+	}
+
+	#[test]
+	fn fixup_after_dropped_commit() {
+		let lines = create_lines(&["drop aaaa c1", "fixup bbbb c2", "pick cccc c3"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			String::from("Cannot fixup without a previous commit")
+		)]);
+	}
+
+	#[test]
+	fn leading_fixups_without_later_commit() {
+		let lines = create_lines(&["fixup aaa c1", "squash bbb c2"]);
+		assert_eq!(validate(&lines), vec![
+			Problem::new(0, String::from("Cannot fixup without a previous commit")),
+			Problem::new(1, String::from("Cannot squash without a previous commit")),
+		]);
+	}
+
+	#[test]
+	fn reset_undefined_label() {
+		let lines = create_lines(&["pick aaa c1", "reset feature", "label feature"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			String::from("Label 'feature' is not defined")
+		)]);
+	}
+
+	#[test]
+	fn reset_label_never_defined() {
+		let lines = create_lines(&["pick aaa c1", "reset main"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			String::from("Label 'main' is not defined")
+		)]);
+	}
+
+	#[rstest(
+		line,
+		case::commit("reset abcd1234 # c1"),
+		case::new_root("reset [new root]")
+	)]
+	fn reset_without_label(line: &str) {
+		let lines = create_lines(&["pick aaa c1", line]);
+		assert!(validate(&lines).is_empty());
+	}
+
+	#[test]
+	fn merge_undefined_labels() {
+		let lines = create_lines(&[
+			"label one",
+			"pick aaa c1",
+			"merge -C bbb one two three # Merge",
+			"label two",
+			"label three",
+		]);
+		assert_eq!(validate(&lines), vec![
+			Problem::new(2, String::from("Label 'two' is not defined")),
+			Problem::new(2, String::from("Label 'three' is not defined")),
+		]);
+	}
+
+	#[test]
+	fn duplicate_label() {
+		let lines = create_lines(&["label one", "pick aaa c1", "label one"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			2,
+			String::from("Label 'one' is already defined")
+		)]);
+	}
+
+	#[test]
+	fn commit_picked_twice() {
+		let lines = create_lines(&["pick aaaa111 c1", "pick bbbb c2", "squash aaaa c1"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			2,
+			String::from("Commit aaaa is picked more than once")
+		)]);
+	}
+
+	#[test]
+	fn short_hash_picked_twice() {
+		let lines = create_lines(&["pick aaa c1", "pick aaa c2"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			String::from("Commit aaa is picked more than once")
+		)]);
+	}
+
+	#[test]
+	fn merge_commit_references() {
+		let lines = create_lines(&["label one", "pick aaa c1", "merge -C bbb one abcd1234 # Merge"]);
+		assert!(validate(&lines).is_empty());
+	}
+
+	#[test]
+	fn merge_label_never_defined() {
+		let lines = create_lines(&["pick aaa c1", "merge -C bbb feature # Merge"]);
+		assert_eq!(validate(&lines), vec![Problem::new(
+			1,
+			String::from("Label 'feature' is not defined")
+		)]);
+	}
+
+	#[test]
+	fn dropped_commit_not_counted_as_picked() {
+		let lines = create_lines(&["drop aaaa c1", "pick aaaa c1"]);
+		assert!(validate(&lines).is_empty());
+	}
+
+	#[test]
+	fn empty_exec_command() {
+		let lines = create_lines(&["pick aaa c1", "exec  "]);
+		assert_eq!(validate(&lines), vec![Problem::new(1, String::from("Exec command is empty"))]);
+	}
+
+	#[test]
+	fn problem_getters() {
+		let problem = Problem::new(1, String::from("message"));
+		assert_eq!(problem.get_index(), 1);
+		assert_eq!(problem.get_message(), "message");
+	}
+}
@@ -0,0 +1,359 @@
+use crate::{
+	display::display_color::DisplayColor,
+	input::{input_handler::InputMode, Input},
+	process::{process_module::ProcessModule, process_result::ProcessResult, state::State},
+	todo_file::{history::history_entry::HistoryEntry, TodoFile},
+	view::{line_segment::LineSegment, view_data::ViewData, view_line::ViewLine, View},
+};
+
+const MAXIMUM_PREVIEW_LINES: usize = 5;
+
+pub struct UndoHistory {
+	current_index: usize,
+	// the history can not change while the view is open, so the entries are only described once, on activate
+	entries: Vec<HistoryEntry>,
+	selected_index: usize,
+	view_data: ViewData,
+}
+
+impl ProcessModule for UndoHistory {
+	fn activate(&mut self, todo_file: &TodoFile, _: State) -> ProcessResult {
+		let (undo_entries, redo_entries) = todo_file.get_history_entries();
+		self.current_index = undo_entries.len();
+		self.entries = undo_entries;
+		self.entries.extend(redo_entries);
+		self.selected_index = self.current_index;
+		ProcessResult::new()
+	}
+
+	fn deactivate(&mut self) {
+		self.entries.clear();
+		self.view_data.reset();
+	}
+
+	fn build_view_data(&mut self, view: &View<'_>, _: &TodoFile) -> &ViewData {
+		let view_size = view.get_view_size();
+
+		self.view_data.clear();
+		self.view_data.set_view_size(view_size.width(), view_size.height());
+		self.view_data.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+			"Undo history, Enter to jump to the selected change, Esc to return",
+			DisplayColor::IndicatorColor,
+		)));
+
+		let descriptions =
+			std::iter::once("Start of history").chain(self.entries.iter().map(HistoryEntry::get_description));
+		for (index, description) in descriptions.enumerate() {
+			let selected = index == self.selected_index;
+			let mut segments = vec![
+				LineSegment::new(if selected { "> " } else { "  " }),
+				LineSegment::new_with_color_and_style(
+					description,
+					DisplayColor::Normal,
+					index > self.current_index,
+					false,
+					false,
+				),
+			];
+			if index == self.current_index {
+				segments.push(LineSegment::new_with_color(" (current)", DisplayColor::IndicatorColor));
+			}
+			self.view_data
+				.push_line(ViewLine::new_with_pinned_segments(segments, 1).set_selected(selected));
+		}
+
+		if self.selected_index > 0 {
+			if let Some(entry) = self.entries.get(self.selected_index - 1) {
+				self.view_data.push_trailing_line(ViewLine::new_empty_line());
+				self.view_data
+					.push_trailing_line(ViewLine::from(LineSegment::new_with_color_and_style(
+						format!("Affected lines, starting at line {}:", entry.get_start_index() + 1).as_str(),
+						DisplayColor::Normal,
+						true,
+						false,
+						false,
+					)));
+				for line in entry.get_lines().iter().take(MAXIMUM_PREVIEW_LINES) {
+					self.view_data
+						.push_trailing_line(ViewLine::from(format!("  {}", line.to_text())));
+				}
+				if entry.get_lines().len() > MAXIMUM_PREVIEW_LINES {
+					self.view_data.push_trailing_line(ViewLine::from(format!(
+						"  ... and {} more",
+						entry.get_lines().len() - MAXIMUM_PREVIEW_LINES
+					)));
+				}
+			}
+		}
+
+		self.view_data.rebuild();
+		self.view_data.ensure_line_visible(self.selected_index);
+		&self.view_data
+	}
+
+	fn handle_input(&mut self, view: &mut View<'_>, todo_file: &mut TodoFile) -> ProcessResult {
+		let input = view.get_input(InputMode::Default);
+		let mut result = ProcessResult::new().input(input);
+		let last_index = self.entries.len();
+		match input {
+			Input::ScrollUp => self.selected_index = self.selected_index.saturating_sub(1),
+			Input::ScrollDown => {
+				if self.selected_index < last_index {
+					self.selected_index += 1;
+				}
+			},
+			Input::ScrollTop => self.selected_index = 0,
+			Input::ScrollBottom => self.selected_index = last_index,
+			Input::Enter => {
+				let mut changed_range = None;
+				for _ in self.selected_index..self.current_index {
+					changed_range = todo_file.undo();
+				}
+				for _ in self.current_index..self.selected_index {
+					changed_range = todo_file.redo();
+				}
+				let selected_entry = if self.selected_index == 0 {
+					None
+				}
+				else {
+					self.entries.get(self.selected_index - 1)
+				};
+				if let Some(entry) = selected_entry {
+					todo_file.set_selected_line_index(entry.get_start_index());
+				}
+				else if let Some((start_index, _)) = changed_range {
+					todo_file.set_selected_line_index(start_index);
+				}
+				result = result.state(State::List);
+			},
+			Input::Escape => result = result.state(State::List),
+			_ => {},
+		}
+		result
+	}
+}
+
+impl UndoHistory {
+	pub(crate) fn new() -> Self {
+		let mut view_data = ViewData::new();
+		view_data.set_show_title(true);
+		Self {
+			current_index: 0,
+			entries: vec![],
+			selected_index: 0,
+			view_data,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		assert_process_result,
+		assert_rendered_output,
+		process::testutil::{process_module_test, TestContext, ViewState},
+		todo_file::{action::Action, edit_content::EditContext, line::Line},
+	};
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_empty_history() {
+		process_module_test(
+			&["pick aaa c1"],
+			ViewState::default(),
+			&[],
+			|test_context: TestContext<'_>| {
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Undo history, Enter to jump to the selected change, Esc to return",
+					"{BODY}",
+					"{Normal(selected)}> {Normal(selected)}Start of history{IndicatorColor(selected)} (current)"
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_with_history() {
+		process_module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context
+					.rebase_todo_file
+					.update_range(0, 1, &EditContext::new().action(Action::Drop));
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Undo history, Enter to jump to the selected change, Esc to return",
+					"{BODY}",
+					"{Normal}  {Normal}Start of history",
+					"{Normal(selected)}> {Normal(selected)}Changed 2 lines to drop{IndicatorColor(selected)} (current)",
+					"{TRAILING}",
+					"",
+					"{Normal,Dimmed}Affected lines, starting at line 1:",
+					"{Normal}  drop aaa c1",
+					"{Normal}  drop bbb c2"
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn build_view_data_with_redo() {
+		process_module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(1, 1);
+				test_context.rebase_todo_file.undo();
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"{IndicatorColor}Undo history, Enter to jump to the selected change, Esc to return",
+					"{BODY}",
+					"{Normal(selected)}> {Normal(selected)}Start of history{IndicatorColor(selected)} (current)",
+					"{Normal}  {Normal,Dimmed}Removed 1 line"
+				);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn jump_undo() {
+		process_module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			ViewState::default(),
+			&[Input::ScrollUp, Input::Enter],
+			|mut test_context: TestContext<'_>| {
+				test_context
+					.rebase_todo_file
+					.update_range(1, 1, &EditContext::new().action(Action::Drop));
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(test_context.handle_input(&mut module), input = Input::ScrollUp);
+				assert_eq!(module.selected_index, 0);
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Enter,
+					state = State::List
+				);
+				assert_eq!(
+					test_context.rebase_todo_file.get_line(1).unwrap().get_action(),
+					&Action::Pick
+				);
+				assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 1);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn jump_redo() {
+		process_module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			ViewState::default(),
+			&[Input::ScrollDown, Input::ScrollDown, Input::Enter],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.add_line(2, Line::new("exec make").unwrap());
+				test_context.rebase_todo_file.undo();
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(test_context.handle_input(&mut module), input = Input::ScrollDown);
+				assert_process_result!(test_context.handle_input(&mut module), input = Input::ScrollDown);
+				assert_eq!(module.selected_index, 1);
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Enter,
+					state = State::List
+				);
+				assert_eq!(test_context.rebase_todo_file.get_line(2).unwrap().to_text(), "exec make");
+				assert_eq!(test_context.rebase_todo_file.get_selected_line_index(), 2);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn scroll_top_and_bottom() {
+		process_module_test(
+			&["pick aaa c1"],
+			ViewState::default(),
+			&[Input::ScrollTop, Input::ScrollBottom],
+			|mut test_context: TestContext<'_>| {
+				test_context
+					.rebase_todo_file
+					.update_range(0, 0, &EditContext::new().action(Action::Drop));
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				test_context.handle_input(&mut module);
+				assert_eq!(module.selected_index, 0);
+				test_context.handle_input(&mut module);
+				assert_eq!(module.selected_index, 1);
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn entries_loaded_on_activate() {
+		process_module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			ViewState::default(),
+			&[],
+			|mut test_context: TestContext<'_>| {
+				test_context.rebase_todo_file.remove_lines(1, 1);
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_eq!(module.entries.len(), 1);
+				module.deactivate();
+				assert!(module.entries.is_empty());
+			},
+		);
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn escape() {
+		process_module_test(
+			&["pick aaa c1"],
+			ViewState::default(),
+			&[Input::Escape],
+			|mut test_context: TestContext<'_>| {
+				test_context
+					.rebase_todo_file
+					.update_range(0, 0, &EditContext::new().action(Action::Drop));
+				let mut module = UndoHistory::new();
+				assert_process_result!(test_context.activate(&mut module, State::List));
+				assert_process_result!(
+					test_context.handle_input(&mut module),
+					input = Input::Escape,
+					state = State::List
+				);
+				assert_eq!(
+					test_context.rebase_todo_file.get_line(0).unwrap().get_action(),
+					&Action::Drop
+				);
+			},
+		);
+	}
+}
@@ -5,7 +5,10 @@ pub mod testutil;
 pub mod view_data;
 pub mod view_line;
 
+use std::time::Duration;
+
 use anyhow::Result;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
 	constants::{TITLE, TITLE_HELP_INDICATOR_LENGTH, TITLE_LENGTH, TITLE_SHORT, TITLE_SHORT_LENGTH},
@@ -37,6 +40,10 @@ impl<'v> View<'v> {
 		self.display.get_input(mode)
 	}
 
+	pub(crate) fn is_input_ready(&self, timeout: Duration) -> bool {
+		self.display.is_input_ready(timeout)
+	}
+
 	pub(crate) fn get_view_size(&self) -> Size {
 		self.display.get_window_size()
 	}
@@ -48,7 +55,7 @@ impl<'v> View<'v> {
 		self.display.ensure_at_line_start()?;
 		if view_data.show_title() {
 			self.display.ensure_at_line_start()?;
-			self.draw_title(view_data.show_help())?;
+			self.draw_title(view_data.show_help(), view_data.get_title_status())?;
 			self.display.next_line()?;
 		}
 
@@ -116,7 +123,7 @@ impl<'v> View<'v> {
 		Ok(())
 	}
 
-	fn draw_title(&mut self, show_help: bool) -> Result<()> {
+	fn draw_title(&mut self, show_help: bool, status: Option<&str>) -> Result<()> {
 		self.display.color(DisplayColor::Normal, false)?;
 		self.display.set_style(false, true, false)?;
 		let window_width = self.display.get_window_size().width();
@@ -130,12 +137,20 @@ impl<'v> View<'v> {
 
 		let title_help_indicator_total_length = TITLE_HELP_INDICATOR_LENGTH + help_indicator.len();
 
+		// the status is only drawn when there is room for it with the help indicator
+		let status = status
+			.map(|status| format!(" {}", status))
+			.filter(|status| window_width > TITLE_LENGTH + status.width() + title_help_indicator_total_length)
+			.unwrap_or_default();
+		let title_length = TITLE_LENGTH + status.width();
+
 		if window_width >= TITLE_LENGTH {
 			self.display.draw_str(TITLE)?;
+			self.display.draw_str(status.as_str())?;
 			// only draw help if there is room
-			if window_width > TITLE_LENGTH + title_help_indicator_total_length {
-				if (window_width - TITLE_LENGTH - title_help_indicator_total_length) > 0 {
-					let padding = " ".repeat(window_width - TITLE_LENGTH - title_help_indicator_total_length);
+			if window_width > title_length + title_help_indicator_total_length {
+				if (window_width - title_length - title_help_indicator_total_length) > 0 {
+					let padding = " ".repeat(window_width - title_length - title_help_indicator_total_length);
 					self.display.draw_str(padding.as_str())?;
 				}
 				if show_help {
@@ -275,6 +290,36 @@ mod tests {
 		});
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn render_title_full_width_with_status() {
+		view_module_test(Size::new(50, 10), |mut test_context| {
+			let mut view_data = ViewData::new();
+			view_data.set_show_title(true);
+			view_data.set_show_help(true);
+			view_data.set_title_status(Some("Filtered"));
+			test_context.view.render(&view_data).unwrap();
+			let mut expected = vec!["Git Interactive Rebase Tool Filtered       Help: ?"];
+			expected.extend(vec!["~"; 9]);
+			TestContext::assert_output(&expected);
+		});
+	}
+
+	#[test]
+	#[serial_test::serial]
+	fn render_title_full_width_with_status_not_enough_length() {
+		view_module_test(Size::new(40, 10), |mut test_context| {
+			let mut view_data = ViewData::new();
+			view_data.set_show_title(true);
+			view_data.set_show_help(true);
+			view_data.set_title_status(Some("Filtered"));
+			test_context.view.render(&view_data).unwrap();
+			let mut expected = vec!["Git Interactive Rebase Tool      Help: ?"];
+			expected.extend(vec!["~"; 9]);
+			TestContext::assert_output(&expected);
+		});
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn render_leading_lines() {
@@ -21,10 +21,12 @@ fn render_style(color: DisplayColor, selected: bool, dimmed: bool, underline: bo
 		DisplayColor::DiffContextColor => String::from("DiffContextColor"),
 		DisplayColor::DiffWhitespaceColor => String::from("DiffWhitespaceColor"),
 		DisplayColor::IndicatorColor => String::from("IndicatorColor"),
+		DisplayColor::WarningColor => String::from("WarningColor"),
 		DisplayColor::Normal => String::from("Normal"),
 		DisplayColor::ActionLabel => String::from("ActionLabel"),
 		DisplayColor::ActionReset => String::from("ActionReset"),
 		DisplayColor::ActionMerge => String::from("ActionMerge"),
+		DisplayColor::ActionUpdateRef => String::from("ActionUpdateRef"),
 	};
 
 	if selected {
@@ -90,11 +92,14 @@ fn render_view_line(view_line: &ViewLine) -> String {
 fn render_view_data(view_data: &ViewData) -> Vec<String> {
 	let mut lines = vec![];
 	if view_data.show_title() {
+		let status = view_data
+			.get_title_status()
+			.map_or_else(String::new, |status| format!("{{STATUS:{}}}", status));
 		if view_data.show_help() {
-			lines.push(String::from("{TITLE}{HELP}"));
+			lines.push(format!("{{TITLE}}{}{{HELP}}", status));
 		}
 		else {
-			lines.push(String::from("{TITLE}"));
+			lines.push(format!("{{TITLE}}{}", status));
 		}
 	}
 
@@ -13,6 +13,7 @@ pub struct ViewData {
 	trailing_lines_cache: Option<Vec<ViewLine>>,
 	show_title: bool,
 	show_help: bool,
+	title_status: Option<String>,
 	max_leading_line_length: usize,
 	max_line_length: usize,
 	max_trailing_line_length: usize,
@@ -33,6 +34,7 @@ impl ViewData {
 			scroll_position: ScrollPosition::new(),
 			show_help: false,
 			show_title: false,
+			title_status: None,
 			trailing_lines: vec![],
 			trailing_lines_cache: None,
 			width: 0,
@@ -165,6 +167,10 @@ impl ViewData {
 		self.show_help = show;
 	}
 
+	pub(crate) fn set_title_status(&mut self, status: Option<&str>) {
+		self.title_status = status.map(String::from);
+	}
+
 	pub(crate) fn push_leading_line(&mut self, view_line: ViewLine) {
 		self.leading_lines_cache = None;
 		self.lines_cache = None;
@@ -250,6 +256,44 @@ impl ViewData {
 		slope.mul_add(value - input_start, output_start).round() as usize
 	}
 
+	// the index of the body line drawn at a row of the window, if a body line is drawn there
+	pub(crate) fn get_line_index_at_row(&self, row: usize) -> Option<usize> {
+		let body_row = row.checked_sub(if self.show_title { 1 } else { 0 } + self.get_leading_lines().len())?;
+		let visible_lines = self.get_lines().len();
+		if body_row >= visible_lines {
+			return None;
+		}
+		// the same top line that rebuild used, which is limited to keep the view full
+		let top = if self.scroll_position.get_top_position() + visible_lines > self.lines.len() {
+			self.lines.len() - visible_lines
+		}
+		else {
+			self.scroll_position.get_top_position()
+		};
+		Some(top + body_row)
+	}
+
+	pub(crate) fn is_scroll_bar_column(&self, column: usize) -> bool {
+		self.should_show_scroll_bar() && column + 1 == self.width
+	}
+
+	// the index of the body line a row of the scroll bar points to
+	pub(crate) fn get_scroll_bar_line_index(&self, row: usize) -> Option<usize> {
+		if !self.should_show_scroll_bar() {
+			return None;
+		}
+		let body_row = row.checked_sub(if self.show_title { 1 } else { 0 } + self.get_leading_lines().len())?;
+		let visible_lines = self.get_lines().len();
+		if body_row >= visible_lines {
+			return None;
+		}
+		if visible_lines <= 1 {
+			return Some(0);
+		}
+		// the first and last rows of the scroll bar are the first and last lines, like the scroll indicator
+		Some(body_row * (self.lines.len() - 1) / (visible_lines - 1))
+	}
+
 	pub(super) const fn show_title(&self) -> bool {
 		self.show_title
 	}
@@ -258,6 +302,10 @@ impl ViewData {
 		self.show_help
 	}
 
+	pub(super) fn get_title_status(&self) -> Option<&str> {
+		self.title_status.as_deref()
+	}
+
 	pub(crate) fn is_empty(&self) -> bool {
 		self.lines.is_empty() && self.leading_lines.is_empty() && self.trailing_lines.is_empty()
 	}
@@ -564,6 +612,48 @@ mod tests {
 		view_data
 	}
 
+	#[test]
+	fn get_line_index_at_row() {
+		let mut view_data = create_mocked_scroll_vertical_view_data();
+		view_data.rebuild();
+		assert_eq!(view_data.get_line_index_at_row(2), None);
+		assert_eq!(view_data.get_line_index_at_row(3), Some(0));
+		assert_eq!(view_data.get_line_index_at_row(7), Some(4));
+		assert_eq!(view_data.get_line_index_at_row(8), None);
+		view_data.scroll_down();
+		view_data.rebuild();
+		assert_eq!(view_data.get_line_index_at_row(3), Some(1));
+	}
+
+	#[test]
+	fn get_line_index_at_row_with_title() {
+		let mut view_data = create_mocked_scroll_vertical_view_data();
+		view_data.set_show_title(true);
+		view_data.rebuild();
+		assert_eq!(view_data.get_line_index_at_row(3), None);
+		assert_eq!(view_data.get_line_index_at_row(4), Some(0));
+	}
+
+	#[test]
+	fn get_scroll_bar_line_index() {
+		let mut view_data = create_mocked_scroll_vertical_view_data();
+		view_data.rebuild();
+		assert!(view_data.is_scroll_bar_column(99));
+		assert!(!view_data.is_scroll_bar_column(98));
+		assert_eq!(view_data.get_scroll_bar_line_index(3), Some(0));
+		assert_eq!(view_data.get_scroll_bar_line_index(5), Some(4));
+		assert_eq!(view_data.get_scroll_bar_line_index(7), Some(8));
+		assert_eq!(view_data.get_scroll_bar_line_index(8), None);
+	}
+
+	#[test]
+	fn get_scroll_bar_line_index_without_scroll_bar() {
+		let mut view_data = create_mocked_view_data();
+		view_data.rebuild();
+		assert!(!view_data.is_scroll_bar_column(99));
+		assert_eq!(view_data.get_scroll_bar_line_index(3), None);
+	}
+
 	#[test]
 	fn render_empty() {
 		let view_data = ViewData::new();